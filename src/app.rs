@@ -1,42 +1,75 @@
 use crate::{
     args::Args,
-    common::{AppEditMode, AppTime, AppTimeFormat, ClockName, ClockTypeId, Content, Style, Toggle},
-    constants::{TABATA_MAX_ROUNDS, TABATA_PAUSE, TABATA_WORK, TICK_VALUE_MS},
+    common::{
+        AppClock, AppEditMode, AppTime, AppTimeFormat, ClockDescription, ClockName, ClockTypeId,
+        Content, DateFormat, DecimalSeparator, Flip, NotifyUrgency, Palette, SoundKind, Spacing,
+        Style, Theme, Toggle, with_local_offset,
+    },
+    constants::{
+        FLASH_DURATION, PREVIEW_TIMEOUT, RESIZE_DEBOUNCE, TABATA_MAX_ROUNDS, TABATA_PAUSE,
+        TABATA_WORK, TICK_VALUE_MS,
+    },
+    duration::{DurationEx, MAX_DURATION},
     event::Event,
     events::{self, TuiEventHandler},
-    storage::AppStorage,
+    storage::{AppStorage, ClockModeStorage, CountdownStorage},
     terminal::Terminal,
     widgets::{
-        clock::{self, ClockState, ClockStateArgs},
-        countdown::{Countdown, CountdownState, CountdownStateArgs},
-        event::{EventState, EventStateArgs, EventWidget},
-        footer::{Footer, FooterState},
-        header::Header,
-        local_time::{LocalTimeState, LocalTimeStateArgs, LocalTimeWidget},
+        alarm::{AlarmState, AlarmStateArgs, AlarmWidget},
+        clock::{self, ClockState, ClockStateArgs, ClockWidget},
+        command::{Command, CommandState, CommandWidget},
+        countdown::{Countdown, CountdownList, CountdownState, CountdownStateArgs, SequenceState},
+        event::{DEFAULT_SNOOZE, EventState, EventStateArgs, EventWidget},
+        footer::{Footer, FooterItem, FooterState},
+        header::{Header, Progress},
+        help::{HelpState, HelpWidget},
+        local_time::{LocalTimeState, LocalTimeStateArgs, LocalTimeStyle, LocalTimeWidget},
         pomodoro::{
             Mode as PomodoroMode, PauseDuration, PomodoroState, PomodoroStateArgs, PomodoroWidget,
         },
+        settings::{SettingsItem, SettingsState, SettingsWidget},
         timer::{Timer, TimerState},
     },
 };
 
-use crossterm::event::Event as CrosstermEvent;
+use crossterm::{
+    event::{Event as CrosstermEvent, MouseButton, MouseEvent, MouseEventKind},
+    terminal::SetTitle,
+};
+
+#[cfg(feature = "keep-awake")]
+use crate::keep_awake::KeepAwake;
 
 #[cfg(feature = "sound")]
-use crate::sound::Sound;
-#[cfg(feature = "sound")]
-use std::path::PathBuf;
+use crate::{
+    config::SoundsConfig,
+    sound::{Sound, Sounds},
+};
 
+use clap::ValueEnum;
 use color_eyre::Result;
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
     layout::{Constraint, Layout, Position, Rect},
+    style::{Color, Modifier, Style as RatatuiStyle},
+    text::Line,
     widgets::{StatefulWidget, Widget},
 };
 
-use std::time::Duration;
-use tracing::{debug, error};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fs,
+    io::Write,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use tracing::{debug, error, warn};
+
+const SNAPSHOT_FILE_DEFAULT: &str = "timr-tui-snapshot.txt";
+/// Min. height of content (clock + labels) to keep on-screen while moving it
+const MIN_CONTENT_HEIGHT: u16 = 8;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Mode {
@@ -48,29 +81,128 @@ pub struct App {
     content: Content,
     mode: Mode,
     notification: Toggle,
+    // not supported on macOS
+    #[cfg_attr(target_os = "macos", allow(dead_code))]
+    notify_urgency: Option<NotifyUrgency>,
+    notify_timeout: Option<Duration>,
+    /// Silences notifications and sounds (for current session only)
+    quiet: bool,
     blink: Toggle,
     #[cfg(feature = "sound")]
     sound: Option<Sound>,
+    /// Sound to play every second while a clock is running
+    #[cfg(feature = "sound")]
+    tick_sound: Option<Sound>,
+    /// Sounds configured by config file
+    #[cfg(feature = "sound")]
+    sounds: Sounds,
+    /// Source of `app_time`
+    clock: AppClock,
     app_time: AppTime,
     app_time_format: AppTimeFormat,
     countdown: CountdownState,
+    /// Countdowns next to the active `countdown`
+    other_countdowns: VecDeque<CountdownState>,
     timer: TimerState,
     pomodoro: PomodoroState,
     event: EventState,
     local_time: LocalTimeState,
+    alarm: AlarmState,
     style: Style,
     with_decis: bool,
+    decimal_separator: DecimalSeparator,
+    flip: Option<Flip>,
+    urgency_colors: bool,
+    /// Renders a line of time left, time to finish and percentage done below clocks
+    status_line: bool,
+    /// Shows time running and wall-clock time of timer
+    timer_wall: bool,
+    /// Pulses digits of a running pomodoro pause
+    breathe: bool,
+    /// Shows initial value next to current value of countdown and pomodoro
+    show_initial: bool,
+    /// Renders seconds only of countdown
+    seconds_only: bool,
+    /// Renders a bar of time left below countdown and pomodoro
+    bar: bool,
+    /// Shows time elapsed since `launched_at` in footer
+    show_uptime: bool,
+    launched_at: AppTime,
+    /// Inhibits display sleep while any clock is running (if enabled)
+    #[cfg(feature = "keep-awake")]
+    keep_awake: Option<KeepAwake>,
+    /// System clipboard (created by first copy). It's kept alive, because some platforms (e.g. X11) clear copied text by dropping it.
+    #[cfg(feature = "clipboard")]
+    clipboard: Option<arboard::Clipboard>,
+    /// Sets current value as title of terminal
+    set_title: bool,
+    /// Latest title set (if any)
+    title: Option<String>,
+    spacing: Spacing,
+    /// Hides seconds of clock values exceeding it
+    hide_seconds_after: Option<Duration>,
+    local_time_style: LocalTimeStyle,
+    progress: Progress,
+    /// Renders labels of years (`Y`) and days (`D`)
+    unit_labels: bool,
+    /// Shows seconds of app time in footer, regardless of `app_time_format`
+    footer_seconds: bool,
     vim_motions: bool,
     footer: FooterState,
+    line: bool,
+    /// Hides header and footer
+    zen: bool,
+    /// Dims content
+    dim: bool,
+    /// Lines to move content down (or up, if negative) from the center
+    voffset: i16,
+    /// Max. `voffset` to keep content on-screen, updated by each render
+    max_voffset: i16,
+    theme: Option<Theme>,
+    snapshot_path: PathBuf,
+    /// File to append a line to if any clock is done
+    output_on_done: Option<PathBuf>,
+    show_menu_by_content: BTreeMap<Content, bool>,
     cursor_position: Option<Position>,
+    /// Time of latest `Resize` event
+    resized_at: Option<Instant>,
+    /// Message to show for a moment and the time it was received
+    flash: Option<(String, Instant)>,
+    settings: SettingsState,
+    help: HelpState,
+    command: CommandState,
+    app_tx: events::AppEventTx,
 }
 
 pub struct AppArgs {
     pub style: Style,
     pub with_decis: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
+    pub urgency_colors: bool,
+    pub status_line: bool,
+    pub timer_wall: bool,
+    pub breathe: bool,
+    pub show_initial: bool,
+    pub seconds_only: bool,
+    pub bar: bool,
+    pub show_uptime: bool,
+    #[cfg(feature = "keep-awake")]
+    pub keep_awake: bool,
+    pub set_title: bool,
+    pub spacing: Spacing,
+    pub hide_seconds_after: Option<Duration>,
+    pub local_time_style: LocalTimeStyle,
+    pub progress: Progress,
+    pub unit_labels: bool,
+    pub footer_seconds: bool,
     pub notification: Toggle,
+    pub notify_urgency: Option<NotifyUrgency>,
+    pub notify_timeout: Option<Duration>,
+    pub quiet: bool,
     pub blink: Toggle,
     pub show_menu: bool,
+    pub show_menu_by_content: BTreeMap<Content, bool>,
     pub vim_motions: bool,
     pub app_time_format: AppTimeFormat,
     pub content: Content,
@@ -78,6 +210,9 @@ pub struct AppArgs {
     pub pomodoro_round: u64,
     pub pomodoro_auto_switch: bool,
     pub pomodoro_max_rounds: Option<u64>,
+    pub pomodoro_goal: Option<u64>,
+    pub pomodoro_goal_progress: u64,
+    pub pomodoro_goal_date: Option<time::Date>,
     pub initial_value_work: Duration,
     pub current_value_work: Duration,
     pub pause_duration: PauseDuration,
@@ -85,25 +220,83 @@ pub struct AppArgs {
     pub initial_value_countdown: Duration,
     pub current_value_countdown: Duration,
     pub elapsed_value_countdown: Duration,
+    pub autostart_countdown: Option<Duration>,
+    pub done_text_countdown: Option<String>,
+    /// Absolute time to count down to
+    pub target_countdown: Option<OffsetDateTime>,
+    pub count_up_countdown: bool,
+    pub warn_secs_countdown: u64,
+    pub pause_at_countdown: Option<Duration>,
+    pub align_to_minute_countdown: bool,
+    pub minute_cue_countdown: bool,
+    pub auto_reset_after_countdown: Option<u64>,
+    pub met_countdown: bool,
+    /// Max. value of all clocks
+    pub max_duration: Duration,
+    /// Number of units to change a value by jumping in edit mode
+    pub jump_step: u32,
+    pub name_countdown: Option<String>,
+    pub sequence_countdown: Option<SequenceState>,
+    pub presets_countdown: Vec<Duration>,
+    pub other_countdowns: Vec<CountdownStorage>,
+    /// Run (`true`) or pause (`false`) clock of `content` explicitly
+    pub start_running: Option<bool>,
+    /// Stored modes to restore clocks as they were
+    pub clock_mode_countdown: Option<ClockModeStorage>,
+    pub clock_mode_timer: Option<ClockModeStorage>,
+    pub clock_mode_pomodoro: Option<ClockModeStorage>,
     pub current_value_timer: Duration,
+    /// Soft goal of timer
+    pub goal_timer: Option<Duration>,
+    /// Wraps timer to zero by reaching `max_duration`
+    pub wrap_timer: bool,
     pub event: Event,
+    pub milestones_event: Vec<Event>,
+    /// Duration to push a passed event forward by
+    pub snooze_event: Duration,
+    pub alarm: Option<time::Time>,
+    pub date_format: DateFormat,
+    pub clock: AppClock,
     pub app_tx: events::AppEventTx,
     #[cfg(feature = "sound")]
     pub sound_path: Option<PathBuf>,
+    #[cfg(feature = "sound")]
+    pub tick_sound_path: Option<PathBuf>,
+    #[cfg(feature = "sound")]
+    pub sounds: SoundsConfig,
     pub footer_toggle_app_time: Toggle,
+    pub line: bool,
+    pub zen: bool,
+    pub dim: bool,
+    pub voffset: i16,
+    pub theme: Option<Theme>,
+    pub snapshot_path: PathBuf,
+    pub output_on_done: Option<PathBuf>,
 }
 
 pub struct FromAppArgs {
     pub args: Args,
     pub stg: AppStorage,
+    pub date_format: DateFormat,
+    pub clock: AppClock,
     pub app_tx: events::AppEventTx,
+    #[cfg(feature = "sound")]
+    pub sounds: SoundsConfig,
 }
 
 /// Creates an `App` by merging `Args` and `AppStorage` (`Args` wins)
 /// and adding `AppEventTx`
 impl From<FromAppArgs> for App {
     fn from(args: FromAppArgs) -> Self {
-        let FromAppArgs { args, stg, app_tx } = args;
+        let FromAppArgs {
+            args,
+            stg,
+            date_format,
+            clock,
+            app_tx,
+            #[cfg(feature = "sound")]
+            sounds,
+        } = args;
 
         let work_from_args = args.tabata.then_some(TABATA_WORK).or(args.work);
         let pause_from_args = args
@@ -112,6 +305,49 @@ impl From<FromAppArgs> for App {
             .or(args.pause);
         let max_rounds_from_args = args.tabata.then_some(TABATA_MAX_ROUNDS).or(args.max_rounds);
 
+        // countdown to the next occurrence of a weekday/time
+        let app_time = clock.now();
+        let now = OffsetDateTime::from(app_time);
+        let target_countdown = args.countdown_target.map(|target| match app_time {
+            AppTime::Local(now) => target.next_after(now, with_local_offset),
+            AppTime::Utc(now) => target.next_after(now, |t| t),
+        });
+        let max_duration = args.max_duration.unwrap_or(MAX_DURATION);
+        let sequence_from_args = SequenceState::new(
+            args.sequence
+                .iter()
+                .map(|d| (*d).min(max_duration))
+                .collect(),
+            0,
+        );
+        let countdown_from_args = target_countdown
+            .map(|target| Duration::try_from(target - now).unwrap_or(Duration::ZERO))
+            .or(args.countdown.first().copied())
+            .or(args.countdown_file)
+            .map(|d| d.min(max_duration))
+            .or(sequence_from_args.as_ref().map(SequenceState::get_current));
+        // a stored target (if any) is used to recompute time left, which is outdated after a restart
+        let stored_target_countdown = stg
+            .target_countdown
+            .filter(|_| countdown_from_args.is_none());
+
+        // events of a file are milestones in addition to the ones of args
+        let events_file = args.events_file.unwrap_or_default();
+        for error in &events_file.errors {
+            warn!("Skipped event of events file at {error}");
+        }
+        if !events_file.errors.is_empty() {
+            _ = app_tx.send(events::AppEvent::Flash(format!(
+                "skipped {} malformed line(s) of events file",
+                events_file.errors.len()
+            )));
+        }
+        let milestones_from_args: Vec<Event> = args
+            .milestone
+            .into_iter()
+            .chain(events_file.events)
+            .collect();
+
         let is_pause_from_args = pause_from_args.is_some();
         let pause_duration = pause_from_args.unwrap_or(stg.pause_duration);
         let current_value_pause = if is_pause_from_args {
@@ -120,31 +356,62 @@ impl From<FromAppArgs> for App {
             stg.current_value_pause
         };
 
+        // Mode to start with: `--mode` always wins, regardless of any other args.
+        // W/o it, mode is inferred by other args (especially durations).
+        // If many of them are given, first match wins in following order:
+        // pomodoro, countdown, event, alarm, timer and latest stored mode at last.
+        let content = args.mode.unwrap_or({
+            if work_from_args.is_some() || is_pause_from_args {
+                Content::Pomodoro
+            } else if countdown_from_args.is_some() {
+                Content::Countdown
+            } else if args.event.is_some() || !milestones_from_args.is_empty() {
+                Content::Event
+            } else if args.alarm.is_some() {
+                Content::Alarm
+            } else if args.timer_goal.is_some() {
+                Content::Timer
+            } else {
+                stg.content
+            }
+        });
+
+        let mut show_menu_by_content = stg.show_menu_by_content;
+        if args.menu {
+            show_menu_by_content.insert(content, true);
+        }
+
         App::new(AppArgs {
-            with_decis: args.decis || stg.with_decis,
+            with_decis: args.decis || (stg.with_decis && !args.no_decis),
+            decimal_separator: args.decimal_separator.unwrap_or(stg.decimal_separator),
+            flip: args.flip,
+            urgency_colors: args.urgency_colors,
+            status_line: args.status_line,
+            timer_wall: args.timer_wall,
+            breathe: args.breathe,
+            show_initial: args.show_initial,
+            seconds_only: args.seconds_only,
+            bar: args.bar,
+            show_uptime: args.show_uptime,
+            #[cfg(feature = "keep-awake")]
+            keep_awake: args.keep_awake,
+            set_title: args.set_title,
+            spacing: args.spacing.unwrap_or_default(),
+            hide_seconds_after: args.hide_seconds_after,
+            local_time_style: args.localtime_style.unwrap_or_default(),
+            progress: args.progress.unwrap_or_default(),
+            unit_labels: !args.no_unit_labels,
+            footer_seconds: args.footer_seconds,
             show_menu: args.menu || stg.show_menu,
+            show_menu_by_content,
             vim_motions: args.vim.unwrap_or(stg.vim).into(),
             notification: args.notification.unwrap_or(stg.notification),
+            notify_urgency: args.notify_urgency,
+            notify_timeout: args.notify_timeout.map(Duration::from_millis),
+            quiet: args.quiet,
             blink: args.blink.unwrap_or(stg.blink),
             app_time_format: stg.app_time_format,
-            // Check args to set a possible mode to start with.
-            content: match args.mode {
-                Some(mode) => mode,
-                // check other args (especially durations)
-                None => {
-                    if work_from_args.is_some() || is_pause_from_args {
-                        Content::Pomodoro
-                    } else if args.countdown.is_some() {
-                        Content::Countdown
-                    } else if args.event.is_some() {
-                        Content::Event
-                    }
-                    // in other case just use latest stored state
-                    else {
-                        stg.content
-                    }
-                }
-            },
+            content,
             style: args.style.unwrap_or(stg.style),
             pomodoro_mode: stg.pomodoro_mode,
             pomodoro_round: stg.pomodoro_count,
@@ -153,25 +420,108 @@ impl From<FromAppArgs> for App {
                 // 0 -> resets `max_rounds`
                 .and_then(|n| (n > 0).then_some(n))
                 .or(stg.pomodoro_max_rounds),
+            pomodoro_goal: match args.goal {
+                // 0 -> resets `goal`
+                Some(n) => (n > 0).then_some(n),
+                None => stg.pomodoro_goal,
+            },
+            pomodoro_goal_progress: stg.pomodoro_goal_progress,
+            pomodoro_goal_date: stg.pomodoro_goal_date,
             initial_value_work: work_from_args.unwrap_or(stg.inital_value_work),
             // invalidate `current_value_work` if an initial value is set via args
             current_value_work: work_from_args.unwrap_or(stg.current_value_work),
             pause_duration,
             current_value_pause,
-            initial_value_countdown: args.countdown.unwrap_or(stg.inital_value_countdown),
+            initial_value_countdown: countdown_from_args.unwrap_or(stg.inital_value_countdown),
             // invalidate `current_value_countdown` if an initial value is set via args
-            current_value_countdown: args.countdown.unwrap_or(stg.inital_value_countdown),
-            elapsed_value_countdown: match args.countdown {
+            current_value_countdown: countdown_from_args
+                .or(stored_target_countdown.map(|target| {
+                    Duration::try_from(target - now)
+                        .unwrap_or(Duration::ZERO)
+                        .min(max_duration)
+                }))
+                .unwrap_or(stg.current_value_countdown),
+            target_countdown: target_countdown.or(stored_target_countdown),
+            elapsed_value_countdown: match countdown_from_args {
                 // reset value if countdown is set by arguments
                 Some(_) => Duration::ZERO,
                 None => stg.elapsed_value_countdown,
             },
+            autostart_countdown: args
+                .autostart_delay
+                .map(Duration::from_secs)
+                .or(args.autostart.then_some(Duration::ZERO)),
             current_value_timer: stg.current_value_timer,
+            goal_timer: args.timer_goal,
+            wrap_timer: args.timer_wrap,
+            // milestones from args replace stored ones, the same as an event from args does
+            milestones_event: if args.event.is_some() || !milestones_from_args.is_empty() {
+                milestones_from_args
+            } else {
+                stg.milestones_event
+            },
             event: args.event.unwrap_or(stg.event),
+            snooze_event: args.event_snooze.unwrap_or(DEFAULT_SNOOZE),
+            alarm: args.alarm.or(stg.alarm),
+            date_format,
+            clock,
             app_tx,
             #[cfg(feature = "sound")]
             sound_path: args.sound,
+            #[cfg(feature = "sound")]
+            tick_sound_path: args.tick_sound,
+            #[cfg(feature = "sound")]
+            sounds,
             footer_toggle_app_time: stg.footer_app_time,
+            line: args.line,
+            zen: args.zen || stg.zen,
+            dim: args.dim || stg.dim,
+            voffset: args.voffset.unwrap_or(stg.voffset),
+            theme: args.theme.or(stg.theme),
+            done_text_countdown: args.done_text,
+            count_up_countdown: args.count_up || stg.count_up_countdown,
+            warn_secs_countdown: args.warn_secs.unwrap_or(stg.warn_secs_countdown),
+            pause_at_countdown: args.pause_at.or(stg.pause_at_countdown),
+            align_to_minute_countdown: args.align_to_minute,
+            minute_cue_countdown: args.minute_cue,
+            auto_reset_after_countdown: args.auto_reset_after,
+            met_countdown: !(args.no_met || stg.no_met_countdown),
+            max_duration,
+            jump_step: args.jump_step.unwrap_or(stg.jump_step),
+            name_countdown: args.countdown_name.or(stg.name_countdown),
+            sequence_countdown: match countdown_from_args {
+                Some(_) => sequence_from_args,
+                None => SequenceState::new(stg.sequence_countdown, stg.sequence_index_countdown),
+            },
+            // a single value is no preset to choose from
+            presets_countdown: if args.countdown.len() > 1 {
+                args.countdown
+                    .iter()
+                    .map(|d| (*d).min(max_duration))
+                    .collect()
+            } else {
+                vec![]
+            },
+            other_countdowns: stg.other_countdowns,
+            start_running: if args.start_running {
+                Some(true)
+            } else if args.start_paused {
+                Some(false)
+            } else {
+                None
+            },
+            // stored modes are ignored for values set by args
+            clock_mode_countdown: stg
+                .clock_mode_countdown
+                .filter(|_| countdown_from_args.is_none()),
+            clock_mode_timer: stg.clock_mode_timer,
+            clock_mode_pomodoro: stg
+                .clock_mode_pomodoro
+                .filter(|_| work_from_args.is_none() && !is_pause_from_args),
+            snapshot_path: args
+                .snapshot
+                .unwrap_or_else(|| PathBuf::from(SNAPSHOT_FILE_DEFAULT)),
+            output_on_done: args.output_on_done,
         })
     }
 }
@@ -181,6 +531,7 @@ impl App {
         let AppArgs {
             style,
             show_menu,
+            show_menu_by_content,
             vim_motions,
             app_time_format,
             initial_value_work,
@@ -190,37 +541,128 @@ impl App {
             current_value_pause,
             current_value_countdown,
             elapsed_value_countdown,
+            autostart_countdown,
+            done_text_countdown,
+            target_countdown,
+            count_up_countdown,
+            warn_secs_countdown,
+            pause_at_countdown,
+            align_to_minute_countdown,
+            minute_cue_countdown,
+            auto_reset_after_countdown,
+            met_countdown,
+            max_duration,
+            jump_step,
+            name_countdown,
+            sequence_countdown,
+            presets_countdown,
+            other_countdowns,
+            start_running,
+            clock_mode_countdown,
+            clock_mode_timer,
+            clock_mode_pomodoro,
             current_value_timer,
+            goal_timer,
+            wrap_timer,
             content,
             with_decis,
+            decimal_separator,
+            flip,
+            urgency_colors,
+            status_line,
+            timer_wall,
+            breathe,
+            show_initial,
+            seconds_only,
+            bar,
+            show_uptime,
+            #[cfg(feature = "keep-awake")]
+            keep_awake,
+            set_title,
+            spacing,
+            hide_seconds_after,
+            local_time_style,
+            progress,
+            unit_labels,
+            footer_seconds,
             pomodoro_mode,
             pomodoro_round,
             pomodoro_auto_switch,
             pomodoro_max_rounds,
+            pomodoro_goal,
+            pomodoro_goal_progress,
+            pomodoro_goal_date,
             event,
+            milestones_event,
+            snooze_event,
+            alarm,
+            date_format,
+            clock,
             notification,
+            notify_urgency,
+            notify_timeout,
+            quiet,
             blink,
             app_tx,
             footer_toggle_app_time,
+            line,
+            zen,
+            dim,
+            voffset,
+            theme,
+            snapshot_path,
+            output_on_done,
             #[cfg(feature = "sound")]
             sound_path,
+            #[cfg(feature = "sound")]
+            tick_sound_path,
+            #[cfg(feature = "sound")]
+            sounds,
         } = args;
-        let app_time = AppTime::new();
+        let app_time = clock.now();
 
         #[cfg(feature = "sound")]
         let sound = sound_path.and_then(|path| Sound::new(path).ok());
+        #[cfg(feature = "sound")]
+        let tick_sound = tick_sound_path.and_then(|path| Sound::new(path).ok());
+        #[cfg(feature = "sound")]
+        let sounds = Sounds::new(&sounds);
 
-        Self {
+        let mut app = Self {
             mode: Mode::Running,
             notification,
+            notify_urgency,
+            notify_timeout,
+            quiet,
             blink,
             #[cfg(feature = "sound")]
             sound,
+            #[cfg(feature = "sound")]
+            tick_sound,
+            #[cfg(feature = "sound")]
+            sounds,
             content,
             app_time,
             app_time_format,
             style,
             with_decis,
+            decimal_separator,
+            flip,
+            urgency_colors,
+            status_line,
+            timer_wall,
+            breathe,
+            show_initial,
+            seconds_only,
+            bar,
+            show_uptime,
+            set_title,
+            spacing,
+            hide_seconds_after,
+            local_time_style,
+            progress,
+            unit_labels,
+            footer_seconds,
             vim_motions,
             countdown: CountdownState::new(CountdownStateArgs {
                 initial_value: initial_value_countdown,
@@ -236,7 +678,21 @@ impl App {
                 with_decis,
                 app_tx: app_tx.clone(),
                 vim_motions,
+                autostart: autostart_countdown,
+                done_text: done_text_countdown,
+                target: target_countdown,
+                count_up: count_up_countdown,
+                warn_secs: warn_secs_countdown,
+                pause_at: pause_at_countdown,
+                minute_cue: minute_cue_countdown,
+                auto_reset_after: auto_reset_after_countdown,
+                align_to_minute: align_to_minute_countdown,
+                met: met_countdown,
+                name: name_countdown,
+                sequence: sequence_countdown,
+                presets: presets_countdown,
             }),
+            other_countdowns: VecDeque::new(),
             timer: TimerState::new(
                 ClockState::<clock::Timer>::new(ClockStateArgs {
                     initial_value: Duration::ZERO,
@@ -245,9 +701,14 @@ impl App {
                     with_decis,
                     app_tx: Some(app_tx.clone()),
                 })
-                .with_name(ClockName::from("Timer")),
+                .with_name(ClockName::from("Timer"))
+                .with_max_value(max_duration)
+                .with_jump_step(jump_step)
+                .with_wrap(wrap_timer)
+                .with_app_time(app_time),
                 vim_motions,
-            ),
+            )
+            .with_goal(goal_timer, app_tx.clone()),
             pomodoro: PomodoroState::new(PomodoroStateArgs {
                 mode: pomodoro_mode,
                 initial_value_work,
@@ -260,14 +721,33 @@ impl App {
                 vim_motions,
                 auto_switch: pomodoro_auto_switch,
                 max_rounds: pomodoro_max_rounds,
+                goal: pomodoro_goal,
+                // progress of a previous day is ignored
+                goal_progress: if pomodoro_goal_date == Some(OffsetDateTime::from(app_time).date())
+                {
+                    pomodoro_goal_progress
+                } else {
+                    0
+                },
+                goal_date: OffsetDateTime::from(app_time).date(),
+                app_time,
             }),
             local_time: LocalTimeState::new(LocalTimeStateArgs {
                 app_time,
                 app_time_format,
+                date_format: date_format.clone(),
+            }),
+            alarm: AlarmState::new(AlarmStateArgs {
+                app_time,
+                alarm,
+                with_decis,
+                app_tx: app_tx.clone(),
             }),
             event: EventState::new(EventStateArgs {
                 app_time,
                 event,
+                milestones: milestones_event,
+                snooze: snooze_event,
                 with_decis,
                 app_tx: app_tx.clone(),
                 event_time_format: if footer_toggle_app_time == Toggle::On {
@@ -275,9 +755,14 @@ impl App {
                 } else {
                     None
                 },
+                date_format,
             }),
             footer: FooterState::new(
-                show_menu,
+                // visibility of menu depends on content
+                show_menu_by_content
+                    .get(&content)
+                    .copied()
+                    .unwrap_or(show_menu),
                 if footer_toggle_app_time == Toggle::On {
                     Some(app_time_format)
                 } else {
@@ -285,8 +770,63 @@ impl App {
                 },
                 vim_motions,
             ),
+            line,
+            zen,
+            dim,
+            voffset,
+            theme,
+            snapshot_path,
+            output_on_done,
+            show_menu_by_content,
             cursor_position: None,
+            resized_at: None,
+            flash: None,
+            launched_at: app_time,
+            #[cfg(feature = "keep-awake")]
+            keep_awake: keep_awake.then(KeepAwake::default),
+            #[cfg(feature = "clipboard")]
+            clipboard: None,
+            title: None,
+            max_voffset: i16::MAX,
+            settings: SettingsState::default(),
+            help: HelpState::default(),
+            command: CommandState::default(),
+            app_tx,
+            clock,
+        };
+
+        app.countdown.set_max_value(max_duration);
+        app.countdown.set_jump_step(jump_step);
+        app.pomodoro.set_max_value(max_duration);
+        app.pomodoro.set_jump_step(jump_step);
+        let other_countdowns = other_countdowns
+            .into_iter()
+            .map(|stg| app.new_countdown(stg))
+            .collect();
+        app.other_countdowns = other_countdowns;
+
+        // restore stored modes over inferred ones
+        if let Some(running) = clock_mode_countdown.and_then(ClockModeStorage::running) {
+            app.countdown.set_running(running);
+        }
+        if let Some(running) = clock_mode_timer.and_then(ClockModeStorage::running) {
+            app.timer.set_running(running);
+        }
+        if let Some(running) = clock_mode_pomodoro.and_then(ClockModeStorage::running) {
+            app.pomodoro.set_running(running);
+        }
+
+        // explicit mode of current clock has precedence over its inferred (or stored) mode
+        if let Some(running) = start_running {
+            match app.content {
+                Content::Countdown => app.countdown.set_running(running),
+                Content::Timer => app.timer.set_running(running),
+                Content::Pomodoro => app.pomodoro.set_running(running),
+                Content::Event | Content::LocalTime | Content::Alarm => {}
+            }
         }
+
+        app
     }
 
     pub async fn run(
@@ -297,142 +837,285 @@ impl App {
         // Closure to handle `KeyEvent`'s
         let handle_key_event = |app: &mut Self, key: KeyEvent| {
             debug!("Received key {:?}", key.code);
+            let switch_countdowns = app.content == Content::Countdown
+                && matches!(app.get_edit_mode(), AppEditMode::None);
             match key.code {
                 KeyCode::Char('q') => app.mode = Mode::Quit,
-                KeyCode::Char('1') => app.content = Content::Countdown,
-                KeyCode::Char('2') => app.content = Content::Timer,
-                KeyCode::Char('3') => app.content = Content::Pomodoro,
-                KeyCode::Char('4') => app.content = Content::Event,
-                KeyCode::Char('5') => app.content = Content::LocalTime,
+                KeyCode::Char('1') => app.set_content(Content::Countdown),
+                KeyCode::Char('2') => app.set_content(Content::Timer),
+                KeyCode::Char('3') => app.set_content(Content::Pomodoro),
+                KeyCode::Char('4') => app.set_content(Content::Event),
+                KeyCode::Char('5') => app.set_content(Content::LocalTime),
+                KeyCode::Char('6') => app.set_content(Content::Alarm),
                 // switch `screens`
                 KeyCode::Right if !app.vim_motions => {
-                    app.content = app.content.next();
+                    app.set_content(app.content.next());
                 }
                 KeyCode::Char('l') if app.vim_motions => {
-                    app.content = app.content.next();
+                    app.set_content(app.content.next());
                 }
                 KeyCode::Left if !app.vim_motions => {
-                    app.content = app.content.prev();
+                    app.set_content(app.content.prev());
                 }
                 KeyCode::Char('h') if app.vim_motions => {
-                    app.content = app.content.prev();
+                    app.set_content(app.content.prev());
                 }
+                // multiple countdowns
+                KeyCode::Tab if switch_countdowns => app.next_countdown(),
+                KeyCode::BackTab if switch_countdowns => app.prev_countdown(),
+                KeyCode::Char('n') if switch_countdowns => app.add_countdown(),
+                KeyCode::Char('x') if switch_countdowns => app.remove_countdown(),
+                // command line (vim)
+                KeyCode::Char(':') if app.vim_motions => app.command.open(),
                 // toogle app time format
-                KeyCode::Char(':') => {
-                    if app.content == Content::LocalTime {
-                        // For LocalTime content: just cycle through formats
-                        app.app_time_format = app.app_time_format.next();
-                        app.local_time.set_app_time_format(app.app_time_format);
-                        // Only update footer if it's currently showing time
-                        if app.footer.app_time_format().is_some() {
-                            app.footer.set_app_time_format(Some(app.app_time_format));
-                        }
-                    } else {
-                        // For other content: allow footer to toggle between formats and None
-                        let new_format = match app.footer.app_time_format() {
-                            // footer is hidden -> show first format
-                            None => Some(AppTimeFormat::first()),
-                            Some(v) => {
-                                if v != &AppTimeFormat::last() {
-                                    Some(v.next())
-                                } else {
-                                    // reached last format -> hide footer time
-                                    None
-                                }
-                            }
-                        };
-
-                        if let Some(format) = new_format {
-                            app.app_time_format = format;
-                            app.local_time.set_app_time_format(format);
-                        }
-                        app.footer.set_app_time_format(new_format);
-                        app.countdown.set_app_time_format(new_format);
-                        app.event.set_app_time_format(new_format);
-                    }
-                }
-                KeyCode::Char(',') => {
-                    app.style = app.style.next();
+                KeyCode::Char(':') => app.toggle_app_time_format(true),
+                KeyCode::Char(';') => app.toggle_app_time_format(false),
+                KeyCode::Char(',') => app.change_setting(SettingsItem::Style),
+                KeyCode::Char('<') => app.style = app.style.prev(),
+                KeyCode::Char('.') => app.change_setting(SettingsItem::Decis),
+                // move content up / down
+                KeyCode::Char('+') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    app.move_content(1)
                 }
-                KeyCode::Char('.') => {
-                    app.with_decis = !app.with_decis;
-                    // update clocks
-                    app.timer.set_with_decis(app.with_decis);
-                    app.countdown.set_with_decis(app.with_decis);
-                    app.pomodoro.set_with_decis(app.with_decis);
-                    app.event.set_with_decis(app.with_decis);
+                KeyCode::Char('-') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    app.move_content(-1)
                 }
                 // toogle menu
-                KeyCode::Char('m') => app.footer.set_show_menu(!app.footer.get_show_menu()),
+                KeyCode::Char('m') => app.toggle_menu(),
+                // toggle settings
+                KeyCode::Char('S') => app.settings.toggle(),
+                #[cfg(feature = "clipboard")]
+                KeyCode::Char('y') => app.copy_to_clipboard(),
+                // toggle zen mode
+                KeyCode::Char('f') => app.zen = !app.zen,
+                // toggle help
+                KeyCode::F(1) => app.help.toggle(),
+                // `h` is used by vim motions (see above)
+                KeyCode::Char('h') if !app.vim_motions => app.help.toggle(),
+                KeyCode::F(12) => app.snapshot(),
                 _ => {}
             };
         };
+        // Closure to handle `KeyEvent`'s while settings are shown
+        let handle_settings_key_event = |app: &mut Self, key: KeyEvent| {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => app.settings.prev(),
+                KeyCode::Down | KeyCode::Char('j') => app.settings.next(),
+                KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Char('h')
+                | KeyCode::Char('l')
+                | KeyCode::Char(' ')
+                | KeyCode::Enter => app.change_setting(app.settings.get_selected()),
+                KeyCode::Esc | KeyCode::Char('S') => app.settings.hide(),
+                KeyCode::Char('q') => app.mode = Mode::Quit,
+                _ => {}
+            };
+        };
+        // Closure to handle `KeyEvent`'s while command line is shown
+        let handle_command_key_event = |app: &mut Self, key: KeyEvent| {
+            match app.command.update(key) {
+                Some(Ok(command)) => app.run_command(command),
+                Some(Err(err)) => {
+                    _ = app.app_tx.send(events::AppEvent::Flash(err));
+                }
+                None => {}
+            }
+            if !app.command.is_shown() {
+                app.cursor_position = None;
+            }
+        };
+        // Closure to handle `KeyEvent`'s while help is shown
+        let handle_help_key_event = |app: &mut Self, key: KeyEvent| {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => app.help.scroll_up(),
+                KeyCode::Down | KeyCode::Char('j') => app.help.scroll_down(),
+                // any other key closes help
+                _ => app.help.hide(),
+            };
+        };
+        // Closure to handle `MouseEvent`'s
+        let handle_mouse_event = |app: &mut Self, mouse: MouseEvent| {
+            debug!("Received mouse {:?}", mouse.kind);
+            // footer is hidden in zen mode
+            if mouse.kind == MouseEventKind::Down(MouseButton::Left) && !app.zen {
+                match app
+                    .footer
+                    .get_item_at(Position::new(mouse.column, mouse.row))
+                {
+                    Some(FooterItem::Menu) => app.toggle_menu(),
+                    Some(FooterItem::Content(content)) => app.set_content(content),
+                    None => {}
+                }
+            }
+        };
         // Closure to handle `TuiEvent`'s
         // It returns a flag (bool) whether the app needs to be re-drawn or not
         let handle_tui_events = |app: &mut Self, event: events::TuiEvent| -> Result<bool> {
+            // Check before (!!) handling the event to catch the latest change of a clock as well
+            let was_ticking = app.is_ticking();
             if matches!(event, events::TuiEvent::Tick) {
-                app.app_time = AppTime::new();
-                app.countdown.set_app_time(app.app_time);
-                app.local_time.set_app_time(app.app_time);
-                app.event.set_app_time(app.app_time);
-            }
-
-            // Pipe events into subviews and handle only 'unhandled' events afterwards
-            let unhandled = match app.content {
-                Content::Countdown => app.countdown.update(event.clone()),
-                Content::Timer => app.timer.update(event.clone()),
-                Content::Pomodoro => app.pomodoro.update(event.clone()),
-                Content::Event => app.event.update(event.clone()),
-                Content::LocalTime => app.local_time.update(event.clone()),
+                app.update_app_time();
+            }
+
+            // Any key acknowledges a playing (notification) sound to fade it out
+            #[cfg(feature = "sound")]
+            if let events::TuiEvent::Crossterm(CrosstermEvent::Key(_)) = event {
+                app.stop_sounds();
+            }
+
+            // Help (if shown) gets all key events
+            if app.help.is_shown()
+                && let events::TuiEvent::Crossterm(CrosstermEvent::Key(key)) = event
+            {
+                handle_help_key_event(app, key);
+                return Ok(true);
+            }
+
+            // Settings (if shown) get all key events
+            if app.settings.is_shown()
+                && let events::TuiEvent::Crossterm(CrosstermEvent::Key(key)) = event
+            {
+                handle_settings_key_event(app, key);
+                return Ok(true);
+            }
+
+            // Command line (if shown) gets all key events
+            if app.command.is_shown()
+                && let events::TuiEvent::Crossterm(CrosstermEvent::Key(key)) = event
+            {
+                handle_command_key_event(app, key);
+                return Ok(true);
+            }
+
+            // Pipe events into subviews and handle only 'unhandled' events afterwards.
+            // `Tick` goes to all clocks (to keep them ticking in background),
+            // any other event goes to current content only.
+            let unhandled = if matches!(event, events::TuiEvent::Tick) {
+                app.tick();
+                None
+            } else {
+                match app.content {
+                    Content::Countdown => app.countdown.update(event.clone()),
+                    Content::Timer => app.timer.update(event.clone()),
+                    Content::Pomodoro => app.pomodoro.update(event.clone()),
+                    Content::Event => app.event.update(event.clone()),
+                    Content::LocalTime => app.local_time.update(event.clone()),
+                    Content::Alarm => app.alarm.update(event.clone()),
+                }
             };
-            // from all 'unhandled' events we are interested in `CrosstermEvent::Key` only
-            if let Some(events::TuiEvent::Crossterm(CrosstermEvent::Key(key))) = unhandled {
-                handle_key_event(app, key);
+            // from all 'unhandled' events we are interested in `CrosstermEvent::Key` and `MouseEvent` only
+            match unhandled {
+                Some(events::TuiEvent::Crossterm(CrosstermEvent::Key(key))) => {
+                    handle_key_event(app, key)
+                }
+                Some(events::TuiEvent::Mouse(mouse)) => handle_mouse_event(app, mouse),
+                _ => {}
             }
 
-            // Trigger re-draw for specific events only.
-            let trigger_redraw = matches!(
+            // Debounce bursts of `Resize` events (e.g. while dragging a terminal window).
+            // Re-draw is skipped until resizing has been settled.
+            // Next `Tick` will re-draw it afterwards.
+            if matches!(
                 event,
-                events::TuiEvent::Tick
-                    | events::TuiEvent::Crossterm(CrosstermEvent::Key(_))
-                    | events::TuiEvent::Crossterm(CrosstermEvent::Resize(_, _))
-            );
+                events::TuiEvent::Crossterm(CrosstermEvent::Resize(_, _))
+            ) {
+                app.resized_at = Some(Instant::now());
+            }
+            let is_resizing = app
+                .resized_at
+                .is_some_and(|t| t.elapsed() < RESIZE_DEBOUNCE);
+            let resize_settled = !is_resizing && app.resized_at.is_some();
+            if !is_resizing {
+                app.resized_at = None;
+            }
+
+            // Trigger re-draw for specific events only.
+            // `Tick` re-draws if something has been changed by it only.
+            let trigger_redraw = !is_resizing
+                && match event {
+                    events::TuiEvent::Tick => resize_settled || was_ticking || app.is_ticking(),
+                    events::TuiEvent::Crossterm(CrosstermEvent::Key(_))
+                    | events::TuiEvent::Mouse(_) => true,
+                    _ => false,
+                };
             Ok(trigger_redraw)
         };
 
         // Closure to handle `AppEvent`'s
         // It returns a flag (bool) whether the app needs to be re-drawn or not
         let handle_app_events = |app: &mut Self, event: events::AppEvent| -> Result<bool> {
+            // `--output-on-done` is an interop feature, which is not silenced by `--quiet`
+            if let events::AppEvent::ClockDone(_, name, description) = &event
+                && let Err(err) = app.write_done_output(name, description.as_ref())
+            {
+                error!("output_on_done {name} error: {err}");
+            }
+
+            // `--quiet` skips all events which notify or play sounds only
+            if app.quiet
+                && matches!(
+                    event,
+                    events::AppEvent::ClockDone(..)
+                        | events::AppEvent::GoalReached(..)
+                        | events::AppEvent::PomodoroSwitched(..)
+                        | events::AppEvent::TimerGoalReached(..)
+                        | events::AppEvent::CountdownPausedAt(..)
+                )
+            {
+                return Ok(false);
+            }
+
             let mut trigger_redraw = false;
             match event {
                 events::AppEvent::ClockDone(type_id, name, description) => {
                     debug!("AppEvent::ClockDone");
-
-                    if app.notification == Toggle::On {
-                        let msg = match type_id {
-                            ClockTypeId::Timer => {
-                                format!("{name} stopped by reaching its maximum value.")
-                            }
-                            _ => match description {
-                                Some(desc) => format!("{name} {desc} done!"),
-                                None => format!("{name} done!"),
-                            },
-                        };
-                        // notification
-                        let result = notify_rust::Notification::new()
-                            .summary(&msg.to_uppercase())
-                            .show();
-                        if let Err(err) = result {
-                            error!("on_done {name} error: {err}");
+                    let msg = match type_id {
+                        ClockTypeId::Timer => {
+                            format!("{name} stopped by reaching its maximum value.")
                         }
+                        _ => match description {
+                            Some(desc) => format!("{name} {desc} done!"),
+                            None => format!("{name} done!"),
+                        },
                     };
+                    app.notify_and_play(&msg, SoundKind::Done);
+                }
+                events::AppEvent::GoalReached(name, goal) => {
+                    debug!("AppEvent::GoalReached");
+                    let msg = format!("{name} goal of {goal} rounds reached!");
+                    app.notify_and_play(&msg, SoundKind::Done);
+                }
+                events::AppEvent::PomodoroSwitched(mode, special_pause) => {
+                    debug!("AppEvent::PomodoroSwitched {mode} {special_pause}");
 
-                    #[cfg(feature = "sound")]
-                    if let Some(sound) = &app.sound {
-                        if let Err(err) = sound.play() {
-                            error!("Sound error: {:?}", err);
-                        }
-                    }
+                    app.play_sound(match mode {
+                        PomodoroMode::Work => SoundKind::WorkStart,
+                        PomodoroMode::Pause if special_pause => SoundKind::LongPause,
+                        PomodoroMode::Pause => SoundKind::PauseStart,
+                    });
+                }
+                events::AppEvent::TimerGoalReached(goal) => {
+                    debug!("AppEvent::TimerGoalReached");
+                    let msg = format!("Timer goal of {} reached!", DurationEx::from(goal));
+                    app.notify_and_play(&msg, SoundKind::Done);
+                }
+                events::AppEvent::CountdownPausedAt(name, value) => {
+                    debug!("AppEvent::CountdownPausedAt");
+                    let msg = format!("{name} paused at {} left.", DurationEx::from(value));
+                    app.notify_and_play(&msg, SoundKind::Done);
+                }
+                events::AppEvent::CountdownMinuteCue(name, value) => {
+                    debug!("AppEvent::CountdownMinuteCue");
+
+                    let msg = format!("{name} {} left", DurationEx::from(value));
+                    app.flash = Some((msg.to_lowercase(), Instant::now()));
+                    trigger_redraw = true;
+                    app.play_tick_sound();
+                }
+                events::AppEvent::Flash(message) => {
+                    app.flash = Some((message, Instant::now()));
+                    trigger_redraw = true;
                 }
                 events::AppEvent::SetCursor(position) => {
                     app.cursor_position = position;
@@ -462,10 +1145,466 @@ impl App {
         Ok(self)
     }
 
+    /// Renders a single frame and holds it until any key is pressed or `PREVIEW_TIMEOUT` is reached.
+    /// No events are handled (e.g. no `Tick`).
+    pub fn preview(mut self, terminal: &mut Terminal) -> Result<()> {
+        self.draw(terminal)?;
+        let started = Instant::now();
+        while let Some(timeout) = PREVIEW_TIMEOUT.checked_sub(started.elapsed()) {
+            if crossterm::event::poll(timeout)?
+                && let CrosstermEvent::Key(_) = crossterm::event::read()?
+            {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves content by `delta` lines (down if positive), but keeps it on-screen
+    fn move_content(&mut self, delta: i16) {
+        self.voffset = self
+            .voffset
+            .saturating_add(delta)
+            .clamp(-self.max_voffset, self.max_voffset);
+    }
+
+    /// Area to render content into, centered `voffset` lines below (or above) center of `area`
+    fn content_area(&mut self, area: Rect) -> Rect {
+        self.max_voffset = (area.height.saturating_sub(MIN_CONTENT_HEIGHT) / 2) as i16;
+        let voffset = self.voffset.clamp(-self.max_voffset, self.max_voffset);
+        // trimming twice the offset from one side moves the center by it
+        let trim = voffset.unsigned_abs() * 2;
+        let height = area.height - trim;
+        if voffset > 0 {
+            Rect {
+                y: area.y + trim,
+                height,
+                ..area
+            }
+        } else {
+            Rect { height, ..area }
+        }
+    }
+
+    fn toggle_menu(&mut self) {
+        let show_menu = !self.footer.get_show_menu();
+        self.footer.set_show_menu(show_menu);
+        self.show_menu_by_content.insert(self.content, show_menu);
+    }
+
+    /// Switches content and restores its menu visibility (if any)
+    fn set_content(&mut self, content: Content) {
+        self.content = content;
+        if let Some(show_menu) = self.show_menu_by_content.get(&content) {
+            self.footer.set_show_menu(*show_menu);
+        }
+    }
+
     fn is_running(&self) -> bool {
         self.mode != Mode::Quit
     }
 
+    /// Updates `app_time` by `clock` and passes it to all contents
+    fn update_app_time(&mut self) {
+        self.app_time = self.clock.now();
+        self.countdown.set_app_time(self.app_time);
+        for countdown in self.other_countdowns.iter_mut() {
+            countdown.set_app_time(self.app_time);
+        }
+        self.timer.set_app_time(self.app_time);
+        self.local_time.set_app_time(self.app_time);
+        self.event.set_app_time(self.app_time);
+        self.alarm.set_app_time(self.app_time);
+        self.pomodoro.set_app_time(self.app_time);
+    }
+
+    /// Ticks clocks of all contents, not only of the current one.
+    /// `Event`, `LocalTime` and `Alarm` are driven by `app_time` and don't need any `Tick`.
+    fn tick(&mut self) {
+        let secs_before = self.running_clock_secs();
+
+        self.countdown.update(events::TuiEvent::Tick);
+        for countdown in self.other_countdowns.iter_mut() {
+            countdown.update(events::TuiEvent::Tick);
+        }
+        self.timer.update(events::TuiEvent::Tick);
+        self.pomodoro.update(events::TuiEvent::Tick);
+        // remove an outdated flash message
+        if self
+            .flash
+            .as_ref()
+            .is_some_and(|(_, at)| at.elapsed() >= FLASH_DURATION)
+        {
+            self.flash = None;
+        }
+
+        // acquire or release inhibitor of display sleep
+        #[cfg(feature = "keep-awake")]
+        {
+            let any_running = self.is_any_clock_running();
+            if let Some(keep_awake) = &mut self.keep_awake {
+                keep_awake.set(any_running);
+            }
+        }
+
+        // at every whole-second boundary
+        if let (Some(before), Some(after)) = (secs_before, self.running_clock_secs())
+            && before != after
+        {
+            // tick sound (a countdown with `--minute-cue` ticks by each whole minute only)
+            if !(self.content == Content::Countdown && self.countdown.is_minute_cue()) {
+                self.play_tick_sound();
+            }
+        }
+    }
+
+    /// Shows a desktop notification of given message (if enabled) and plays sound of given kind (if configured)
+    fn notify_and_play(&mut self, msg: &str, kind: SoundKind) {
+        if self.notification == Toggle::On
+            && let Err(err) = self.new_notification(&msg.to_uppercase()).show()
+        {
+            error!("Notification \"{msg}\" error: {err}");
+        }
+        self.play_sound(kind);
+    }
+
+    /// Plays `--tick-sound` (if any) unless `--quiet`. Nothing happens w/o `sound` feature.
+    fn play_tick_sound(&mut self) {
+        #[cfg(feature = "sound")]
+        if !self.quiet
+            && let Some(sound) = &mut self.tick_sound
+            && let Err(err) = sound.play()
+        {
+            error!("Tick sound error: {:?}", err);
+        }
+    }
+
+    /// Plays sound of given kind (if configured). Nothing happens w/o `sound` feature.
+    /// `Done` prefers `--sound` (args over config), `LongPause` falls back to `PauseStart`.
+    #[cfg_attr(not(feature = "sound"), allow(unused_variables))]
+    fn play_sound(&mut self, kind: SoundKind) {
+        #[cfg(feature = "sound")]
+        {
+            let sound = match kind {
+                SoundKind::Done => self.sound.as_mut().or(self.sounds.done.as_mut()),
+                SoundKind::WorkStart => self.sounds.work_start.as_mut(),
+                SoundKind::PauseStart => self.sounds.pause_start.as_mut(),
+                SoundKind::LongPause => self
+                    .sounds
+                    .long_pause
+                    .as_mut()
+                    .or(self.sounds.pause_start.as_mut()),
+            };
+            if let Some(sound) = sound
+                && let Err(err) = sound.play()
+            {
+                error!("Sound error: {:?}", err);
+            }
+        }
+    }
+
+    /// Desktop notification with `--notify-urgency` and `--notify-timeout` applied (if any)
+    fn new_notification(&self, summary: &str) -> notify_rust::Notification {
+        let mut notification = notify_rust::Notification::new();
+        notification.summary(summary);
+        #[cfg(not(target_os = "macos"))]
+        if let Some(urgency) = self.notify_urgency {
+            notification.urgency(urgency.into());
+        }
+        if let Some(timeout) = self.notify_timeout {
+            notification.timeout(timeout);
+        }
+        notification
+    }
+
+    /// Fades out all playing sounds (except tick sound)
+    #[cfg(feature = "sound")]
+    fn stop_sounds(&mut self) {
+        let Sounds {
+            work_start,
+            pause_start,
+            long_pause,
+            done,
+        } = &mut self.sounds;
+        for sound in [&mut self.sound, work_start, pause_start, long_pause, done]
+            .into_iter()
+            .flatten()
+        {
+            sound.stop();
+        }
+    }
+
+    /// Whole seconds of the running clock of current content (if any)
+    fn running_clock_secs(&self) -> Option<u64> {
+        self.running_clock_value().map(|value| value.as_secs())
+    }
+
+    /// Whether a clock of any content is running (not of current content only)
+    #[cfg(feature = "keep-awake")]
+    fn is_any_clock_running(&self) -> bool {
+        self.countdown.get_clock().is_running()
+            || self
+                .other_countdowns
+                .iter()
+                .any(|countdown| countdown.get_clock().is_running())
+            || self.timer.get_clock().is_running()
+            || self.pomodoro.get_clock().is_running()
+    }
+
+    /// Value of the running clock of current content (if any)
+    fn running_clock_value(&self) -> Option<Duration> {
+        let value = match self.content {
+            Content::Countdown if self.countdown.get_clock().is_running() => {
+                self.countdown.get_clock().get_current_value()
+            }
+            Content::Timer if self.timer.get_clock().is_running() => {
+                self.timer.get_clock().get_current_value()
+            }
+            Content::Pomodoro if self.pomodoro.get_clock().is_running() => {
+                self.pomodoro.get_clock().get_current_value()
+            }
+            _ => return None,
+        };
+        Some(Duration::from(*value))
+    }
+
+    /// Creates a countdown with same settings as the active one
+    fn new_countdown(&self, stg: CountdownStorage) -> CountdownState {
+        let mut countdown = CountdownState::new(CountdownStateArgs {
+            initial_value: stg.initial_value,
+            current_value: stg.current_value,
+            elapsed_value: stg.elapsed_value,
+            app_time: self.app_time,
+            target_time_format: self.countdown.get_target_time_format(),
+            with_decis: self.with_decis,
+            app_tx: self.app_tx.clone(),
+            vim_motions: self.vim_motions,
+            autostart: None,
+            done_text: self.countdown.get_done_text().map(str::to_owned),
+            target: None,
+            count_up: self.countdown.is_count_up(),
+            warn_secs: self.countdown.get_warn_secs(),
+            pause_at: self.countdown.get_pause_at(),
+            minute_cue: self.countdown.is_minute_cue(),
+            auto_reset_after: self.countdown.get_auto_reset_after(),
+            align_to_minute: self.countdown.is_align_to_minute(),
+            met: self.countdown.is_met(),
+            name: stg.name,
+            sequence: None,
+            presets: self.countdown.get_presets().to_vec(),
+        });
+        countdown.set_max_value(Duration::from(*self.countdown.get_clock().get_max_value()));
+        countdown.set_jump_step(self.countdown.get_clock().get_jump_step());
+        if let Some(running) = stg.clock_mode.and_then(ClockModeStorage::running) {
+            countdown.set_running(running);
+        }
+        countdown
+    }
+
+    /// Adds a countdown (using initial value of the active one) and activates it
+    fn add_countdown(&mut self) {
+        let initial_value = Duration::from(*self.countdown.get_clock().get_initial_value());
+        let countdown = self.new_countdown(CountdownStorage {
+            name: Some(format!("Countdown {}", self.other_countdowns.len() + 2)),
+            initial_value,
+            current_value: initial_value,
+            elapsed_value: Duration::ZERO,
+            clock_mode: None,
+        });
+        let previous = std::mem::replace(&mut self.countdown, countdown);
+        self.other_countdowns.push_back(previous);
+    }
+
+    /// Removes the active countdown and activates the next one.
+    /// The last countdown can't be removed.
+    fn remove_countdown(&mut self) {
+        if let Some(next) = self.other_countdowns.pop_front() {
+            self.countdown = next;
+        }
+    }
+
+    fn next_countdown(&mut self) {
+        if let Some(next) = self.other_countdowns.pop_front() {
+            let previous = std::mem::replace(&mut self.countdown, next);
+            self.other_countdowns.push_back(previous);
+        }
+    }
+
+    fn prev_countdown(&mut self) {
+        if let Some(prev) = self.other_countdowns.pop_back() {
+            let previous = std::mem::replace(&mut self.countdown, prev);
+            self.other_countdowns.push_front(previous);
+        }
+    }
+
+    /// Runs a `Command` entered in command line
+    fn run_command(&mut self, command: Command) {
+        match command {
+            Command::Countdown(value) => {
+                // restart active countdown by new value
+                self.countdown = self.new_countdown(CountdownStorage {
+                    name: self.countdown.get_name().map(str::to_owned),
+                    initial_value: value,
+                    current_value: value,
+                    elapsed_value: Duration::ZERO,
+                    clock_mode: None,
+                });
+                self.set_content(Content::Countdown);
+            }
+            Command::Style(style) => self.style = style,
+            Command::Event(event) => {
+                self.event.set_event(event);
+                self.set_content(Content::Event);
+            }
+            Command::Mode(content) => self.set_content(content),
+            Command::Quit => self.mode = Mode::Quit,
+        }
+    }
+
+    /// Cycles through app time formats (forward or backward).
+    /// Except for `LocalTime`, it shows or hides app time in footer after last (or first) format.
+    fn toggle_app_time_format(&mut self, forward: bool) {
+        if self.content == Content::LocalTime {
+            // For LocalTime content: just cycle through formats
+            self.app_time_format = if forward {
+                self.app_time_format.next()
+            } else {
+                self.app_time_format.prev()
+            };
+            self.local_time.set_app_time_format(self.app_time_format);
+            // Only update footer if it's currently showing time
+            if self.footer.app_time_format().is_some() {
+                self.footer.set_app_time_format(Some(self.app_time_format));
+            }
+        } else {
+            // For other content: allow footer to toggle between formats and None
+            let (first, last) = if forward {
+                (AppTimeFormat::first(), AppTimeFormat::last())
+            } else {
+                (AppTimeFormat::last(), AppTimeFormat::first())
+            };
+            let new_format = match self.footer.app_time_format() {
+                // footer is hidden -> show first format
+                None => Some(first),
+                Some(v) => {
+                    if v != &last {
+                        Some(if forward { v.next() } else { v.prev() })
+                    } else {
+                        // reached last format -> hide footer time
+                        None
+                    }
+                }
+            };
+
+            if let Some(format) = new_format {
+                self.app_time_format = format;
+                self.local_time.set_app_time_format(format);
+            }
+            self.footer.set_app_time_format(new_format);
+            self.countdown.set_app_time_format(new_format);
+            for countdown in self.other_countdowns.iter_mut() {
+                countdown.set_app_time_format(new_format);
+            }
+            self.event.set_app_time_format(new_format);
+        }
+    }
+
+    fn change_setting(&mut self, item: SettingsItem) {
+        match item {
+            SettingsItem::Style => {
+                self.style = self.style.next();
+            }
+            SettingsItem::Decis => {
+                self.with_decis = !self.with_decis;
+                // update clocks
+                self.timer.set_with_decis(self.with_decis);
+                self.countdown.set_with_decis(self.with_decis);
+                for countdown in self.other_countdowns.iter_mut() {
+                    countdown.set_with_decis(self.with_decis);
+                }
+                self.pomodoro.set_with_decis(self.with_decis);
+                self.event.set_with_decis(self.with_decis);
+                self.alarm.set_with_decis(self.with_decis);
+            }
+            SettingsItem::DecimalSeparator => {
+                self.decimal_separator = self.decimal_separator.next();
+            }
+            SettingsItem::Notification => {
+                self.notification = self.notification.toggle();
+            }
+            SettingsItem::Blink => {
+                self.blink = self.blink.toggle();
+            }
+            SettingsItem::Dim => {
+                self.dim = !self.dim;
+            }
+            SettingsItem::Met => {
+                let met = !self.countdown.is_met();
+                self.countdown.set_met(met);
+                for countdown in self.other_countdowns.iter_mut() {
+                    countdown.set_met(met);
+                }
+            }
+        }
+    }
+
+    /// Values of all `SettingsItem`'s to display
+    fn get_settings_values(&self) -> [String; SettingsItem::ALL.len()] {
+        fn name<T: ValueEnum>(value: T) -> String {
+            value
+                .to_possible_value()
+                .map(|v| v.get_name().to_owned())
+                .unwrap_or_default()
+        }
+        SettingsItem::ALL.map(|item| match item {
+            SettingsItem::Style => name(self.style),
+            SettingsItem::Decis => name(Toggle::from(self.with_decis)),
+            SettingsItem::DecimalSeparator => name(self.decimal_separator),
+            SettingsItem::Notification => name(self.notification),
+            SettingsItem::Blink => name(self.blink),
+            SettingsItem::Dim => name(Toggle::from(self.dim)),
+            SettingsItem::Met => name(Toggle::from(self.countdown.is_met())),
+        })
+    }
+
+    /// Whether current content needs to be re-drawn by next `Tick`
+    fn is_ticking(&self) -> bool {
+        // local time of footer is changing
+        self.footer.app_time_format().is_some()
+            // flash message will be removed
+            || self.flash.is_some()
+            // uptime of footer is changing
+            || self.show_uptime
+            || match self.content {
+                Content::Countdown => {
+                    self.countdown.is_ticking()
+                        || self.other_countdowns.iter().any(CountdownState::is_ticking)
+                        // time to finish of status line is changing while paused
+                        || self.status_line
+                }
+                Content::Timer => {
+                    self.timer.get_clock().is_ticking()
+                        // wall time is changing while paused
+                        || (self.timer_wall
+                            && self.timer.get_clock().get_wall_time().is_some())
+                }
+                Content::Pomodoro => {
+                    self.pomodoro.get_clock().is_ticking()
+                        // time to finish of status line is changing while paused
+                        || self.status_line
+                }
+                // time based content is changing all the time
+                Content::Event | Content::LocalTime | Content::Alarm => true,
+            }
+    }
+
+    /// Time elapsed since launch
+    fn get_uptime(&self) -> Duration {
+        (OffsetDateTime::from(self.app_time) - OffsetDateTime::from(self.launched_at))
+            .try_into()
+            .unwrap_or_default()
+    }
+
     fn get_edit_mode(&self) -> AppEditMode {
         match self.content {
             Content::Countdown => {
@@ -499,7 +1638,7 @@ impl App {
                     AppEditMode::None
                 }
             }
-            Content::LocalTime => AppEditMode::None,
+            Content::LocalTime | Content::Alarm => AppEditMode::None,
         }
     }
 
@@ -512,6 +1651,28 @@ impl App {
             Content::Event => true,
             // `LocalTime` does not use a `Clock`
             Content::LocalTime => false,
+            // Alarm runs forever (if it's set)
+            Content::Alarm => self.alarm.get_alarm().is_some(),
+        }
+    }
+
+    fn clock_is_done(&self) -> bool {
+        match self.content {
+            Content::Countdown => self.countdown.get_clock().is_done(),
+            Content::Pomodoro => self.pomodoro.get_clock().is_done(),
+            Content::Timer => self.timer.is_goal_reached(),
+            Content::Event | Content::LocalTime | Content::Alarm => false,
+        }
+    }
+
+    /// Color of `Palette` depending on state of current clock
+    fn get_palette_color(&self, palette: &Palette) -> Color {
+        if !matches!(self.get_edit_mode(), AppEditMode::None) {
+            palette.edit
+        } else if self.clock_is_done() {
+            palette.done
+        } else {
+            palette.active
         }
     }
 
@@ -522,6 +1683,7 @@ impl App {
             Content::Pomodoro => Some(self.pomodoro.get_clock().get_percentage_done()),
             Content::Event => Some(self.event.get_percentage_done()),
             Content::LocalTime => None,
+            Content::Alarm => self.alarm.get_percentage_done(),
         }
     }
 
@@ -534,6 +1696,161 @@ impl App {
                 frame.set_cursor_position(position);
             }
         })?;
+        // title is set outside of the frame, but changed only
+        if self.set_title {
+            let title = self.get_title();
+            if self.title.as_ref() != Some(&title) {
+                crossterm::execute!(terminal.backend_mut(), SetTitle(&title))?;
+                self.title = Some(title);
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends a line with finish time and label of a done clock to `--output-on-done` file (if any)
+    fn write_done_output(
+        &self,
+        name: &ClockName,
+        description: Option<&ClockDescription>,
+    ) -> Result<()> {
+        let Some(path) = &self.output_on_done else {
+            return Ok(());
+        };
+        let time = OffsetDateTime::from(self.app_time).format(&Rfc3339)?;
+        let label = match description {
+            Some(desc) => format!("{name} {desc}"),
+            None => name.to_string(),
+        };
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{time} {label}")?;
+        debug!("Done output appended to {:?}", path);
+        Ok(())
+    }
+
+    /// Current value of content as text, e.g. to render it in a single line.
+    /// Values of clocks are the same as rendered (by `ClockWidget`), but w/o mode.
+    fn get_text(&self) -> String {
+        match self.content {
+            Content::Countdown => self
+                .clock_widget()
+                .count_up(self.countdown.is_count_up())
+                .seconds_only(self.seconds_only)
+                .get_text(self.countdown.get_clock()),
+            Content::Timer => self.clock_widget().get_text(self.timer.get_clock()),
+            Content::Pomodoro => format!(
+                "{} {}",
+                self.pomodoro.get_mode().to_string().to_lowercase(),
+                self.clock_widget().get_text(self.pomodoro.get_clock())
+            ),
+            Content::Event => {
+                let duration = self.event.get_duration();
+                let prefix = if duration.is_since() {
+                    "since"
+                } else {
+                    "until"
+                };
+                let duration = DurationEx::from(Duration::from(duration));
+                if self.with_decis {
+                    format!(
+                        "{} {}",
+                        prefix,
+                        duration.to_string_with_separator(self.decimal_separator.as_char())
+                    )
+                } else {
+                    format!("{} {}", prefix, duration)
+                }
+            }
+            Content::LocalTime => self.app_time.format(&self.app_time_format),
+            Content::Alarm => match self.alarm.get_duration() {
+                Some(duration) => format!("alarm {}", DurationEx::from(duration)),
+                None => "no alarm".into(),
+            },
+        }
+    }
+
+    /// `ClockWidget` with same settings as rendered, to get its value as text
+    fn clock_widget<T: std::fmt::Debug>(&self) -> ClockWidget<T> {
+        ClockWidget::new(
+            self.style,
+            self.blink == Toggle::On,
+            self.decimal_separator,
+            self.flip,
+        )
+        .hide_seconds_after(self.hide_seconds_after)
+    }
+
+    /// Title of terminal (`--set-title`) by current value and label of current clock
+    fn get_title(&self) -> String {
+        let label = match self.content {
+            Content::Countdown => self.countdown.get_label_name().to_owned(),
+            Content::Timer => "Timer".to_owned(),
+            Content::Pomodoro => "Pomodoro".to_owned(),
+            Content::Event => self
+                .event
+                .get_event()
+                .title
+                .unwrap_or_else(|| "Event".to_owned()),
+            Content::LocalTime => "Local time".to_owned(),
+            Content::Alarm => "Alarm".to_owned(),
+        };
+        format!("⏲ {} · {label}", self.get_text())
+    }
+
+    /// Copies current value (as shown in `--line` mode) to system clipboard
+    #[cfg(feature = "clipboard")]
+    fn copy_to_clipboard(&mut self) {
+        let text = self.get_text();
+        let result = match &mut self.clipboard {
+            Some(clipboard) => clipboard.set_text(&text),
+            None => arboard::Clipboard::new().and_then(|mut clipboard| {
+                clipboard.set_text(&text)?;
+                self.clipboard = Some(clipboard);
+                Ok(())
+            }),
+        };
+        match result {
+            Ok(()) => {
+                debug!("Copied to clipboard: {text}");
+                self.flash = Some((format!("copied {text}"), Instant::now()));
+            }
+            Err(err) => error!("Clipboard error: {:?}", err),
+        }
+    }
+
+    /// Stores a snapshot of current screen and flashes its path or an error
+    fn snapshot(&mut self) {
+        let result = crossterm::terminal::size()
+            .map_err(Into::into)
+            .and_then(|(width, height)| self.save_snapshot(Rect::new(0, 0, width, height)));
+        let message = match result {
+            Ok(()) => format!("snapshot stored in {}", self.snapshot_path.display()),
+            Err(err) => {
+                error!("Snapshot error: {:?}", err);
+                format!("snapshot error: {err}")
+            }
+        };
+        self.flash = Some((message, Instant::now()));
+    }
+
+    /// Renders current screen of given size into an off-screen `Buffer` to store it as plain text
+    fn save_snapshot(&mut self, area: Rect) -> Result<()> {
+        let mut buf = Buffer::empty(area);
+        AppWidget.render(area, &mut buf, self);
+
+        let text = (area.top()..area.bottom())
+            .map(|y| {
+                let row: String = (area.left()..area.right())
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect();
+                row.trim_end().to_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&self.snapshot_path, text)?;
+        debug!("Snapshot stored in {:?}", self.snapshot_path);
         Ok(())
     }
 
@@ -541,29 +1858,70 @@ impl App {
         AppStorage {
             content: self.content,
             show_menu: self.footer.get_show_menu(),
+            show_menu_by_content: self.show_menu_by_content.clone(),
             vim: self.vim_motions.into(),
             notification: self.notification,
             blink: self.blink,
             app_time_format: self.app_time_format,
             style: self.style,
             with_decis: self.with_decis,
+            decimal_separator: self.decimal_separator,
+            zen: self.zen,
+            dim: self.dim,
+            voffset: self.voffset,
+            theme: self.theme,
+            jump_step: self.countdown.get_clock().get_jump_step(),
             pomodoro_mode: self.pomodoro.get_mode().clone(),
             pomodoro_count: self.pomodoro.get_round(),
             pomodoro_auto_switch: self.pomodoro.get_auto_switch(),
             pomodoro_max_rounds: self.pomodoro.get_max_rounds(),
+            pomodoro_goal: self.pomodoro.get_goal(),
+            pomodoro_goal_progress: self.pomodoro.get_goal_progress(),
+            pomodoro_goal_date: Some(self.pomodoro.get_goal_date()),
             inital_value_work: Duration::from(*self.pomodoro.get_clock_work().get_initial_value()),
             current_value_work: Duration::from(*self.pomodoro.get_clock_work().get_current_value()),
             pause_duration: self.pomodoro.get_pause_duration().clone(),
             current_value_pause: Duration::from(
                 *self.pomodoro.get_clock_pause().get_current_value(),
             ),
+            clock_mode_pomodoro: Some(self.pomodoro.get_clock().get_mode().into()),
             inital_value_countdown: Duration::from(*self.countdown.get_clock().get_initial_value()),
             current_value_countdown: Duration::from(
                 *self.countdown.get_clock().get_current_value(),
             ),
             elapsed_value_countdown: Duration::from(*self.countdown.get_elapsed_value()),
+            count_up_countdown: self.countdown.is_count_up(),
+            no_met_countdown: !self.countdown.is_met(),
+            warn_secs_countdown: self.countdown.get_warn_secs(),
+            pause_at_countdown: self.countdown.get_pause_at(),
+            name_countdown: self.countdown.get_name().map(str::to_owned),
+            sequence_countdown: self
+                .countdown
+                .get_sequence()
+                .map(|sequence| sequence.get_steps().to_vec())
+                .unwrap_or_default(),
+            sequence_index_countdown: self
+                .countdown
+                .get_sequence()
+                .map_or(0, SequenceState::get_index),
+            other_countdowns: self
+                .other_countdowns
+                .iter()
+                .map(|countdown| CountdownStorage {
+                    name: countdown.get_name().map(str::to_owned),
+                    initial_value: Duration::from(*countdown.get_clock().get_initial_value()),
+                    current_value: Duration::from(*countdown.get_clock().get_current_value()),
+                    elapsed_value: Duration::from(*countdown.get_elapsed_value()),
+                    clock_mode: Some(countdown.get_clock().get_mode().into()),
+                })
+                .collect(),
+            clock_mode_countdown: Some(self.countdown.get_clock().get_mode().into()),
+            target_countdown: self.countdown.get_target(),
             current_value_timer: Duration::from(*self.timer.get_clock().get_current_value()),
+            clock_mode_timer: Some(self.timer.get_clock().get_mode().into()),
             event: self.event.get_event(),
+            milestones_event: self.event.get_milestones().to_vec(),
+            alarm: self.alarm.get_alarm(),
             footer_app_time: self.footer.app_time_format().is_some().into(),
         }
     }
@@ -572,33 +1930,108 @@ impl App {
 struct AppWidget;
 
 impl AppWidget {
+    /// Renders current content in a single line (`--line` mode)
+    fn render_line(&self, area: Rect, buf: &mut Buffer, state: &mut App) {
+        let text = state.get_text();
+        Line::from(format!("⏲ {text}"))
+            .centered()
+            .render(area.centered_vertically(Constraint::Length(1)), buf);
+    }
+
     fn render_content(&self, area: Rect, buf: &mut Buffer, state: &mut App) {
         match state.content {
             Content::Timer => {
                 Timer {
                     style: state.style,
                     blink: state.blink == Toggle::On,
+                    decimal_separator: state.decimal_separator,
+                    flip: state.flip,
+                    unit_labels: state.unit_labels,
+                    status_line: state.status_line,
+                    spacing: state.spacing,
+                    hide_seconds_after: state.hide_seconds_after,
+                    wall_time: state.timer_wall,
+                    dim: state.dim,
                 }
                 .render(area, buf, &mut state.timer);
             }
-            Content::Countdown => Countdown {
-                style: state.style,
-                blink: state.blink == Toggle::On,
+            Content::Countdown => {
+                let [area, list_area] = Layout::vertical([
+                    Constraint::Fill(1),
+                    Constraint::Length(state.other_countdowns.len() as u16),
+                ])
+                .areas(area);
+                Countdown {
+                    style: state.style,
+                    blink: state.blink == Toggle::On,
+                    decimal_separator: state.decimal_separator,
+                    flip: state.flip,
+                    urgency_colors: state.urgency_colors,
+                    unit_labels: state.unit_labels,
+                    status_line: state.status_line,
+                    app_time_format: state.app_time_format,
+                    spacing: state.spacing,
+                    hide_seconds_after: state.hide_seconds_after,
+                    show_initial: state.show_initial,
+                    seconds_only: state.seconds_only,
+                    bar: state.bar,
+                    dim: state.dim,
+                }
+                .render(area, buf, &mut state.countdown);
+                CountdownList {
+                    decimal_separator: state.decimal_separator,
+                }
+                .render(list_area, buf, &mut state.other_countdowns);
             }
-            .render(area, buf, &mut state.countdown),
             Content::Pomodoro => PomodoroWidget {
                 style: state.style,
                 blink: state.blink == Toggle::On,
+                decimal_separator: state.decimal_separator,
+                flip: state.flip,
+                urgency_colors: state.urgency_colors,
+                unit_labels: state.unit_labels,
+                status_line: state.status_line,
+                app_time_format: state.app_time_format,
+                spacing: state.spacing,
+                hide_seconds_after: state.hide_seconds_after,
+                breathe: state.breathe,
+                show_initial: state.show_initial,
+                bar: state.bar,
+                dim: state.dim,
             }
             .render(area, buf, &mut state.pomodoro),
             Content::Event => EventWidget {
                 style: state.style,
                 blink: state.blink == Toggle::On,
+                decimal_separator: state.decimal_separator,
+                flip: state.flip,
+                unit_labels: state.unit_labels,
+                status_line: state.status_line,
+                app_time_format: state.app_time_format,
+                spacing: state.spacing,
+                dim: state.dim,
             }
             .render(area, buf, &mut state.event),
             Content::LocalTime => {
-                LocalTimeWidget { style: state.style }.render(area, buf, &mut state.local_time);
+                LocalTimeWidget {
+                    style: state.style,
+                    flip: state.flip,
+                    local_time_style: state.local_time_style,
+                    dim: state.dim,
+                }
+                .render(area, buf, &mut state.local_time);
+            }
+            Content::Alarm => AlarmWidget {
+                style: state.style,
+                blink: state.blink == Toggle::On,
+                decimal_separator: state.decimal_separator,
+                flip: state.flip,
+                status_line: state.status_line,
+                app_time_format: state.app_time_format,
+                spacing: state.spacing,
+                dim: state.dim,
             }
+            .render(area, buf, &mut state.alarm),
         };
     }
 }
@@ -606,20 +2039,65 @@ impl AppWidget {
 impl StatefulWidget for AppWidget {
     type State = App;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let [v0, v1, v2] = Layout::vertical([
-            Constraint::Length(1),
+        if let Some(palette) = state.theme.and_then(|theme| theme.palette()) {
+            buf.set_style(
+                area,
+                RatatuiStyle::new()
+                    .fg(state.get_palette_color(&palette))
+                    .bg(palette.background),
+            );
+        }
+        if state.line {
+            self.render_line(area, buf, state);
+            return;
+        }
+
+        let header_height = |progress: &[Progress]| {
+            if !state.zen && progress.contains(&state.progress) {
+                1
+            } else {
+                0
+            }
+        };
+        let [v0, v1, v_bottom, v2] = Layout::vertical([
+            Constraint::Length(header_height(&[Progress::Top, Progress::Gauge])),
             Constraint::Percentage(100),
-            Constraint::Length(if state.footer.get_show_menu() { 5 } else { 1 }),
+            Constraint::Length(header_height(&[Progress::Bottom])),
+            Constraint::Length(match (state.zen, state.footer.get_show_menu()) {
+                (true, _) => 0,
+                (false, true) => 5,
+                (false, false) => 1,
+            }),
         ])
         .areas(area);
 
-        // header
-        Header {
+        // header (progress)
+        let header = Header {
             percentage: state.get_percentage_done(),
+            gauge: state.progress == Progress::Gauge,
+        };
+        if state.progress == Progress::Bottom {
+            header.render(v_bottom, buf);
+        } else {
+            header.render(v0, buf);
         }
-        .render(v0, buf);
         // content
-        self.render_content(v1, buf, state);
+        let content_area = state.content_area(v1);
+        self.render_content(content_area, buf, state);
+        // flash message (if any) at the bottom of content
+        if let Some((message, _)) = &state.flash {
+            let [_, flash_area] =
+                Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(v1);
+            Line::raw(message.to_uppercase())
+                .centered()
+                .style(Modifier::BOLD)
+                .render(flash_area, buf);
+        }
+        // command line at the bottom of content (covers flash message)
+        if state.command.is_shown() {
+            CommandWidget.render(v1, buf, &mut state.command);
+            state.cursor_position = state.command.get_cursor();
+        }
         // footer
         Footer {
             running_clock: state.clock_is_running(),
@@ -628,7 +2106,563 @@ impl StatefulWidget for AppWidget {
             app_time: state.app_time,
             pomodoro_auto_switch: state.pomodoro.get_auto_switch(),
             is_tabata: state.pomodoro.is_tabata(),
+            countdown_count_up: state.countdown.is_count_up(),
+            countdown_met: state.countdown.get_clock().is_done() && state.countdown.is_met(),
+            app_time_seconds: state.footer_seconds,
+            clipboard: cfg!(feature = "clipboard"),
+            uptime: state.show_uptime.then(|| state.get_uptime()),
         }
         .render(v2, buf, &mut state.footer);
+        // settings (overlay)
+        if state.settings.is_shown() {
+            SettingsWidget {
+                values: state.get_settings_values(),
+            }
+            .render(v1, buf, &mut state.settings);
+        }
+        // help (overlay)
+        if state.help.is_shown() {
+            HelpWidget.render(v1, buf, &mut state.help);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    fn app(args: &[&str]) -> App {
+        app_with_clock(
+            args,
+            AppClock::System,
+            tokio::sync::mpsc::unbounded_channel().0,
+        )
+    }
+
+    fn app_with_clock(args: &[&str], clock: AppClock, app_tx: events::AppEventTx) -> App {
+        App::from(FromAppArgs {
+            args: Args::parse_from([&"timr-tui"].into_iter().chain(args)),
+            stg: AppStorage::default(),
+            date_format: DateFormat::default(),
+            clock,
+            app_tx,
+            #[cfg(feature = "sound")]
+            sounds: SoundsConfig::default(),
+        })
+    }
+
+    const FIXED_TIME: OffsetDateTime = time::macros::datetime!(2024-06-10 14:30:00 UTC);
+
+    #[test]
+    fn test_storage_round_trip() {
+        let stg = crate::storage::tests::stg();
+        let app = App::from(FromAppArgs {
+            args: Args::parse_from(["timr-tui"]),
+            stg: crate::storage::tests::stg(),
+            date_format: DateFormat::default(),
+            clock: AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            app_tx: tokio::sync::mpsc::unbounded_channel().0,
+            #[cfg(feature = "sound")]
+            sounds: SoundsConfig::default(),
+        });
+        assert_eq!(app.to_storage(), stg);
+    }
+
+    #[test]
+    fn test_get_text() {
+        // value as rendered, but w/o mode
+        assert_eq!(app(&["--countdown", "4:32"]).get_text(), "4:32");
+        assert_eq!(app(&["--countdown", "5:00", "--count-up"]).get_text(), "0");
+        assert_eq!(
+            app(&["--countdown", "1:40", "--seconds-only"]).get_text(),
+            "100"
+        );
+        assert_eq!(
+            app(&["--countdown", "2:30:00", "--hide-seconds-after", "1:00:00"]).get_text(),
+            "2:30"
+        );
+        let mut timer = app(&["-m", "timer", "--hide-seconds-after", "1:00:00"]);
+        assert_eq!(timer.get_text(), "0");
+        timer.timer.set_with_decis(true);
+        assert_eq!(timer.get_text(), "0.0");
+    }
+
+    #[test]
+    fn test_get_title() {
+        let mut app = app(&["--countdown", "5:00"]);
+        assert_eq!(app.get_title(), "⏲ 5:00 · Countdown");
+        app.set_content(Content::Pomodoro);
+        assert!(app.get_title().ends_with(" · Pomodoro"));
+        let app = app_with_clock(
+            &["-e", "time=2024-06-10 14:30:05,title=x", "-m", "event"],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tokio::sync::mpsc::unbounded_channel().0,
+        );
+        assert_eq!(app.get_title(), "⏲ until 5 · x");
+    }
+
+    #[test]
+    fn test_restore_clock_modes() {
+        let app_with_stg = |args: &[&str]| {
+            App::from(FromAppArgs {
+                args: Args::parse_from([&"timr-tui"].into_iter().chain(args)),
+                stg: AppStorage {
+                    current_value_countdown: Duration::from_secs(300),
+                    clock_mode_countdown: Some(ClockModeStorage::Running),
+                    current_value_timer: Duration::from_secs(60),
+                    clock_mode_timer: Some(ClockModeStorage::Running),
+                    clock_mode_pomodoro: Some(ClockModeStorage::Paused),
+                    ..AppStorage::default()
+                },
+                date_format: DateFormat::default(),
+                clock: AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+                app_tx: tokio::sync::mpsc::unbounded_channel().0,
+                #[cfg(feature = "sound")]
+                sounds: SoundsConfig::default(),
+            })
+        };
+        let app = app_with_stg(&[]);
+        assert!(app.countdown.get_clock().is_running());
+        assert!(app.timer.get_clock().is_running());
+        // `Initial` is never paused
+        assert!(app.pomodoro.get_clock().is_initial());
+        // values of args have precedence over stored modes
+        let app = app_with_stg(&["--countdown", "3:00"]);
+        assert!(app.countdown.get_clock().is_initial());
+        assert!(app.timer.get_clock().is_running());
+        // explicit mode of args has precedence over stored mode
+        let app = app_with_stg(&["--mode", "timer", "--start-paused"]);
+        assert!(app.countdown.get_clock().is_running());
+        assert_eq!(app.timer.get_clock().get_mode(), &clock::Mode::Pause);
+    }
+
+    #[test]
+    fn test_countdown_target_reopened() {
+        let app_at = |args: &[&str], stg: AppStorage, time: OffsetDateTime| {
+            App::from(FromAppArgs {
+                args: Args::parse_from([&"timr-tui"].into_iter().chain(args)),
+                stg,
+                date_format: DateFormat::default(),
+                clock: AppClock::Fixed(AppTime::Utc(time)),
+                app_tx: tokio::sync::mpsc::unbounded_channel().0,
+                #[cfg(feature = "sound")]
+                sounds: SoundsConfig::default(),
+            })
+        };
+        // Monday 14:30 -> 15:00
+        let mut app = app_at(
+            &["--countdown-target", "Mon 15:00", "--start-running"],
+            AppStorage::default(),
+            FIXED_TIME,
+        );
+        let target = time::macros::datetime!(2024-06-10 15:00 UTC);
+        assert_eq!(app.countdown.get_target(), Some(target));
+        app.tick();
+        let stg = app.to_storage();
+        assert_eq!(stg.target_countdown, Some(target));
+        // closed for 10 minutes
+        let app = app_at(&[], stg, FIXED_TIME + time::Duration::minutes(10));
+        assert_eq!(
+            Duration::from(*app.countdown.get_clock().get_current_value()),
+            Duration::from_secs(20 * 60)
+        );
+        assert!(app.countdown.get_clock().is_running());
+        assert_eq!(app.countdown.get_target(), Some(target));
+        // closed after target has been reached
+        let app = app_at(
+            &[],
+            app.to_storage(),
+            FIXED_TIME + time::Duration::minutes(45),
+        );
+        assert!(app.countdown.get_clock().is_done());
+        // countdown set by args ignores a stored target
+        let stg = AppStorage {
+            target_countdown: Some(target),
+            ..AppStorage::default()
+        };
+        let app = app_at(&["--countdown", "5:00"], stg, FIXED_TIME);
+        assert_eq!(app.countdown.get_target(), None);
+    }
+
+    #[test]
+    fn test_event_done_by_clock() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = app_with_clock(
+            &["-e", "time=2024-06-10 14:30:05,title=x"],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tx,
+        );
+        app.update_app_time();
+        assert!(rx.try_recv().is_err());
+        app.clock.advance(Duration::from_secs(5));
+        app.update_app_time();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(events::AppEvent::ClockDone(ClockTypeId::Event, ..))
+        ));
+    }
+
+    #[test]
+    fn test_event_done_once_by_clock() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = app_with_clock(
+            &[
+                "-e",
+                "time=2024-06-10 14:30:01,title=x",
+                "--milestone",
+                "time=2024-06-10 14:30:02,title=y",
+            ],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tx,
+        );
+        // tick by tick
+        for _ in 0..30 {
+            app.clock.advance(Duration::from_millis(100));
+            app.update_app_time();
+        }
+        let done: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+            .filter(|e| matches!(e, events::AppEvent::ClockDone(ClockTypeId::Event, ..)))
+            .collect();
+        // once for event and once for re-armed milestone
+        assert_eq!(
+            done,
+            [
+                events::AppEvent::ClockDone(ClockTypeId::Event, ClockName::from("x"), None),
+                events::AppEvent::ClockDone(ClockTypeId::Event, ClockName::from("y"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_file() {
+        let path = std::env::temp_dir().join("timr-tui-test-app-events");
+        fs::write(
+            &path,
+            "2024-06-10 16:00:00,z\n2024-06-10 15:00:00,y\n2024-06-10 xx\n",
+        )
+        .unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app = app_with_clock(
+            &[
+                "--milestone",
+                "time=2024-06-10 14:31:00,title=x",
+                "--events-file",
+                path.to_str().unwrap(),
+            ],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tx,
+        );
+        fs::remove_file(path).unwrap();
+        // mode is inferred by events
+        assert_eq!(app.content, Content::Event);
+        // nearest one of both, args and file
+        assert_eq!(app.event.get_event().title, Some("x".into()));
+        let titles: Vec<_> = app
+            .event
+            .get_milestones()
+            .iter()
+            .map(|e| e.title.clone())
+            .collect();
+        assert_eq!(titles, vec![Some("y".into()), Some("z".into())]);
+        // malformed line is reported
+        assert_eq!(
+            rx.try_recv(),
+            Ok(events::AppEvent::Flash(
+                "skipped 1 malformed line(s) of events file".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_alarm_by_clock() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = app_with_clock(
+            &["--alarm", "14:31"],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tx,
+        );
+        app.clock.advance(Duration::from_secs(59));
+        app.update_app_time();
+        assert!(rx.try_recv().is_err());
+        app.clock.advance(Duration::from_secs(1));
+        app.update_app_time();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(events::AppEvent::ClockDone(ClockTypeId::Alarm, ..))
+        ));
+    }
+
+    #[test]
+    fn test_write_done_output() {
+        let path = std::env::temp_dir().join("timr-tui-test-output-on-done");
+        _ = fs::remove_file(&path);
+        let app = app_with_clock(
+            &["--output-on-done", path.to_str().unwrap()],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tokio::sync::mpsc::unbounded_channel().0,
+        );
+        app.write_done_output(&ClockName::from("Countdown".to_string()), None)
+            .unwrap();
+        app.write_done_output(
+            &ClockName::from("Pomodoro".to_string()),
+            Some(&ClockDescription::from("Work".to_string())),
+        )
+        .unwrap();
+        // lines are appended
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "2024-06-10T14:30:00Z Countdown\n2024-06-10T14:30:00Z Pomodoro Work\n"
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_theme() {
+        let mut app = app(&["--theme", "nord", "--countdown", "5"]);
+        let palette = Theme::Nord.palette().unwrap();
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        AppWidget.render(area, &mut buf, &mut app);
+        assert_eq!(buf[(0, 0)].bg, palette.background);
+        assert_eq!(buf[(0, 0)].fg, palette.active);
+
+        app.countdown
+            .update(crate::widgets::test_utils::Key::Edit.into());
+        AppWidget.render(area, &mut buf, &mut app);
+        assert_eq!(buf[(0, 0)].fg, palette.edit);
+        // theme is stored
+        assert_eq!(app.to_storage().theme, Some(Theme::Nord));
+    }
+
+    #[test]
+    fn test_theme_default() {
+        let mut app = app(&["--theme", "default", "--countdown", "5"]);
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        AppWidget.render(area, &mut buf, &mut app);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+        // default is stored to override a previously stored theme
+        assert_eq!(app.to_storage().theme, Some(Theme::Default));
+    }
+
+    #[test]
+    fn test_voffset() {
+        let mut app = app(&["--voffset", "-2"]);
+        let area = Rect::new(0, 0, 40, 20);
+        assert_eq!(app.content_area(area), Rect::new(0, 0, 40, 16));
+        app.move_content(5);
+        assert_eq!(app.voffset, 3);
+        assert_eq!(app.content_area(area), Rect::new(0, 6, 40, 14));
+        // clamped to keep content on-screen
+        app.move_content(10);
+        assert_eq!(app.voffset, 6);
+        assert_eq!(app.content_area(area), Rect::new(0, 12, 40, 8));
+        // stored as is
+        app.voffset = 20;
+        assert_eq!(app.content_area(area), Rect::new(0, 12, 40, 8));
+        assert_eq!(app.to_storage().voffset, 20);
+    }
+
+    #[test]
+    fn test_progress() {
+        // menu (5 lines) at the bottom
+        let area = Rect::new(0, 0, 40, 14);
+        let render = |args: &[&str]| {
+            let mut buf = Buffer::empty(area);
+            AppWidget.render(area, &mut buf, &mut app(args));
+            buf
+        };
+        let row = |buf: &Buffer, y: u16| -> String {
+            (0..area.width).map(|x| buf[(x, y)].symbol()).collect()
+        };
+        let line = "─".repeat(40);
+
+        let buf = render(&["--countdown", "5"]);
+        assert_eq!(row(&buf, 0), line);
+
+        let buf = render(&["--countdown", "5", "--progress", "bottom"]);
+        assert_ne!(row(&buf, 0), line);
+        assert_eq!(row(&buf, 8), line);
+
+        let buf = render(&["--countdown", "5", "--progress", "off"]);
+        assert_ne!(row(&buf, 0), line);
+        assert_ne!(row(&buf, 8), line);
+    }
+
+    #[test]
+    fn test_run_command() {
+        let mut app = app(&["--countdown", "10:00", "--style", "full"]);
+        app.countdown.set_running(true);
+        app.set_content(Content::Timer);
+
+        app.run_command(Command::Countdown(Duration::from_secs(300)));
+        assert_eq!(app.content, Content::Countdown);
+        let clock = app.countdown.get_clock();
+        assert_eq!(
+            Duration::from(*clock.get_initial_value()),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            Duration::from(*clock.get_current_value()),
+            Duration::from_secs(300)
+        );
+        assert!(!clock.is_running());
+
+        app.run_command(Command::Style(Style::Braille));
+        assert_eq!(app.style, Style::Braille);
+
+        let event = crate::event::parse_event("time=2025-12-25 00:00:00,title=xmas").unwrap();
+        app.run_command(Command::Event(event.clone()));
+        assert_eq!(app.content, Content::Event);
+        assert_eq!(app.event.get_event(), event);
+
+        app.run_command(Command::Mode(Content::Pomodoro));
+        assert_eq!(app.content, Content::Pomodoro);
+
+        app.run_command(Command::Quit);
+        assert!(!app.is_running());
+    }
+
+    #[test]
+    fn test_mode_precedence() {
+        let content = |args: &[&str]| app(args).content;
+        // `--mode` wins
+        assert_eq!(
+            content(&["--work", "10:00", "--mode", "timer"]),
+            Content::Timer
+        );
+        assert_eq!(
+            content(&["-c", "5:00", "-e", "2025-12-25 00:00:00", "-m", "alarm"]),
+            Content::Alarm
+        );
+        // inferred by args
+        assert_eq!(content(&["--pause", "5:00"]), Content::Pomodoro);
+        assert_eq!(content(&["--timer-goal", "1:00"]), Content::Timer);
+        // first match wins
+        assert_eq!(
+            content(&["--work", "10:00", "-c", "5:00"]),
+            Content::Pomodoro
+        );
+        assert_eq!(
+            content(&["-c", "5:00", "-e", "2025-12-25 00:00:00"]),
+            Content::Countdown
+        );
+        assert_eq!(
+            content(&["--alarm", "7:00", "--timer-goal", "1:00"]),
+            Content::Alarm
+        );
+        // stored one
+        assert_eq!(content(&[]), AppStorage::default().content);
+    }
+
+    #[test]
+    fn test_uptime() {
+        let mut app = app_with_clock(
+            &["--show-uptime"],
+            AppClock::Fixed(AppTime::Utc(FIXED_TIME)),
+            tokio::sync::mpsc::unbounded_channel().0,
+        );
+        assert!(app.is_ticking());
+        assert_eq!(app.get_uptime(), Duration::ZERO);
+        app.app_time = AppTime::Utc(FIXED_TIME + Duration::from_secs(90));
+        assert_eq!(app.get_uptime(), Duration::from_secs(90));
+    }
+
+    #[test]
+    fn test_countdowns() {
+        // names of active countdown followed by other countdowns
+        let names = |countdowns: &App| {
+            std::iter::once(&countdowns.countdown)
+                .chain(countdowns.other_countdowns.iter())
+                .map(|countdown| countdown.get_name().map(str::to_owned))
+                .collect::<Vec<_>>()
+        };
+        let c = |name: &str| Some(name.to_owned());
+        let mut countdowns = app(&["-c", "5:00"]);
+        // add
+        countdowns.add_countdown();
+        countdowns.add_countdown();
+        assert_eq!(
+            names(&countdowns),
+            [c("Countdown 3"), None, c("Countdown 2")]
+        );
+        // next (incl. wrap-around)
+        countdowns.next_countdown();
+        assert_eq!(
+            names(&countdowns),
+            [None, c("Countdown 2"), c("Countdown 3")]
+        );
+        countdowns.next_countdown();
+        countdowns.next_countdown();
+        assert_eq!(
+            names(&countdowns),
+            [c("Countdown 3"), None, c("Countdown 2")]
+        );
+        // prev (incl. wrap-around)
+        countdowns.prev_countdown();
+        assert_eq!(
+            names(&countdowns),
+            [c("Countdown 2"), c("Countdown 3"), None]
+        );
+        countdowns.prev_countdown();
+        countdowns.prev_countdown();
+        assert_eq!(
+            names(&countdowns),
+            [c("Countdown 3"), None, c("Countdown 2")]
+        );
+        // remove
+        countdowns.remove_countdown();
+        assert_eq!(names(&countdowns), [None, c("Countdown 2")]);
+        countdowns.remove_countdown();
+        assert_eq!(names(&countdowns), [c("Countdown 2")]);
+        // last one can't be removed
+        countdowns.remove_countdown();
+        assert_eq!(names(&countdowns), [c("Countdown 2")]);
+        // next and prev of last one
+        countdowns.next_countdown();
+        countdowns.prev_countdown();
+        assert_eq!(names(&countdowns), [c("Countdown 2")]);
+    }
+
+    #[test]
+    fn test_save_snapshot() {
+        let path = std::env::temp_dir().join("timr-tui-test-snapshot.txt");
+        let path_arg = path.to_string_lossy();
+        let mut snapshot = app(&["-c", "5:00", "--snapshot", &path_arg, "--line"]);
+        snapshot.save_snapshot(Rect::new(0, 0, 30, 3)).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        // rows w/o trailing spaces
+        assert_eq!(text, "\n            ⏲ 5:00\n");
+    }
+
+    #[test]
+    fn test_tick_in_background() {
+        let mut countdown = app(&["-c", "5:00", "--start-running"]);
+        countdown.set_content(Content::Timer);
+        // 1 sec.
+        for _ in 0..(1000 / TICK_VALUE_MS) {
+            countdown.tick();
+        }
+        assert_eq!(
+            Duration::from(*countdown.countdown.get_clock().get_current_value()),
+            Duration::from_secs(4 * 60 + 59)
+        );
+        assert!(countdown.countdown.get_clock().is_running());
+    }
+
+    #[test]
+    fn test_is_ticking_status_line() {
+        // paused clocks
+        let mut paused = app(&["-c", "5:00", "--start-paused"]);
+        assert!(!paused.is_ticking());
+        paused.set_content(Content::Pomodoro);
+        assert!(!paused.is_ticking());
+        // time to finish is changing
+        let mut paused = app(&["-c", "5:00", "--start-paused", "--status-line"]);
+        assert!(paused.is_ticking());
+        paused.set_content(Content::Pomodoro);
+        assert!(paused.is_ticking());
     }
 }
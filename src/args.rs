@@ -1,24 +1,79 @@
 use crate::{
-    common::{Content, Style, Toggle},
+    common::{Content, DecimalSeparator, Flip, NotifyUrgency, Spacing, Style, Theme, Toggle},
     duration,
-    event::{Event, parse_event},
-    widgets::pomodoro::PauseDuration,
+    event::{Event, EventsFile, WeeklyTarget, parse_event, parse_events_file, parse_weekly_target},
+    logging::LogLevel,
+    widgets::{
+        clock_elements::is_supported_char, header::Progress, local_time::LocalTimeStyle,
+        pomodoro::PauseDuration,
+    },
 };
 #[cfg(feature = "sound")]
 use crate::{sound, sound::SoundError};
 use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
 use std::time::Duration;
+use time::{Time, macros::format_description};
 
 pub const LOG_DIRECTORY_DEFAULT_MISSING_VALUE: &str = " "; // empty string
 
+/// Preferences of `Args` saved by `--save-args` to use as defaults of next launches.
+/// Precedence: explicit args > saved args > built-in defaults (or stored values).
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedArgs {
+    pub style: Option<Style>,
+    pub decis: Option<bool>,
+    pub decimal_separator: Option<DecimalSeparator>,
+    pub flip: Option<Flip>,
+    pub spacing: Option<Spacing>,
+    pub progress: Option<Progress>,
+    pub theme: Option<Theme>,
+    pub urgency_colors: Option<bool>,
+    pub status_line: Option<bool>,
+    pub no_unit_labels: Option<bool>,
+    pub notification: Option<Toggle>,
+    pub blink: Option<Toggle>,
+}
+
 #[derive(Parser)]
 #[command(version)]
 pub struct Args {
-    #[arg(long, short, value_parser = duration::parse_long_duration,
-        help = "Countdown time to start from. Formats: 'Yy Dd hh:mm:ss', 'Dd hh:mm:ss', 'Yy mm:ss', 'Dd mm:ss', 'Yy ss', 'Dd ss', 'hh:mm:ss', 'mm:ss', 'ss'. Examples: '1y 5d 10:30:00', '2d 4:00', '1d 10', '5:03'."
+    #[arg(long, short, value_parser = countdown_parser, value_delimiter = ',',
+        help = "Countdown time to start from. Formats: 'Yy Dd hh:mm:ss', 'Dd hh:mm:ss', 'Yy mm:ss', 'Dd mm:ss', 'Yy ss', 'Dd ss', 'hh:mm:ss', 'mm:ss', 'ss'. Examples: '1y 5d 10:30:00', '2d 4:00', '1d 10', '5:03'. Use '-' to read it from stdin. Multiple values separated by commas (or by repeating `-c`) are presets to load by pressing '1' .. '9' in countdown screen, starting with the first one. Numbers above the count of presets still switch screens. Example: '5:00,10:00,25:00'."
+    )]
+    pub countdown: Vec<Duration>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_parser = countdown_file_parser,
+        conflicts_with_all = ["countdown", "countdown_target"],
+        help = "File to read countdown time from. Formats: same as `--countdown`.",
+        value_hint = clap::ValueHint::FilePath,
     )]
-    pub countdown: Option<Duration>,
+    pub countdown_file: Option<Duration>,
+
+    #[arg(
+        long,
+        value_parser = parse_weekly_target,
+        conflicts_with = "countdown",
+        help = "Countdown to the next occurrence of a weekday at a given time. Format: '<weekday> hh:mm[:ss]'. Example: 'Fri 17:00'."
+    )]
+    pub countdown_target: Option<WeeklyTarget>,
+
+    #[arg(long, help = "Start countdown immediately.")]
+    pub autostart: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Seconds to \"get ready\" before starting countdown automatically. Any key cancels it and is handled as usual (e.g. `space` starts countdown right away). Implies `--autostart`."
+    )]
+    pub autostart_delay: Option<u64>,
 
     #[arg(long, short, value_parser = duration::parse_duration,
         help = "Work time to count down from. Formats: 'ss', 'mm:ss', 'hh:mm:ss'"
@@ -33,6 +88,9 @@ pub struct Args {
     #[arg(long, help = "Maximum number of pomodoro rounds. 0 = unlimited.")]
     pub max_rounds: Option<u64>,
 
+    #[arg(long, help = "Daily goal of pomodoro (work) rounds. 0 = no goal.")]
+    pub goal: Option<u64>,
+
     #[arg(
         long,
         help = "Tabata regimen: work 20s, pause 10s, 8 rounds, auto-switch enabled."
@@ -50,24 +108,387 @@ pub struct Args {
     )]
     pub event: Option<Event>,
 
-    #[arg(long, short = 'd', help = "Show deciseconds.")]
+    #[arg(
+        long,
+        value_parser = parse_event,
+        help = "Milestone of an event. Same format as `--event`. Repeat it to add more milestones. Event screen counts to the nearest upcoming one and advances to next one after it passed."
+    )]
+    pub milestone: Vec<Event>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_parser = events_file_parser,
+        help = "File of events to add as milestones, one per line. Format: 'YYYY-MM-DD HH:MM:SS,title' or same as `--event`. Empty lines and comments ('#') are ignored, malformed lines are skipped.",
+        value_hint = clap::ValueHint::FilePath,
+    )]
+    pub events_file: Option<EventsFile>,
+
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = duration::parse_long_duration,
+        help = "Duration to push a passed event forward by pressing 'z', e.g. to repeat a reminder. Default: '1d'."
+    )]
+    pub event_snooze: Option<Duration>,
+
+    #[arg(
+        long,
+        value_parser = alarm_parser,
+        help = "Daily alarm time. Format: 'hh:mm'. Example: '07:00'."
+    )]
+    pub alarm: Option<Time>,
+
+    #[arg(
+        long,
+        value_parser = done_text_parser,
+        help = "Text to display instead of digits if a countdown is done. Supported chars: A-Z, 0-9. Example: 'DONE'."
+    )]
+    pub done_text: Option<String>,
+
+    #[arg(
+        long,
+        help = "Show elapsed time of a countdown instead of the time left. Toggle it by pressing 'u'."
+    )]
+    pub count_up: bool,
+
+    #[arg(
+        long,
+        help = "Don't count elapsed time (MET) after a countdown is done. Change it in settings ('S')."
+    )]
+    pub no_met: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Blink countdown within its final seconds. 0 to disable it."
+    )]
+    pub warn_secs: Option<u64>,
+
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = duration::parse_long_duration,
+        help = "Pause countdown once by reaching given time left (and notify), e.g. to acknowledge the final stretch. 0 to disable it. Example: '1:00'."
+    )]
+    pub pause_at: Option<Duration>,
+
+    #[arg(
+        long,
+        help = "Cue each whole minute left of a running countdown by a short message and the tick sound (if any). The tick sound of a countdown is played by minutes only then."
+    )]
+    pub minute_cue: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Reset a done countdown to its initial value (not running) after given seconds, e.g. to be ready for next use. 0 to disable it."
+    )]
+    pub auto_reset_after: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Start a countdown at the next whole second of local time, so its seconds change in sync with a wall clock."
+    )]
+    pub align_to_minute: bool,
+
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Name of the active countdown. Add more countdowns by pressing 'n', switch between them by pressing 'tab'."
+    )]
+    pub countdown_name: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "DURATIONS",
+        value_delimiter = ',',
+        value_parser = duration::parse_long_duration,
+        conflicts_with_all = ["countdown", "countdown_file", "countdown_target"],
+        help = "Durations to count down one after another, separated by commas. Formats: same as `--countdown`. Example: '25:00,5:00,25:00,15:00'."
+    )]
+    pub sequence: Vec<Duration>,
+
+    #[arg(
+        long,
+        value_name = "STEP",
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Number of units to change a value by pressing 'ctrl+↑' or 'ctrl+↓' in edit mode. Default: 10."
+    )]
+    pub jump_step: Option<u32>,
+
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = duration::parse_long_duration,
+        help = "Soft goal of the timer. Reaching it changes the color of its digits, but it keeps counting. Example: '1:00:00'."
+    )]
+    pub timer_goal: Option<Duration>,
+
+    #[arg(
+        long,
+        overrides_with = "timer_stop",
+        help = "Wrap the timer back to zero by reaching its max. duration (e.g. for demos), instead of stopping it."
+    )]
+    pub timer_wrap: bool,
+
+    #[arg(
+        long,
+        overrides_with = "timer_wrap",
+        help = "Stop the timer by reaching its max. duration. That's the default."
+    )]
+    pub timer_stop: bool,
+
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = duration::parse_long_duration,
+        help = "Max. duration to count down from, to edit or to reach by a timer. It can't exceed the default of '9999y 364d 23:59:59'. Example: '24:00:00'."
+    )]
+    pub max_duration: Option<Duration>,
+
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = duration::parse_long_duration,
+        help = "Hide seconds of any clock while its value exceeds given duration. Seconds show up again below it. Example: '1:00:00'."
+    )]
+    pub hide_seconds_after: Option<Duration>,
+
+    #[arg(
+        long,
+        short = 'd',
+        overrides_with = "no_decis",
+        help = "Show deciseconds."
+    )]
     pub decis: bool,
 
-    #[arg(long, short = 'm', value_enum, help = "Mode to start with.")]
+    #[arg(
+        long,
+        overrides_with = "decis",
+        help = "Hide deciseconds, e.g. to override `--decis` saved by `--save-args`."
+    )]
+    pub no_decis: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Decimal separator to display deciseconds with. It affects the display only, durations are parsed w/o deciseconds."
+    )]
+    pub decimal_separator: Option<DecimalSeparator>,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Mirror digits horizontally (h) or vertically (v)."
+    )]
+    pub flip: Option<Flip>,
+
+    #[arg(
+        long,
+        overrides_with = "no_urgency_colors",
+        help = "Shift color of countdown (and pomodoro) digits from green to yellow to red while time is running out."
+    )]
+    pub urgency_colors: bool,
+
+    #[arg(
+        long,
+        overrides_with = "urgency_colors",
+        help = "Keep color of digits, e.g. to override `--urgency-colors` saved by `--save-args`."
+    )]
+    pub no_urgency_colors: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Space between digits and around colons. Default: 'normal'."
+    )]
+    pub spacing: Option<Spacing>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STYLE",
+        help = "Style to render local time. Default: 'digital'."
+    )]
+    pub localtime_style: Option<LocalTimeStyle>,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Where and how to render progress of a clock. 'off' hides it. Default: 'top'."
+    )]
+    pub progress: Option<Progress>,
+
+    #[arg(long, value_enum, help = "Color theme to match a terminal theme.")]
+    pub theme: Option<Theme>,
+
+    #[arg(
+        long,
+        overrides_with = "no_status_line",
+        help = "Show a line of time left, time to finish (in format of local time) and percentage done below any clock. Timer shows its value only."
+    )]
+    pub status_line: bool,
+
+    #[arg(
+        long,
+        overrides_with = "status_line",
+        help = "Hide status line, e.g. to override `--status-line` saved by `--save-args`."
+    )]
+    pub no_status_line: bool,
+
+    #[arg(
+        long,
+        help = "Show time running of the timer next to its wall-clock time since first start, e.g. 'ran 42:00 of 1:10:00 wall'."
+    )]
+    pub timer_wall: bool,
+
+    #[arg(
+        long,
+        help = "Let digits of a running pomodoro pause gently pulse (breathe)."
+    )]
+    pub breathe: bool,
+
+    #[arg(
+        long,
+        help = "Show initial value of countdown and pomodoro (work or pause) in their labels, e.g. 'COUNTDOWN > / 10:00'."
+    )]
+    pub show_initial: bool,
+
+    #[arg(
+        long,
+        help = "Render countdown by seconds only as huge digits, e.g. '90' instead of '1:30'. Values above 999 seconds are rendered as usual."
+    )]
+    pub seconds_only: bool,
+
+    #[arg(
+        long,
+        help = "Show a bar of block characters below countdown and pomodoro, which drains by time passed. Hidden for timer."
+    )]
+    pub bar: bool,
+
+    #[arg(
+        long,
+        overrides_with = "unit_labels",
+        help = "Hide labels of years (Y) and days (D) to render long durations in narrow terminals."
+    )]
+    pub no_unit_labels: bool,
+
+    #[arg(
+        long,
+        overrides_with = "no_unit_labels",
+        help = "Show labels of years (Y) and days (D), e.g. to override `--no-unit-labels` saved by `--save-args`."
+    )]
+    pub unit_labels: bool,
+
+    #[arg(
+        long,
+        help = "Show seconds of local time in footer, even if its format is without seconds."
+    )]
+    pub footer_seconds: bool,
+
+    #[arg(
+        long,
+        help = "Show time elapsed since launch of the app in footer, e.g. for debugging."
+    )]
+    pub show_uptime: bool,
+
+    #[cfg(feature = "keep-awake")]
+    #[arg(
+        long,
+        help = "Keep display awake while any clock is running, e.g. for presentations."
+    )]
+    pub keep_awake: bool,
+
+    #[arg(
+        long,
+        short = 'm',
+        value_enum,
+        help = "Mode to start with. It has precedence over the mode inferred by other args (e.g. `--work` infers pomodoro)."
+    )]
     pub mode: Option<Content>,
 
+    #[arg(
+        long,
+        conflicts_with = "start_paused",
+        help = "Start clock of the current mode (countdown, timer or pomodoro) running. It has precedence over the mode inferred by stored values."
+    )]
+    pub start_running: bool,
+
+    #[arg(
+        long,
+        help = "Start clock of the current mode (countdown, timer or pomodoro) paused. It has precedence over the mode inferred by stored values and `--autostart`."
+    )]
+    pub start_paused: bool,
+
     #[arg(long, short = 's', value_enum, help = "Style to display time with.")]
     pub style: Option<Style>,
 
+    #[arg(
+        long,
+        help = "Print a sample of each style (to pick one for `--style`) and exit."
+    )]
+    pub list_styles: bool,
+
+    #[arg(
+        long,
+        help = "Compact mode to render a single line only (e.g. to embed it into a tmux status bar)."
+    )]
+    pub line: bool,
+
+    #[arg(
+        long,
+        help = "Zen mode to hide header and footer. Toggle it by pressing 'f'."
+    )]
+    pub zen: bool,
+
+    #[arg(
+        long,
+        help = "Set current value (e.g. time left) and label of current clock as title of the terminal window or tab. Previous title is restored on quit (if supported by the terminal), otherwise it's cleared."
+    )]
+    pub set_title: bool,
+
+    #[arg(
+        long,
+        help = "Dim all digits, e.g. to reduce brightness at night. Change it in settings by pressing 'S'."
+    )]
+    pub dim: bool,
+
+    #[arg(
+        long,
+        allow_hyphen_values = true,
+        value_name = "N",
+        help = "Move the clock N lines down (or up, if negative) from the center. Adjust it by pressing 'alt +' or 'alt -'."
+    )]
+    pub voffset: Option<i16>,
+
     #[arg(long, value_enum, help = "Open menu.")]
     pub menu: bool,
 
+    #[arg(
+        long,
+        help = "Enable mouse support (e.g. to click on menu items). Note: It might interfere with text selection of your terminal."
+    )]
+    pub mouse: bool,
+
     #[arg(long, short = 'v', value_enum, help = "Enable/disable Vim motions.")]
     pub vim: Option<Toggle>,
 
     #[arg(long, short = 'r', help = "Reset stored values to defaults.")]
     pub reset: bool,
 
+    #[arg(
+        long,
+        help = "Save preferences given by current args (e.g. `--style`, `--decis`, `--theme`) as defaults of next launches. They are merged over preferences saved before. Explicit args still win."
+    )]
+    pub save_args: bool,
+
+    #[arg(
+        long,
+        help = "Don't store any values on exit. Combined with `--reset` it starts a clean session without touching stored values."
+    )]
+    pub no_persist: bool,
+
     #[arg(
         long,
         short,
@@ -76,6 +497,27 @@ pub struct Args {
     )]
     pub notification: Option<Toggle>,
 
+    #[arg(
+        long,
+        value_enum,
+        value_name = "URGENCY",
+        help = "Urgency of desktop notifications. Linux/BSD: `critical` ones don't expire by default. Windows: `critical` ones stay on screen until dismissed. Not supported on macOS."
+    )]
+    pub notify_urgency: Option<NotifyUrgency>,
+
+    #[arg(
+        long,
+        value_name = "MS",
+        help = "Timeout of desktop notifications in milliseconds. `0` to never expire. Linux/BSD only."
+    )]
+    pub notify_timeout: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Silence all notifications and sounds for current session. Stored preferences (e.g. `--notification`) are not changed."
+    )]
+    pub quiet: bool,
+
     #[arg(
         long,
         value_enum,
@@ -87,12 +529,37 @@ pub struct Args {
     #[arg(
         long,
         value_enum,
-        help = "Path to sound file (.mp3 or .wav) to play as notification. Experimental.",
+        help = "Path to sound file (.mp3, .wav, .ogg or .flac) to play as notification. Experimental.",
         value_hint = clap::ValueHint::FilePath,
         value_parser = sound_file_parser,
     )]
     pub sound: Option<PathBuf>,
 
+    #[cfg(feature = "sound")]
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Path to a short sound file (.mp3, .wav, .ogg or .flac) to play every second while a clock is running. `--minute-cue` silences it for countdowns. Experimental.",
+        value_hint = clap::ValueHint::FilePath,
+        value_parser = sound_file_parser,
+    )]
+    pub tick_sound: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "File to store a (plain text) snapshot of the current screen in by pressing `F12`. It sets the path only, nothing is stored w/o pressing `F12`. Default: './timr-tui-snapshot.txt'.",
+        value_hint = clap::ValueHint::FilePath,
+    )]
+    pub snapshot: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "File to append a line with finish time and label to if any clock is done (e.g. to watch it by scripts). It's written by `--quiet` too.",
+        value_hint = clap::ValueHint::FilePath,
+    )]
+    pub output_on_done: Option<PathBuf>,
+
     #[arg(
         long,
         // allows both --log=path and --log path syntax
@@ -105,6 +572,92 @@ pub struct Args {
         value_hint = clap::ValueHint::DirPath,
     )]
     pub log: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_enum,
+        requires = "log",
+        value_name = "LEVEL",
+        help = "Verbosity of log file. Needs `--log`. Default: 'debug'."
+    )]
+    pub log_level: Option<LogLevel>,
+
+    #[arg(
+        long,
+        hide = true,
+        value_name = "SECONDS",
+        help = "Simulate a countdown (set by `--countdown`) headless for given seconds and print its transitions."
+    )]
+    pub simulate: Option<u64>,
+
+    #[arg(
+        long,
+        hide = true,
+        help = "Render a single frame and exit by pressing any key (or after a few seconds). Nothing is stored."
+    )]
+    pub preview: bool,
+}
+
+impl Args {
+    /// Preferences given explicitly by current args
+    pub fn to_saved(&self) -> SavedArgs {
+        SavedArgs {
+            style: self.style,
+            decis: explicit_flag(self.decis, self.no_decis),
+            decimal_separator: self.decimal_separator,
+            flip: self.flip,
+            spacing: self.spacing,
+            progress: self.progress,
+            theme: self.theme,
+            urgency_colors: explicit_flag(self.urgency_colors, self.no_urgency_colors),
+            status_line: explicit_flag(self.status_line, self.no_status_line),
+            no_unit_labels: explicit_flag(self.no_unit_labels, self.unit_labels),
+            notification: self.notification,
+            blink: self.blink,
+        }
+    }
+
+    /// Uses saved preferences for all args not given explicitly
+    pub fn with_saved(mut self, saved: SavedArgs) -> Self {
+        self.style = self.style.or(saved.style);
+        with_saved_flag(&mut self.decis, &mut self.no_decis, saved.decis);
+        self.decimal_separator = self.decimal_separator.or(saved.decimal_separator);
+        self.flip = self.flip.or(saved.flip);
+        self.spacing = self.spacing.or(saved.spacing);
+        self.progress = self.progress.or(saved.progress);
+        self.theme = self.theme.or(saved.theme);
+        with_saved_flag(
+            &mut self.urgency_colors,
+            &mut self.no_urgency_colors,
+            saved.urgency_colors,
+        );
+        with_saved_flag(
+            &mut self.status_line,
+            &mut self.no_status_line,
+            saved.status_line,
+        );
+        with_saved_flag(
+            &mut self.no_unit_labels,
+            &mut self.unit_labels,
+            saved.no_unit_labels,
+        );
+        self.notification = self.notification.or(saved.notification);
+        self.blink = self.blink.or(saved.blink);
+        self
+    }
+}
+
+/// State of a flag given explicitly by `--<flag>` (`Some(true)`) or its counterpart `--no-<flag>` (`Some(false)`)
+fn explicit_flag(on: bool, off: bool) -> Option<bool> {
+    (on || off).then_some(on)
+}
+
+/// Applies saved state of a flag (if any), but only if neither the flag nor its counterpart is given explicitly
+fn with_saved_flag(on: &mut bool, off: &mut bool, saved: Option<bool>) {
+    if let (None, Some(saved)) = (explicit_flag(*on, *off), saved) {
+        *on = saved;
+        *off = !saved;
+    }
 }
 
 fn pause_duration_parser(s: &str) -> Result<PauseDuration, String> {
@@ -126,6 +679,62 @@ fn pause_duration_parser(s: &str) -> Result<PauseDuration, String> {
     }
 }
 
+/// Custom parser for `--countdown` to read its value from stdin by `-`
+fn countdown_parser(s: &str) -> Result<Duration, String> {
+    if s != "-" {
+        return duration::parse_long_duration(s).map_err(|e| e.to_string());
+    }
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| format!("Failed to read countdown from stdin: {e}"))?;
+    duration::parse_long_duration(&input).map_err(|e| {
+        format!(
+            "Failed to parse countdown '{}' from stdin: {e}",
+            input.trim()
+        )
+    })
+}
+
+/// Custom parser for `--countdown-file`
+fn countdown_file_parser(s: &str) -> Result<Duration, String> {
+    let input = fs::read_to_string(s)
+        .map_err(|e| format!("Failed to read countdown from file '{s}': {e}"))?;
+    duration::parse_long_duration(&input).map_err(|e| {
+        format!(
+            "Failed to parse countdown '{}' from file '{s}': {e}",
+            input.trim()
+        )
+    })
+}
+
+/// Custom parser for `--events-file`
+fn events_file_parser(s: &str) -> Result<EventsFile, String> {
+    fs::read_to_string(s)
+        .map(|input| parse_events_file(&input))
+        .map_err(|e| format!("Failed to read events from file '{s}': {e}"))
+}
+
+fn alarm_parser(s: &str) -> Result<Time, String> {
+    Time::parse(
+        s.trim(),
+        format_description!("[hour padding:none]:[minute]"),
+    )
+    .map_err(|e| format!("Failed to parse alarm '{s}': {e}. Expected format: 'hh:mm'"))
+}
+
+fn done_text_parser(s: &str) -> Result<String, String> {
+    const MAX_LEN: usize = 8;
+    let text = s.trim().to_uppercase();
+    if text.is_empty() || text.chars().count() > MAX_LEN {
+        return Err(format!("Text must have 1 to {MAX_LEN} chars."));
+    }
+    match text.chars().find(|c| !is_supported_char(*c)) {
+        Some(c) => Err(format!("Unsupported char '{c}'.")),
+        None => Ok(text),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::duration::ONE_MINUTE;
@@ -172,6 +781,193 @@ mod tests {
     fn pause_parser_invalid() {
         assert!(pause_duration_parser("invalid-duration").is_err());
     }
+
+    #[test]
+    fn alarm_parser_valid() {
+        assert_eq!(alarm_parser("07:00").unwrap(), time::macros::time!(7:00));
+        assert_eq!(alarm_parser("7:30").unwrap(), time::macros::time!(7:30));
+        assert_eq!(alarm_parser("23:59").unwrap(), time::macros::time!(23:59));
+    }
+
+    #[test]
+    fn done_text_parser_valid() {
+        assert_eq!(done_text_parser("DONE").unwrap(), "DONE");
+        assert_eq!(done_text_parser(" done ").unwrap(), "DONE");
+        assert_eq!(done_text_parser("ONE").unwrap(), "ONE");
+        assert_eq!(done_text_parser("fin").unwrap(), "FIN");
+        assert_eq!(done_text_parser("GO2").unwrap(), "GO2");
+    }
+
+    #[test]
+    fn done_text_parser_invalid() {
+        assert!(done_text_parser("").is_err());
+        assert!(done_text_parser("DONEDONEDONE").is_err());
+        assert!(done_text_parser("FIN!").is_err());
+        assert!(done_text_parser("DO NE").is_err());
+    }
+
+    #[test]
+    fn countdown_file_parser_valid() {
+        let path = std::env::temp_dir().join("timr-tui-test-countdown-valid");
+        fs::write(&path, "1:30\n").unwrap();
+        assert_eq!(
+            countdown_file_parser(path.to_str().unwrap()).unwrap(),
+            Duration::from_secs(90)
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn countdown_file_parser_invalid() {
+        let path = std::env::temp_dir().join("timr-tui-test-countdown-invalid");
+        fs::write(&path, "1:60").unwrap();
+        assert!(countdown_file_parser(path.to_str().unwrap()).is_err());
+        fs::remove_file(path).unwrap();
+        // missing file
+        assert!(countdown_file_parser("/does/not/exist").is_err());
+    }
+
+    #[test]
+    fn events_file_parser_valid() {
+        let path = std::env::temp_dir().join("timr-tui-test-events");
+        fs::write(&path, "2025-12-24 18:00:00,Xmas eve\nnot an event\n").unwrap();
+        let result = events_file_parser(path.to_str().unwrap()).unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.errors.len(), 1);
+        fs::remove_file(path).unwrap();
+        // missing file
+        assert!(events_file_parser("/does/not/exist").is_err());
+    }
+
+    #[test]
+    fn alarm_parser_invalid() {
+        assert!(alarm_parser("24:00").is_err());
+        assert!(alarm_parser("07:60").is_err());
+        assert!(alarm_parser("07").is_err());
+    }
+
+    #[test]
+    fn event_with_mode() {
+        let args = Args::try_parse_from([
+            "timr-tui",
+            "-e",
+            "time=2025-12-25 00:00:00,title=Xmas",
+            "-m",
+            "event",
+        ])
+        .unwrap();
+        assert_eq!(args.mode, Some(Content::Event));
+        let event = args.event.unwrap();
+        assert_eq!(event.date_time, time::macros::datetime!(2025-12-25 00:00));
+        assert_eq!(event.title, Some("Xmas".into()));
+        // mode is inferred by `--event`
+        let args = Args::try_parse_from(["timr-tui", "-e", "2025-12-25 00:00:00"]).unwrap();
+        assert_eq!(args.mode, None);
+        assert!(args.event.is_some());
+    }
+
+    #[test]
+    fn log_level_requires_log() {
+        assert!(Args::try_parse_from(["timr-tui", "--log-level", "warn"]).is_err());
+        let args = Args::try_parse_from(["timr-tui", "--log", "--log-level", "warn"]).unwrap();
+        assert_eq!(args.log_level, Some(LogLevel::Warn));
+    }
+
+    #[test]
+    fn countdown_presets() {
+        let parse = |args: &[&str]| {
+            Args::try_parse_from(["timr-tui"].iter().chain(args))
+                .unwrap()
+                .countdown
+        };
+        assert_eq!(parse(&["-c", "5:00"]), vec![Duration::from_secs(300)]);
+        assert_eq!(
+            parse(&["-c", "5:00,10:00,1:00:00"]),
+            vec![
+                Duration::from_secs(300),
+                Duration::from_secs(600),
+                Duration::from_secs(3600)
+            ]
+        );
+        // repeated `-c` adds presets, too
+        assert_eq!(
+            parse(&["-c", "5:00", "-c", "10:00"]),
+            vec![Duration::from_secs(300), Duration::from_secs(600)]
+        );
+        assert!(parse(&[]).is_empty());
+        assert!(Args::try_parse_from(["timr-tui", "-c", "5:00,foo"]).is_err());
+    }
+
+    #[test]
+    fn timer_wrap_or_stop() {
+        let parse = |args: &[&str]| {
+            Args::try_parse_from(["timr-tui"].iter().chain(args))
+                .unwrap()
+                .timer_wrap
+        };
+        assert!(!parse(&[]));
+        assert!(parse(&["--timer-wrap"]));
+        // last one wins
+        assert!(!parse(&["--timer-wrap", "--timer-stop"]));
+        assert!(parse(&["--timer-stop", "--timer-wrap"]));
+    }
+
+    #[test]
+    fn saved_args() {
+        let parse = |args: &[&str]| Args::try_parse_from(["timr-tui"].iter().chain(args)).unwrap();
+        let saved = parse(&["--style", "braille", "--status-line", "--blink", "on"]).to_saved();
+        assert_eq!(saved.style, Some(Style::Braille));
+        assert_eq!(saved.status_line, Some(true));
+        assert_eq!(saved.decis, None);
+
+        // explicit args win
+        let args = parse(&["--style", "cross", "-d"]).with_saved(saved);
+        assert_eq!(args.style, Some(Style::Cross));
+        assert!(args.decis);
+        assert!(args.status_line);
+        assert_eq!(args.blink, Some(Toggle::On));
+        assert_eq!(args.theme, None);
+    }
+
+    #[test]
+    fn saved_args_turned_off() {
+        let parse = |args: &[&str]| Args::try_parse_from(["timr-tui"].iter().chain(args)).unwrap();
+        let saved = parse(&["--decis", "--status-line", "--no-unit-labels"]).to_saved();
+        // explicit counterparts win
+        let args = parse(&["--no-decis", "--no-status-line", "--unit-labels"]).with_saved(saved);
+        assert!(!args.decis);
+        assert!(args.no_decis);
+        assert!(!args.status_line);
+        assert!(!args.no_unit_labels);
+        // saved off
+        let saved = parse(&["--no-status-line"]).to_saved();
+        assert_eq!(saved.status_line, Some(false));
+        let args = parse(&[]).with_saved(saved);
+        assert!(!args.status_line);
+        assert!(args.no_status_line);
+        // last one wins
+        assert!(!parse(&["--decis", "--no-decis"]).decis);
+        assert!(parse(&["--no-decis", "--decis"]).decis);
+    }
+
+    #[test]
+    fn saved_args_merged() {
+        let parse = |args: &[&str]| Args::try_parse_from(["timr-tui"].iter().chain(args)).unwrap();
+        let saved = parse(&["--save-args", "--style", "braille"]).to_saved();
+        // second save keeps first one
+        let saved = parse(&["--save-args", "--decis"])
+            .with_saved(saved)
+            .to_saved();
+        assert_eq!(saved.style, Some(Style::Braille));
+        assert_eq!(saved.decis, Some(true));
+        // explicit args override saved ones
+        let saved = parse(&["--save-args", "--style", "cross", "--no-decis"])
+            .with_saved(saved)
+            .to_saved();
+        assert_eq!(saved.style, Some(Style::Cross));
+        assert_eq!(saved.decis, Some(false));
+        assert_eq!(saved.theme, None);
+    }
 }
 
 #[cfg(feature = "sound")]
@@ -1,9 +1,12 @@
 use clap::ValueEnum;
-use ratatui::symbols::shade;
+use ratatui::{style::Color, symbols::shade};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use strum::EnumString;
-use time::{OffsetDateTime, format_description};
+use time::{
+    OffsetDateTime, UtcOffset,
+    format_description::{self, OwnedFormatItem},
+};
 
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Default, Serialize, Deserialize,
@@ -20,6 +23,8 @@ pub enum Content {
     Event,
     #[value(name = "localtime", alias = "l")]
     LocalTime,
+    #[value(name = "alarm", alias = "a")]
+    Alarm,
 }
 
 impl Content {
@@ -29,29 +34,41 @@ impl Content {
             Content::Timer => Content::Pomodoro,
             Content::Pomodoro => Content::Event,
             Content::Event => Content::LocalTime,
-            Content::LocalTime => Content::Countdown,
+            Content::LocalTime => Content::Alarm,
+            Content::Alarm => Content::Countdown,
         }
     }
 
     pub fn prev(&self) -> Self {
         match self {
-            Content::Countdown => Content::LocalTime,
+            Content::Countdown => Content::Alarm,
             Content::Timer => Content::Countdown,
             Content::Pomodoro => Content::Timer,
             Content::Event => Content::Pomodoro,
             Content::LocalTime => Content::Event,
+            Content::Alarm => Content::LocalTime,
         }
     }
 }
 
-#[derive(Clone, Debug)]
+/// Events to play a sound for
+#[derive(Debug, Clone, Copy)]
+pub enum SoundKind {
+    Done,
+    WorkStart,
+    PauseStart,
+    LongPause,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ClockTypeId {
     Countdown,
     Timer,
     Event,
+    Alarm,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClockName(String);
 
 impl fmt::Display for ClockName {
@@ -72,7 +89,7 @@ impl From<&str> for ClockName {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClockDescription(String);
 
 impl fmt::Display for ClockDescription {
@@ -93,7 +110,7 @@ impl From<&str> for ClockDescription {
     }
 }
 
-#[derive(Debug, Copy, Clone, ValueEnum, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize)]
 pub enum Style {
     #[default]
     #[value(name = "full", alias = "f")]
@@ -115,6 +132,118 @@ pub enum Style {
     Braille,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum, Default, Serialize, Deserialize)]
+pub enum DecimalSeparator {
+    #[default]
+    #[value(name = ".", alias = "dot")]
+    Dot,
+    #[value(name = ",", alias = "comma")]
+    Comma,
+}
+
+/// Mirrors digits, e.g. for displays facing a mirror
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum, Serialize, Deserialize)]
+pub enum Flip {
+    #[value(name = "h", alias = "horizontal")]
+    Horizontal,
+    #[value(name = "v", alias = "vertical")]
+    Vertical,
+}
+
+/// Horizontal space between digits and around colons
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize)]
+pub enum Spacing {
+    #[value(name = "tight", alias = "t")]
+    Tight,
+    #[default]
+    #[value(name = "normal", alias = "n")]
+    Normal,
+    #[value(name = "wide", alias = "w")]
+    Wide,
+}
+
+/// Colors of a `Theme`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// Color of a running or paused clock
+    pub active: Color,
+    /// Color while editing
+    pub edit: Color,
+    /// Color of a done clock
+    pub done: Color,
+    pub background: Color,
+}
+
+/// Built-in color themes to match popular terminal themes
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+pub enum Theme {
+    /// Colors of the terminal (no theme). Useful to clear a stored theme.
+    #[value(name = "default")]
+    Default,
+    #[value(name = "nord")]
+    Nord,
+    #[value(name = "gruvbox")]
+    Gruvbox,
+    #[value(name = "dracula")]
+    Dracula,
+    #[value(name = "solarized")]
+    Solarized,
+}
+
+impl Theme {
+    /// Colors of a theme. `None` for `Default` to keep colors of the terminal.
+    pub fn palette(&self) -> Option<Palette> {
+        let palette = match self {
+            Theme::Default => return None,
+            // https://www.nordtheme.com/docs/colors-and-palettes
+            Theme::Nord => Palette {
+                active: Color::from_u32(0x88c0d0),
+                edit: Color::from_u32(0xebcb8b),
+                done: Color::from_u32(0xa3be8c),
+                background: Color::from_u32(0x2e3440),
+            },
+            // https://github.com/morhetz/gruvbox#palette
+            Theme::Gruvbox => Palette {
+                active: Color::from_u32(0x83a598),
+                edit: Color::from_u32(0xfabd2f),
+                done: Color::from_u32(0xb8bb26),
+                background: Color::from_u32(0x282828),
+            },
+            // https://draculatheme.com/contribute#color-palette
+            Theme::Dracula => Palette {
+                active: Color::from_u32(0xbd93f9),
+                edit: Color::from_u32(0xf1fa8c),
+                done: Color::from_u32(0x50fa7b),
+                background: Color::from_u32(0x282a36),
+            },
+            // https://ethanschoonover.com/solarized/#the-values (dark)
+            Theme::Solarized => Palette {
+                active: Color::from_u32(0x268bd2),
+                edit: Color::from_u32(0xb58900),
+                done: Color::from_u32(0x859900),
+                background: Color::from_u32(0x002b36),
+            },
+        };
+        Some(palette)
+    }
+}
+
+impl DecimalSeparator {
+    pub fn as_char(&self) -> char {
+        match self {
+            DecimalSeparator::Dot => '.',
+            DecimalSeparator::Comma => ',',
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            DecimalSeparator::Dot => DecimalSeparator::Comma,
+            DecimalSeparator::Comma => DecimalSeparator::Dot,
+        }
+    }
+}
+
 impl Style {
     pub fn next(&self) -> Self {
         match self {
@@ -128,6 +257,18 @@ impl Style {
         }
     }
 
+    pub fn prev(&self) -> Self {
+        match self {
+            Style::Full => Style::Cross,
+            Style::Dark => Style::Full,
+            Style::Medium => Style::Dark,
+            Style::Light => Style::Medium,
+            Style::Braille => Style::Light,
+            Style::Thick => Style::Braille,
+            Style::Cross => Style::Thick,
+        }
+    }
+
     pub fn get_digit_symbol(&self) -> &str {
         match &self {
             Style::Full => shade::FULL,
@@ -148,6 +289,8 @@ pub enum AppTimeFormat {
     HhMmSs,
     /// `hh:mm`
     HhMm,
+    /// `hh:mm:ss AM` (or PM)
+    Hh12MmSs,
     /// `hh:mm AM` (or PM)
     Hh12Mm,
 }
@@ -164,10 +307,58 @@ impl AppTimeFormat {
     pub fn next(&self) -> Self {
         match self {
             AppTimeFormat::HhMmSs => AppTimeFormat::HhMm,
-            AppTimeFormat::HhMm => AppTimeFormat::Hh12Mm,
+            AppTimeFormat::HhMm => AppTimeFormat::Hh12MmSs,
+            AppTimeFormat::Hh12MmSs => AppTimeFormat::Hh12Mm,
             AppTimeFormat::Hh12Mm => AppTimeFormat::HhMmSs,
         }
     }
+
+    pub fn prev(&self) -> Self {
+        match self {
+            AppTimeFormat::HhMmSs => AppTimeFormat::Hh12Mm,
+            AppTimeFormat::HhMm => AppTimeFormat::HhMmSs,
+            AppTimeFormat::Hh12MmSs => AppTimeFormat::HhMm,
+            AppTimeFormat::Hh12Mm => AppTimeFormat::Hh12MmSs,
+        }
+    }
+
+    /// Whether hours are shown in 12-hour format along with a period (AM or PM)
+    pub fn is_12h(&self) -> bool {
+        matches!(self, AppTimeFormat::Hh12MmSs | AppTimeFormat::Hh12Mm)
+    }
+
+    pub fn with_seconds(&self) -> bool {
+        matches!(self, AppTimeFormat::HhMmSs | AppTimeFormat::Hh12MmSs)
+    }
+
+    /// Same format, but with seconds
+    pub fn to_seconds(self) -> Self {
+        match self {
+            AppTimeFormat::HhMmSs | AppTimeFormat::HhMm => AppTimeFormat::HhMmSs,
+            AppTimeFormat::Hh12MmSs | AppTimeFormat::Hh12Mm => AppTimeFormat::Hh12MmSs,
+        }
+    }
+}
+
+/// Format of dates shown by `LocalTime` and `Event`,
+/// described by `time`'s format description, e.g. `[weekday repr:short] [day] [month repr:short]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateFormat(OwnedFormatItem);
+
+impl DateFormat {
+    pub const DEFAULT: &str = "[year]-[month]-[day]";
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        format_description::parse_owned::<2>(value)
+            .map(Self)
+            .map_err(|e| format!("Invalid date format '{value}': {e}"))
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self::parse(Self::DEFAULT).expect("valid default date format")
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -198,6 +389,7 @@ impl AppTime {
         let parse_str = match app_format {
             AppTimeFormat::HhMmSs => "[hour]:[minute]:[second]",
             AppTimeFormat::HhMm => "[hour]:[minute]",
+            AppTimeFormat::Hh12MmSs => "[hour repr:12 padding:none]:[minute]:[second] [period]",
             AppTimeFormat::Hh12Mm => "[hour repr:12 padding:none]:[minute] [period]",
         };
 
@@ -211,15 +403,19 @@ impl AppTime {
             .unwrap_or_else(|e| e.to_string())
     }
 
-    pub fn format_date(&self) -> String {
-        format_description::parse("[year]-[month]-[day]")
-            .map_err(|_| "parse error")
-            .and_then(|fd| {
-                OffsetDateTime::from(*self)
-                    .format(&fd)
-                    .map_err(|_| "format error")
-            })
-            .unwrap_or_else(|e| e.to_string())
+    /// Adds given duration, keeping the variant (`Local` or `Utc`)
+    pub fn saturating_add(self, duration: std::time::Duration) -> Self {
+        let duration = time::Duration::try_from(duration).unwrap_or(time::Duration::MAX);
+        match self {
+            AppTime::Local(t) => AppTime::Local(t.saturating_add(duration)),
+            AppTime::Utc(t) => AppTime::Utc(t.saturating_add(duration)),
+        }
+    }
+
+    pub fn format_date(&self, date_format: &DateFormat) -> String {
+        OffsetDateTime::from(*self)
+            .format(&date_format.0)
+            .unwrap_or_else(|_| "format error".to_string())
     }
 
     pub fn get_period(&self) -> String {
@@ -247,6 +443,44 @@ impl AppTime {
     }
 }
 
+/// Re-calculates offset of given (local) time, which might differ
+/// from current offset (e.g. due to daylight saving time changes in between).
+pub fn with_local_offset(time: OffsetDateTime) -> OffsetDateTime {
+    UtcOffset::local_offset_at(time)
+        .map(|offset| time.replace_offset(offset))
+        .unwrap_or(time)
+}
+
+/// Source of current `AppTime`
+#[derive(Debug, Clone, Copy, Default)]
+pub enum AppClock {
+    /// Real (system) time
+    #[default]
+    System,
+    /// Fixed time, which changes by `advance` only.
+    /// Useful to test time dependent features (e.g. `done` of `Event`) deterministically.
+    #[cfg(test)]
+    Fixed(AppTime),
+}
+
+impl AppClock {
+    pub fn now(&self) -> AppTime {
+        match self {
+            AppClock::System => AppTime::new(),
+            #[cfg(test)]
+            AppClock::Fixed(app_time) => *app_time,
+        }
+    }
+
+    /// Advances a `Fixed` clock by given duration. `System` is not affected.
+    #[cfg(test)]
+    pub fn advance(&mut self, duration: std::time::Duration) {
+        if let AppClock::Fixed(app_time) = self {
+            *app_time = app_time.saturating_add(duration);
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum AppEditMode {
     None,
@@ -264,6 +498,38 @@ pub enum Toggle {
     Off,
 }
 
+impl Toggle {
+    pub fn toggle(&self) -> Self {
+        match self {
+            Toggle::On => Toggle::Off,
+            Toggle::Off => Toggle::On,
+        }
+    }
+}
+
+/// Urgency of desktop notifications (`--notify-urgency`)
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum NotifyUrgency {
+    #[value(name = "low")]
+    Low,
+    #[value(name = "normal")]
+    Normal,
+    #[value(name = "critical")]
+    Critical,
+}
+
+// Urgency is not supported on macOS
+#[cfg(not(target_os = "macos"))]
+impl From<NotifyUrgency> for notify_rust::Urgency {
+    fn from(value: NotifyUrgency) -> Self {
+        match value {
+            NotifyUrgency::Low => notify_rust::Urgency::Low,
+            NotifyUrgency::Normal => notify_rust::Urgency::Normal,
+            NotifyUrgency::Critical => notify_rust::Urgency::Critical,
+        }
+    }
+}
+
 impl From<bool> for Toggle {
     fn from(value: bool) -> Self {
         match value {
@@ -317,6 +583,17 @@ mod tests {
             "18:06",
             "local"
         );
+        // hh:mm:ss period
+        assert_eq!(
+            AppTime::Utc(dt).format(&AppTimeFormat::Hh12MmSs),
+            "6:06:10 PM",
+            "utc"
+        );
+        assert_eq!(
+            AppTime::Local(dt).format(&AppTimeFormat::Hh12MmSs),
+            "6:06:10 PM",
+            "local"
+        );
         // hh:mm period
         assert_eq!(
             AppTime::Utc(dt).format(&AppTimeFormat::Hh12Mm),
@@ -330,12 +607,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_date() {
+        let dt = PrimitiveDateTime::new(
+            Date::from_calendar_date(2025, Month::January, 6).unwrap(),
+            Time::from_hms(18, 6, 10).unwrap(),
+        )
+        .assume_utc();
+        assert_eq!(
+            AppTime::Utc(dt).format_date(&DateFormat::default()),
+            "2025-01-06"
+        );
+        let custom = DateFormat::parse("[weekday repr:short] [day] [month repr:short]").unwrap();
+        assert_eq!(AppTime::Local(dt).format_date(&custom), "Mon 06 Jan");
+        assert!(DateFormat::parse("[foo]").is_err());
+    }
+
+    #[test]
+    fn test_format_date_week_start() {
+        // Sunday
+        let dt = PrimitiveDateTime::new(
+            Date::from_calendar_date(2025, Month::January, 5).unwrap(),
+            Time::MIDNIGHT,
+        )
+        .assume_utc();
+        let format = |f: &str| AppTime::Utc(dt).format_date(&DateFormat::parse(f).unwrap());
+        assert_eq!(
+            format("[week_number repr:sunday]-[weekday repr:sunday]"),
+            "01-1"
+        );
+        assert_eq!(
+            format("[week_number repr:monday]-[weekday repr:monday]"),
+            "00-7"
+        );
+        assert_eq!(format("[week_number repr:iso]"), "01");
+    }
+
     #[test]
     fn test_content_next() {
         let start = Content::Countdown;
         let mut current = start;
 
-        // Cycle through: Countdown -> Timer -> Pomodoro -> Event -> LocalTime -> Countdown
+        // Cycle through: Countdown -> Timer -> Pomodoro -> Event -> LocalTime -> Alarm -> Countdown
         current = current.next();
         assert_eq!(current, Content::Timer);
 
@@ -348,6 +661,9 @@ mod tests {
         current = current.next();
         assert_eq!(current, Content::LocalTime);
 
+        current = current.next();
+        assert_eq!(current, Content::Alarm);
+
         current = current.next();
         assert_eq!(current, start, "Should cycle back to start");
     }
@@ -357,7 +673,10 @@ mod tests {
         let start = Content::Countdown;
         let mut current = start;
 
-        // Cycle backwards: Countdown -> LocalTime -> Event -> Pomodoro -> Timer -> Countdown
+        // Cycle backwards: Countdown -> Alarm -> LocalTime -> Event -> Pomodoro -> Timer -> Countdown
+        current = current.prev();
+        assert_eq!(current, Content::Alarm);
+
         current = current.prev();
         assert_eq!(current, Content::LocalTime);
 
@@ -373,4 +692,46 @@ mod tests {
         current = current.prev();
         assert_eq!(current, start, "Should cycle back to start");
     }
+
+    #[test]
+    fn test_style_prev() {
+        // `prev` reverts `next`
+        for style in Style::value_variants() {
+            assert_eq!(style.next().prev(), *style);
+        }
+        assert_eq!(Style::Full.prev(), Style::Cross);
+    }
+
+    #[test]
+    fn test_app_time_format_to_seconds() {
+        assert_eq!(AppTimeFormat::HhMm.to_seconds(), AppTimeFormat::HhMmSs);
+        assert_eq!(AppTimeFormat::HhMmSs.to_seconds(), AppTimeFormat::HhMmSs);
+        assert_eq!(AppTimeFormat::Hh12Mm.to_seconds(), AppTimeFormat::Hh12MmSs);
+        assert_eq!(
+            AppTimeFormat::Hh12MmSs.to_seconds(),
+            AppTimeFormat::Hh12MmSs
+        );
+    }
+
+    #[test]
+    fn test_app_time_format_prev() {
+        let mut current = AppTimeFormat::first();
+
+        // Cycle backwards: HhMmSs -> Hh12Mm -> Hh12MmSs -> HhMm -> HhMmSs
+        current = current.prev();
+        assert_eq!(current, AppTimeFormat::last());
+
+        current = current.prev();
+        assert_eq!(current, AppTimeFormat::Hh12MmSs);
+
+        current = current.prev();
+        assert_eq!(current, AppTimeFormat::HhMm);
+
+        current = current.prev();
+        assert_eq!(
+            current,
+            AppTimeFormat::first(),
+            "Should cycle back to start"
+        );
+    }
 }
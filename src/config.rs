@@ -1,12 +1,86 @@
-use crate::constants::APP_NAME;
+use crate::{
+    args::SavedArgs, common::DateFormat, constants::APP_NAME, duration::parse_long_duration,
+};
 use color_eyre::eyre::{Result, eyre};
 use directories::ProjectDirs;
+use serde::{Deserialize, Deserializer};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const CONFIG_FILE: &str = "config.toml";
+/// File of args saved by `--save-args`, next to `CONFIG_FILE`
+const SAVED_ARGS_FILE: &str = "args.toml";
+
+/// Paths of sound files to play by specific events (`[sounds]` table of config file)
+#[cfg(feature = "sound")]
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SoundsConfig {
+    /// Pomodoro switched to `work`
+    pub work_start: Option<PathBuf>,
+    /// Pomodoro switched to `pause`
+    pub pause_start: Option<PathBuf>,
+    /// Pomodoro switched to a special (long) `pause`
+    pub long_pause: Option<PathBuf>,
+    /// Any clock is done
+    pub done: Option<PathBuf>,
+}
+
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_long_duration(&s)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+fn deserialize_date_format<'de, D>(deserializer: D) -> Result<Option<DateFormat>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    DateFormat::parse(&s)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+/// Default durations used if no values are stored (or by `--reset`),
+/// `[defaults]` table of config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DefaultsConfig {
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub work: Option<Duration>,
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub pause: Option<Duration>,
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub countdown: Option<Duration>,
+}
+
+/// Content of (optional) config file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    /// Format of dates shown by `LocalTime` and `Event`
+    #[serde(deserialize_with = "deserialize_date_format")]
+    date_format: Option<DateFormat>,
+    defaults: DefaultsConfig,
+    #[cfg(feature = "sound")]
+    sounds: SoundsConfig,
+}
 
 pub struct Config {
     pub log_dir: PathBuf,
     pub data_dir: PathBuf,
+    pub date_format: DateFormat,
+    pub defaults: DefaultsConfig,
+    /// Args saved by `--save-args`
+    pub saved_args: SavedArgs,
+    #[cfg(feature = "sound")]
+    pub sounds: SoundsConfig,
 }
 
 impl Config {
@@ -19,7 +93,24 @@ impl Config {
         let data_dir = get_default_state_dir()?.join("data");
         fs::create_dir_all(&data_dir)?;
 
-        Ok(Self { log_dir, data_dir })
+        // optional config file
+        let ConfigFile {
+            date_format,
+            defaults,
+            #[cfg(feature = "sound")]
+            sounds,
+        } = load_config_file(&get_project_dir()?.config_dir().join(CONFIG_FILE))?;
+        let saved_args = load_saved_args(&get_project_dir()?.config_dir().join(SAVED_ARGS_FILE))?;
+
+        Ok(Self {
+            log_dir,
+            data_dir,
+            date_format: date_format.unwrap_or_default(),
+            defaults,
+            saved_args,
+            #[cfg(feature = "sound")]
+            sounds,
+        })
     }
 }
 
@@ -39,3 +130,123 @@ fn get_default_state_dir() -> Result<PathBuf> {
 
     Ok(directory)
 }
+
+/// Loads config file. A missing file is not an error, `ConfigFile::default` is used then.
+fn load_config_file(path: &Path) -> Result<ConfigFile> {
+    if !path.exists() {
+        return Ok(ConfigFile::default());
+    }
+    let content = fs::read_to_string(path)?;
+    parse_config_file(&content).map_err(|e| eyre!("Invalid config file {:?}: {}", path, e))
+}
+
+fn parse_config_file(content: &str) -> Result<ConfigFile, toml::de::Error> {
+    toml::from_str(content)
+}
+
+/// Loads args saved by `--save-args`. A missing file is not an error, `SavedArgs::default` is used then.
+fn load_saved_args(path: &Path) -> Result<SavedArgs> {
+    if !path.exists() {
+        return Ok(SavedArgs::default());
+    }
+    let content = fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| eyre!("Invalid saved args {:?}: {}", path, e))
+}
+
+/// Saves args to use them as defaults of next launches
+pub fn save_args(saved_args: &SavedArgs) -> Result<()> {
+    let dir = get_project_dir()?.config_dir().to_path_buf();
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(SAVED_ARGS_FILE), toml::to_string(saved_args)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config_file_empty() {
+        assert!(parse_config_file("").is_ok());
+    }
+
+    #[test]
+    fn test_parse_config_file_invalid() {
+        assert!(parse_config_file("[sounds").is_err());
+    }
+
+    #[test]
+    fn test_parse_config_file_defaults() {
+        let cfg = parse_config_file(
+            r#"
+            [defaults]
+            work = "50:00"
+            countdown = "1d 2:00"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.defaults.work, Some(Duration::from_secs(50 * 60)));
+        assert_eq!(cfg.defaults.pause, None);
+        assert_eq!(
+            cfg.defaults.countdown,
+            Some(Duration::from_secs(24 * 60 * 60 + 2 * 60))
+        );
+    }
+
+    #[test]
+    fn test_parse_config_file_defaults_invalid() {
+        assert!(parse_config_file("[defaults]\nwork = \"1:60\"").is_err());
+        assert!(parse_config_file("[defaults]\nwork = 25").is_err());
+    }
+
+    #[test]
+    fn test_parse_config_file_date_format() {
+        let cfg =
+            parse_config_file(r#"date_format = "[weekday repr:short] [day] [month repr:short]""#)
+                .unwrap();
+        assert_eq!(
+            cfg.date_format,
+            DateFormat::parse("[weekday repr:short] [day] [month repr:short]").ok()
+        );
+        assert_eq!(parse_config_file("").unwrap().date_format, None);
+    }
+
+    #[test]
+    fn test_parse_config_file_date_format_invalid() {
+        assert!(parse_config_file("date_format = \"[weekday\"").is_err());
+        assert!(parse_config_file("date_format = \"[foo]\"").is_err());
+    }
+
+    #[test]
+    fn test_saved_args_round_trip() {
+        let path = std::env::temp_dir().join("timr-tui-test-saved-args.toml");
+        let saved_args = SavedArgs {
+            style: Some(crate::common::Style::Braille),
+            status_line: Some(true),
+            ..SavedArgs::default()
+        };
+        fs::write(&path, toml::to_string(&saved_args).unwrap()).unwrap();
+        let loaded = load_saved_args(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, saved_args);
+        // missing file
+        assert_eq!(load_saved_args(&path).unwrap(), SavedArgs::default());
+    }
+
+    #[cfg(feature = "sound")]
+    #[test]
+    fn test_parse_config_file_sounds() {
+        let cfg = parse_config_file(
+            r#"
+            [sounds]
+            work_start = "/tmp/work.mp3"
+            done = "/tmp/done.ogg"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.sounds.work_start, Some(PathBuf::from("/tmp/work.mp3")));
+        assert_eq!(cfg.sounds.pause_start, None);
+        assert_eq!(cfg.sounds.long_pause, None);
+        assert_eq!(cfg.sounds.done, Some(PathBuf::from("/tmp/done.ogg")));
+    }
+}
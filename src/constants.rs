@@ -4,6 +4,15 @@ pub static APP_NAME: &str = env!("CARGO_PKG_NAME");
 
 pub static TICK_VALUE_MS: u64 = 1000 / 10; // 0.1 sec in milliseconds
 
+// Time to wait for more `Resize` events before re-drawing
+pub static RESIZE_DEBOUNCE: Duration = Duration::from_millis(50);
+
+// Time to show a flash message (e.g. "max reached" while editing)
+pub static FLASH_DURATION: Duration = Duration::from_millis(1500);
+
+// Time to hold a single frame rendered by `--preview`
+pub static PREVIEW_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub static TABATA_WORK: Duration = Duration::from_secs(20);
 pub static TABATA_PAUSE: Duration = Duration::from_secs(10);
 pub static TABATA_MAX_ROUNDS: u64 = 8;
@@ -231,6 +231,16 @@ impl ClockDuration for CalendarDuration {
     }
 }
 
+/// Wrapper of `Duration` to display it in a human-friendly way.
+///
+/// ```
+/// use std::time::Duration;
+/// use timr_tui::duration::DurationEx;
+///
+/// let d = DurationEx::from(Duration::from_millis(90_500));
+/// assert_eq!(d.to_string(), "1:30");
+/// assert_eq!(d.to_string_with_decis(), "1:30.5");
+/// ```
 #[derive(Debug, Clone, Copy, PartialOrd)]
 pub struct DurationEx {
     inner: Duration,
@@ -319,7 +329,12 @@ impl DurationEx {
     }
 
     pub fn to_string_with_decis(self) -> String {
-        format!("{}.{}", self, self.decis())
+        self.to_string_with_separator('.')
+    }
+
+    /// Same as `to_string_with_decis`, but with a custom decimal separator (e.g. `,`)
+    pub fn to_string_with_separator(self, separator: char) -> String {
+        format!("{}{}{}", self, separator, self.decis())
     }
 }
 
@@ -387,13 +402,32 @@ fn parse_minutes(m: &str) -> Result<u8, Report> {
     Ok(mins)
 }
 
-/// Parse hours
-fn parse_hours(h: &str) -> Result<u8, Report> {
-    let hours = h.parse::<u8>().map_err(|_| eyre!("Invalid hours"))?;
+/// Max. hours of a duration, derived from `MAX_DURATION`
+const MAX_HOURS: u64 = MAX_DURATION.as_secs() / (SECS_PER_MINUTE * MINS_PER_HOUR);
+
+/// Parse hours of a duration (not a time of day).
+/// Hours are not limited to 24, but they must not exceed `MAX_HOURS`.
+fn parse_hours(h: &str) -> Result<u64, Report> {
+    let hours = h
+        .parse::<u64>()
+        .map_err(|_| eyre!("Invalid hours: '{}'", h))?;
+    ensure!(
+        hours <= MAX_HOURS,
+        "Hours must not be greater than {}.",
+        MAX_HOURS
+    );
     Ok(hours)
 }
 
 /// Parses  `Duration` from `hh:mm:ss`, `mm:ss` or `ss`
+///
+/// ```
+/// use std::time::Duration;
+/// use timr_tui::duration::parse_duration;
+///
+/// assert_eq!(parse_duration("1:30").unwrap(), Duration::from_secs(90));
+/// assert!(parse_duration("1:60").is_err());
+/// ```
 pub fn parse_duration(arg: &str) -> Result<Duration, Report> {
     let parts: Vec<&str> = arg.split(':').collect();
 
@@ -414,7 +448,7 @@ pub fn parse_duration(arg: &str) -> Result<Duration, Report> {
             let h = parse_hours(hh)?;
             let m = parse_minutes(mm)?;
             let s = parse_seconds(ss)?;
-            (h as u64, m as u64, s as u64)
+            (h, m as u64, s as u64)
         }
         _ => {
             return Err(eyre!(
@@ -430,6 +464,12 @@ pub fn parse_duration(arg: &str) -> Result<Duration, Report> {
 /// Similar to `parse_duration`, but it parses `years` and `days` in addition
 /// Formats: `Yy Dd`, `Yy` or `Dd` in any combination to other time formats
 /// Examples: `10y 3d 12:10:03`, `2d 10:00`, `101y 33`, `5:30`
+///
+/// ```
+/// use timr_tui::duration::{ONE_DAY, ONE_MINUTE, parse_long_duration};
+///
+/// assert_eq!(parse_long_duration("1d 5:00").unwrap(), ONE_DAY + ONE_MINUTE * 5);
+/// ```
 pub fn parse_long_duration(arg: &str) -> Result<Duration, Report> {
     let arg = arg.trim();
 
@@ -598,6 +638,37 @@ mod tests {
         assert!(parse_duration("60:00").is_err()); // invalid minutes
         assert!(parse_duration("abc").is_err()); // invalid input
         assert!(parse_duration("01:02:03:04").is_err()); // too many parts
+        // hours > 24
+        assert_eq!(
+            parse_duration("1000:00:00").unwrap(),
+            Duration::from_secs(1000 * 60 * 60)
+        );
+        // max. hours
+        assert_eq!(
+            parse_duration(&format!("{MAX_HOURS}:59:59")).unwrap(),
+            Duration::from_secs(MAX_DURATION.as_secs())
+        );
+    }
+
+    #[test]
+    fn test_to_string_with_separator() {
+        let ex = DurationEx::from(Duration::from_millis(90_500));
+        assert_eq!(ex.to_string_with_decis(), "1:30.5");
+        assert_eq!(ex.to_string_with_separator(','), "1:30,5");
+    }
+
+    #[test]
+    fn test_parse_hours() {
+        assert_eq!(parse_hours("0").unwrap(), 0);
+        assert_eq!(parse_hours("255").unwrap(), 255);
+        assert_eq!(parse_hours("256").unwrap(), 256);
+        assert_eq!(parse_hours("1000").unwrap(), 1000);
+        assert_eq!(parse_hours(&MAX_HOURS.to_string()).unwrap(), MAX_HOURS);
+        // errors
+        assert!(parse_hours(&(MAX_HOURS + 1).to_string()).is_err()); // too large
+        assert!(parse_hours("-1").is_err()); // negative
+        assert!(parse_hours("").is_err()); // empty
+        assert!(parse_hours("99999999999999999999999").is_err()); // overflow
     }
 
     #[test]
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
-use time::macros::{datetime, format_description};
+use time::{
+    Duration, OffsetDateTime, Time, Weekday,
+    macros::{datetime, format_description},
+};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Event {
     pub date_time: time::PrimitiveDateTime,
     pub title: Option<String>,
@@ -104,10 +107,152 @@ fn parse_event_key_value(s: &str) -> Result<Event, String> {
     })
 }
 
+/// Events of a file, incl. errors of its malformed (skipped) lines
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventsFile {
+    pub events: Vec<Event>,
+    pub errors: Vec<String>,
+}
+
+/// Parses events line by line. Besides formats of `parse_event`, it supports CSV lines:
+/// "YYYY-MM-DD HH:MM:SS,my event".
+/// Empty lines and comments (starting with `#`) are ignored.
+/// Malformed lines are skipped, but reported by `EventsFile::errors`.
+pub fn parse_events_file(input: &str) -> EventsFile {
+    let mut result = EventsFile::default();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        // CSV: "datetime,title"
+        let csv = line.split_once(',').and_then(|(date_time, title)| {
+            parse_event_date_time(date_time.trim())
+                .ok()
+                .map(|event| Event {
+                    title: Some(title.trim().to_string()).filter(|t| !t.is_empty()),
+                    ..event
+                })
+        });
+        match csv.map_or_else(|| parse_event(line), Ok) {
+            Ok(event) => result.events.push(event),
+            Err(e) => result.errors.push(format!("line {}: {e}", index + 1)),
+        }
+    }
+    result
+}
+
+/// Recurring target: a weekday at a given time, e.g. "Fri 17:00"
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeeklyTarget {
+    pub weekday: Weekday,
+    pub time: Time,
+}
+
+impl WeeklyTarget {
+    /// Next occurrence after `now`.
+    /// If it's the same weekday, but time has been passed already, it's the one of next week.
+    /// `with_offset` re-calculates the offset at the occurrence (e.g. `with_local_offset`),
+    /// which might differ from the one of `now` due to daylight saving time changes in between.
+    pub fn next_after(
+        &self,
+        now: OffsetDateTime,
+        with_offset: impl Fn(OffsetDateTime) -> OffsetDateTime,
+    ) -> OffsetDateTime {
+        let days = (self.weekday.number_days_from_monday() as i64
+            - now.weekday().number_days_from_monday() as i64)
+            .rem_euclid(7);
+        let next = now.replace_time(self.time) + Duration::days(days);
+        match with_offset(next) {
+            next_with_offset if next_with_offset <= now => with_offset(next + Duration::weeks(1)),
+            next_with_offset => next_with_offset,
+        }
+    }
+}
+
+/// Parses a `WeeklyTarget`
+/// Format: "<weekday> hh:mm[:ss]", e.g. "Fri 17:00" or "friday 17:00:30"
+pub fn parse_weekly_target(s: &str) -> Result<WeeklyTarget, String> {
+    const EXPECTED: &str = "Expected format: '<weekday> hh:mm[:ss]'. Example: 'Fri 17:00'";
+    let (weekday_str, time_str) = s
+        .trim()
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| format!("Missing time in '{s}'. {EXPECTED}"))?;
+
+    let weekday = match weekday_str.to_lowercase().as_str() {
+        "mon" | "monday" => Weekday::Monday,
+        "tue" | "tuesday" => Weekday::Tuesday,
+        "wed" | "wednesday" => Weekday::Wednesday,
+        "thu" | "thursday" => Weekday::Thursday,
+        "fri" | "friday" => Weekday::Friday,
+        "sat" | "saturday" => Weekday::Saturday,
+        "sun" | "sunday" => Weekday::Sunday,
+        _ => return Err(format!("Unknown weekday '{weekday_str}'. {EXPECTED}")),
+    };
+
+    let time_str = time_str.trim();
+    let time = Time::parse(
+        time_str,
+        format_description!("[hour padding:none]:[minute]:[second]"),
+    )
+    .or_else(|_| {
+        Time::parse(
+            time_str,
+            format_description!("[hour padding:none]:[minute]"),
+        )
+    })
+    .map_err(|e| format!("Failed to parse time '{time_str}': {e}. {EXPECTED}"))?;
+
+    Ok(WeeklyTarget { weekday, time })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use time::macros::datetime;
+    use time::macros::{datetime, offset, time};
+
+    #[test]
+    fn test_parse_events_file() {
+        let result = parse_events_file(
+            "# birthdays
+2025-03-01 00:00:00,Alice
+
+2025-04-02 12:00:00, Bob, the builder
+2025-05-03 08:00:00,
+time=2025-06-04 10:00:00,title=Key value
+2025-07-05 09:00:00
+2025-13-01 00:00:00,Invalid month
+no event",
+        );
+        assert_eq!(
+            result.events,
+            vec![
+                Event {
+                    date_time: datetime!(2025-03-01 00:00:00),
+                    title: Some("Alice".into()),
+                },
+                Event {
+                    date_time: datetime!(2025-04-02 12:00:00),
+                    title: Some("Bob, the builder".into()),
+                },
+                Event {
+                    date_time: datetime!(2025-05-03 08:00:00),
+                    title: None,
+                },
+                Event {
+                    date_time: datetime!(2025-06-04 10:00:00),
+                    title: Some("Key value".into()),
+                },
+                Event {
+                    date_time: datetime!(2025-07-05 09:00:00),
+                    title: None,
+                },
+            ]
+        );
+        assert_eq!(result.errors.len(), 2);
+        assert!(result.errors[0].starts_with("line 8:"));
+        assert!(result.errors[1].starts_with("line 9:"));
+    }
 
     #[test]
     fn test_parse_event() {
@@ -166,4 +311,79 @@ mod tests {
         // Error cases: malformed key=value pair
         assert!(parse_event("time=2025-10-10 14:30:00,notapair").is_err());
     }
+
+    #[test]
+    fn test_parse_weekly_target() {
+        let target = parse_weekly_target("Fri 17:00").unwrap();
+        assert_eq!(target.weekday, Weekday::Friday);
+        assert_eq!(target.time, time!(17:00));
+
+        let target = parse_weekly_target(" monday  9:30:15 ").unwrap();
+        assert_eq!(target.weekday, Weekday::Monday);
+        assert_eq!(target.time, time!(9:30:15));
+
+        assert!(parse_weekly_target("Fri").is_err());
+        assert!(parse_weekly_target("Fry 17:00").is_err());
+        assert!(parse_weekly_target("Fri 25:00").is_err());
+    }
+
+    #[test]
+    fn test_weekly_target_next_after() {
+        let utc = |t| t;
+        // Monday
+        let now = datetime!(2024-06-10 14:30:00 UTC);
+        let at = |weekday| WeeklyTarget {
+            weekday,
+            time: time!(17:00),
+        };
+        let expected = [
+            (Weekday::Tuesday, datetime!(2024-06-11 17:00 UTC)),
+            (Weekday::Wednesday, datetime!(2024-06-12 17:00 UTC)),
+            (Weekday::Thursday, datetime!(2024-06-13 17:00 UTC)),
+            (Weekday::Friday, datetime!(2024-06-14 17:00 UTC)),
+            (Weekday::Saturday, datetime!(2024-06-15 17:00 UTC)),
+            (Weekday::Sunday, datetime!(2024-06-16 17:00 UTC)),
+        ];
+        for (weekday, next) in expected {
+            assert_eq!(at(weekday).next_after(now, utc), next, "{weekday}");
+        }
+        // same day: before
+        assert_eq!(
+            at(Weekday::Monday).next_after(now, utc),
+            datetime!(2024-06-10 17:00 UTC)
+        );
+        // same day: after (-> next week)
+        assert_eq!(
+            at(Weekday::Monday).next_after(datetime!(2024-06-10 18:00 UTC), utc),
+            datetime!(2024-06-17 17:00 UTC)
+        );
+        // same day: exactly at (-> next week)
+        assert_eq!(
+            at(Weekday::Monday).next_after(datetime!(2024-06-10 17:00 UTC), utc),
+            datetime!(2024-06-17 17:00 UTC)
+        );
+    }
+
+    #[test]
+    fn test_weekly_target_next_after_dst() {
+        // fake local offsets of Europe/Berlin: CEST (+2) until 2024-10-27 01:00 UTC, CET (+1) after
+        let berlin = |t: OffsetDateTime| {
+            let offset = if t < datetime!(2024-10-27 01:00 UTC) {
+                offset!(+2)
+            } else {
+                offset!(+1)
+            };
+            t.replace_offset(offset)
+        };
+        let at_friday = WeeklyTarget {
+            weekday: Weekday::Friday,
+            time: time!(17:00),
+        };
+        // Saturday (CEST) -> Friday (CET)
+        let now = datetime!(2024-10-26 12:00 +2);
+        assert_eq!(
+            at_friday.next_after(now, berlin),
+            datetime!(2024-11-01 17:00 +1)
+        );
+    }
 }
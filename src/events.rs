@@ -1,4 +1,6 @@
-use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEventKind};
+use crossterm::event::{
+    Event as CrosstermEvent, EventStream, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
 use futures::{Stream, StreamExt};
 use ratatui::layout::Position;
 use std::{pin::Pin, time::Duration};
@@ -8,6 +10,7 @@ use tokio_stream::{StreamMap, wrappers::IntervalStream};
 
 use crate::common::{ClockDescription, ClockName, ClockTypeId};
 use crate::constants::TICK_VALUE_MS;
+use crate::widgets::pomodoro::Mode as PomodoroMode;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 enum StreamKey {
@@ -20,12 +23,26 @@ pub enum TuiEvent {
     Error,
     Tick,
     Crossterm(CrosstermEvent),
+    Mouse(MouseEvent),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AppEvent {
     ClockDone(ClockTypeId, ClockName, Option<ClockDescription>),
     SetCursor(Option<Position>),
+    /// Daily goal of pomodoro rounds has been reached
+    GoalReached(ClockName, u64),
+    /// Pomodoro has been switched automatically to given mode.
+    /// Flag is `true` for a special (long) pause.
+    PomodoroSwitched(PomodoroMode, bool),
+    /// Soft goal of timer has been reached
+    TimerGoalReached(Duration),
+    /// Countdown has been paused by reaching given time left (`--pause-at`)
+    CountdownPausedAt(ClockName, Duration),
+    /// Countdown has reached a whole minute left (`--minute-cue`)
+    CountdownMinuteCue(ClockName, Duration),
+    /// Message to show for a moment, e.g. as feedback while editing
+    Flash(String),
 }
 
 pub type AppEventTx = mpsc::UnboundedSender<AppEvent>;
@@ -87,6 +104,14 @@ fn crossterm_stream() -> Pin<Box<dyn Stream<Item = TuiEvent>>> {
                     // filter `KeyEventKind::Press` out to ignore all the other `CrosstermEvent::Key` events
                     Ok(CrosstermEvent::Key(key)) => (key.kind == KeyEventKind::Press)
                         .then_some(TuiEvent::Crossterm(CrosstermEvent::Key(key))),
+                    // filter mouse clicks and scrolling only, ignore any other (e.g. move, drag) events
+                    Ok(CrosstermEvent::Mouse(mouse)) => matches!(
+                        mouse.kind,
+                        MouseEventKind::Down(MouseButton::Left)
+                            | MouseEventKind::ScrollUp
+                            | MouseEventKind::ScrollDown
+                    )
+                    .then_some(TuiEvent::Mouse(mouse)),
                     Ok(other) => Some(TuiEvent::Crossterm(other)),
                     Err(_) => Some(TuiEvent::Error),
                 }
@@ -0,0 +1,214 @@
+use color_eyre::Result;
+use tracing::{debug, warn};
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use color_eyre::Result;
+    use zbus::blocking::Connection;
+
+    const DESTINATION: &str = "org.freedesktop.ScreenSaver";
+    const PATH: &str = "/org/freedesktop/ScreenSaver";
+
+    /// Inhibitor of `org.freedesktop.ScreenSaver`. It's valid as long as its connection is alive.
+    pub struct Inhibitor {
+        connection: Connection,
+        cookie: u32,
+    }
+
+    impl Inhibitor {
+        pub fn acquire() -> Result<Self> {
+            let connection = Connection::session()?;
+            let cookie: u32 = connection
+                .call_method(
+                    Some(DESTINATION),
+                    PATH,
+                    Some(DESTINATION),
+                    "Inhibit",
+                    &("timr-tui", "Clock is running"),
+                )?
+                .body()
+                .deserialize()?;
+            Ok(Self { connection, cookie })
+        }
+    }
+
+    impl Drop for Inhibitor {
+        fn drop(&mut self) {
+            if let Err(err) = self.connection.call_method(
+                Some(DESTINATION),
+                PATH,
+                Some(DESTINATION),
+                "UnInhibit",
+                &(self.cookie),
+            ) {
+                tracing::warn!("Keep awake release error: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use color_eyre::Result;
+    use std::process::{Child, Command, Stdio};
+
+    /// Inhibitor by `caffeinate` to prevent display sleep as long as it's running.
+    pub struct Inhibitor(Child);
+
+    impl Inhibitor {
+        pub fn acquire() -> Result<Self> {
+            let child = Command::new("caffeinate")
+                // `-w` releases it by exiting the app in any case
+                .args(["-d", "-w", &std::process::id().to_string()])
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()?;
+            Ok(Self(child))
+        }
+    }
+
+    impl Drop for Inhibitor {
+        fn drop(&mut self) {
+            if let Err(err) = self.0.kill().and_then(|_| self.0.wait().map(|_| ())) {
+                tracing::warn!("Keep awake release error: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use color_eyre::{Result, eyre::eyre};
+    use windows_sys::Win32::System::Power::{
+        ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED, SetThreadExecutionState,
+    };
+
+    /// Inhibitor by execution state of current thread (the one running the app).
+    pub struct Inhibitor;
+
+    impl Inhibitor {
+        pub fn acquire() -> Result<Self> {
+            let state = ES_CONTINUOUS | ES_DISPLAY_REQUIRED | ES_SYSTEM_REQUIRED;
+            // SAFETY: It sets flags of current thread only
+            if unsafe { SetThreadExecutionState(state) } == 0 {
+                return Err(eyre!("SetThreadExecutionState failed"));
+            }
+            Ok(Self)
+        }
+    }
+
+    impl Drop for Inhibitor {
+        fn drop(&mut self) {
+            // SAFETY: It resets flags of current thread only
+            unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+mod platform {
+    use color_eyre::{Result, eyre::eyre};
+
+    pub struct Inhibitor;
+
+    impl Inhibitor {
+        pub fn acquire() -> Result<Self> {
+            Err(eyre!("not supported by this OS"))
+        }
+    }
+}
+
+pub use platform::Inhibitor;
+
+/// Keeps display awake (`--keep-awake`) by holding an inhibitor of the OS.
+/// Failures are logged only, the app works w/o it as usual.
+pub struct KeepAwake<T = Inhibitor> {
+    /// `None` after a failure to not try it again
+    acquire: Option<fn() -> Result<T>>,
+    inhibitor: Option<T>,
+}
+
+impl Default for KeepAwake {
+    fn default() -> Self {
+        Self::new(Inhibitor::acquire)
+    }
+}
+
+impl<T> std::fmt::Debug for KeepAwake<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeepAwake")
+            .field("acquired", &self.inhibitor.is_some())
+            .finish()
+    }
+}
+
+impl<T> KeepAwake<T> {
+    fn new(acquire: fn() -> Result<T>) -> Self {
+        Self {
+            acquire: Some(acquire),
+            inhibitor: None,
+        }
+    }
+
+    /// Acquires (`true`) or releases (`false`) the inhibitor. Nothing happens if it's in given state already.
+    pub fn set(&mut self, awake: bool) {
+        if awake && self.inhibitor.is_none() {
+            self.acquire();
+        } else if !awake && self.inhibitor.take().is_some() {
+            debug!("Keep awake released");
+        }
+    }
+
+    fn acquire(&mut self) {
+        let Some(acquire) = self.acquire else {
+            return;
+        };
+        match acquire() {
+            Ok(inhibitor) => {
+                debug!("Keep awake acquired");
+                self.inhibitor = Some(inhibitor);
+            }
+            Err(err) => {
+                warn!("Keep awake error: {err}");
+                self.acquire = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use color_eyre::eyre::eyre;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_keep_awake() {
+        static ACQUIRED: AtomicUsize = AtomicUsize::new(0);
+        let mut keep_awake = KeepAwake::new(|| Ok(ACQUIRED.fetch_add(1, Ordering::SeqCst)));
+        keep_awake.set(false);
+        assert!(keep_awake.inhibitor.is_none());
+        keep_awake.set(true);
+        assert!(keep_awake.inhibitor.is_some());
+        // acquired once only
+        keep_awake.set(true);
+        assert_eq!(ACQUIRED.load(Ordering::SeqCst), 1);
+        keep_awake.set(false);
+        assert!(keep_awake.inhibitor.is_none());
+    }
+
+    #[test]
+    fn test_keep_awake_error() {
+        static CALLED: AtomicUsize = AtomicUsize::new(0);
+        let mut keep_awake = KeepAwake::<()>::new(|| {
+            CALLED.fetch_add(1, Ordering::SeqCst);
+            Err(eyre!("unsupported"))
+        });
+        keep_awake.set(true);
+        assert!(keep_awake.inhibitor.is_none());
+        // not tried again
+        keep_awake.set(true);
+        assert_eq!(CALLED.load(Ordering::SeqCst), 1);
+    }
+}
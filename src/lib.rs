@@ -0,0 +1,12 @@
+//! Library parts of `timr-tui`, which might be useful beyond the TUI.
+//!
+//! Currently it exposes human-friendly parsing and formatting of durations only.
+//!
+//! ```
+//! use timr_tui::duration::{DurationEx, parse_long_duration};
+//!
+//! let d = parse_long_duration("2d 10:00").unwrap();
+//! assert_eq!(DurationEx::from(d).to_string(), "2d 00:10:00");
+//! ```
+
+pub mod duration;
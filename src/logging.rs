@@ -1,3 +1,4 @@
+use clap::ValueEnum;
 use color_eyre::eyre::{Result, eyre};
 use std::fs;
 use std::path::PathBuf;
@@ -6,13 +7,42 @@ use tracing_subscriber::{
     self, prelude::__tracing_subscriber_SubscriberExt, util::SubscriberInitExt,
 };
 
+/// Verbosity of logs (`--log-level`)
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, Default)]
+pub enum LogLevel {
+    #[value(name = "trace")]
+    Trace,
+    #[default]
+    #[value(name = "debug")]
+    Debug,
+    #[value(name = "info")]
+    Info,
+    #[value(name = "warn")]
+    Warn,
+    #[value(name = "error")]
+    Error,
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Trace => LevelFilter::TRACE,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Error => LevelFilter::ERROR,
+        }
+    }
+}
+
 pub struct Logger {
     log_dir: PathBuf,
+    level: LogLevel,
 }
 
 impl Logger {
-    pub fn new(log_dir: PathBuf) -> Self {
-        Self { log_dir }
+    pub fn new(log_dir: PathBuf, level: LogLevel) -> Self {
+        Self { log_dir, level }
     }
 
     pub fn init(&self) -> Result<()> {
@@ -31,7 +61,7 @@ impl Logger {
             .with_target(false)
             .with_ansi(false);
         let filter = tracing_subscriber::filter::EnvFilter::from_default_env()
-            .add_directive(LevelFilter::DEBUG.into());
+            .add_directive(LevelFilter::from(self.level).into());
         tracing_subscriber::registry()
             .with(fmt_layer)
             .with(filter)
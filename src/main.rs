@@ -7,21 +7,27 @@ mod events;
 mod logging;
 
 mod args;
-mod duration;
+mod simulation;
 mod storage;
+mod styles;
 mod terminal;
 mod widgets;
 
 #[cfg(feature = "sound")]
 mod sound;
 
+#[cfg(feature = "keep-awake")]
+mod keep_awake;
+
 use app::{App, FromAppArgs};
 use args::{Args, LOG_DIRECTORY_DEFAULT_MISSING_VALUE};
 use clap::Parser;
 use color_eyre::Result;
+use common::AppClock;
 use config::Config;
 use std::path::PathBuf;
 use storage::{AppStorage, Storage};
+use timr_tui::duration;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -32,6 +38,9 @@ async fn main() -> Result<()> {
 
     // get args given by CLI
     let args = Args::parse();
+    let args = args.with_saved(cfg.saved_args);
+    // current effective args to save (if requested), which are explicit args merged over saved ones
+    let saved_args = args.save_args.then(|| args.to_saved());
     // Note:
     // `log` arg can have three different values:
     // (1) not set => None
@@ -52,33 +61,96 @@ async fn main() -> Result<()> {
 
     if let Some(log_dir) = custom_log_dir {
         let dir: PathBuf = log_dir.unwrap_or(&cfg.log_dir).to_path_buf();
-        logging::Logger::new(dir).init()?;
+        logging::Logger::new(dir, args.log_level.unwrap_or_default()).init()?;
     }
 
-    let mut terminal = terminal::setup()?;
-    let events = events::Events::new();
+    // print samples of all styles only, no terminal needed
+    if args.list_styles {
+        for line in styles::list_styles() {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+
+    // headless simulation only, no terminal needed
+    if let Some(seconds) = args.simulate {
+        let initial_value = args
+            .countdown
+            .first()
+            .copied()
+            .or(args.countdown_file)
+            .or(cfg.defaults.countdown)
+            .unwrap_or(storage::DEFAULT_COUNTDOWN);
+        for line in simulation::simulate(initial_value, seconds) {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+
+    let mut terminal = terminal::setup(args.mouse)?;
 
     // check persistant storage
     let storage = Storage::new(cfg.data_dir);
     // option to reset previous stored data to `default`
     let stg = if args.reset {
-        AppStorage::default()
+        AppStorage::with_defaults(&cfg.defaults)
     } else {
-        storage.load().unwrap_or_default()
+        storage
+            .load()
+            .unwrap_or_else(|_| AppStorage::with_defaults(&cfg.defaults))
     };
 
-    let app_storage = App::from(FromAppArgs {
+    // render a single frame only, w/o handling any events or storing anything
+    if args.preview {
+        let (app_tx, _) = tokio::sync::mpsc::unbounded_channel();
+        let result = App::from(FromAppArgs {
+            args,
+            stg,
+            date_format: cfg.date_format,
+            clock: AppClock::System,
+            app_tx,
+            #[cfg(feature = "sound")]
+            sounds: cfg.sounds,
+        })
+        .preview(&mut terminal);
+        terminal::teardown()?;
+        return result;
+    }
+
+    let events = events::Events::new();
+    let persist = !args.no_persist;
+    let set_title = args.set_title;
+    if set_title {
+        terminal::save_title()?;
+    }
+
+    let result = App::from(FromAppArgs {
         args,
         stg,
+        date_format: cfg.date_format,
+        clock: AppClock::System,
         app_tx: events.get_app_event_tx(),
+        #[cfg(feature = "sound")]
+        sounds: cfg.sounds,
     })
     .run(&mut terminal, events)
-    .await?
-    .to_storage();
-    // store app state persistantly
-    storage.save(app_storage)?;
-
+    .await;
+    // Restore terminal before any error is reported, not on happy path only.
+    // Panics are handled by the panic hook set up by `terminal::setup`.
     terminal::teardown()?;
+    if set_title {
+        terminal::restore_title()?;
+    }
+
+    let app_storage = result?.to_storage();
+    // store app state persistantly
+    if persist {
+        storage.save(app_storage)?;
+    }
+    // store args as defaults of next launches
+    if let Some(saved_args) = saved_args {
+        config::save_args(&saved_args)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,102 @@
+use crate::{
+    constants::TICK_VALUE_MS,
+    duration::DurationEx,
+    events::AppEvent,
+    widgets::clock::{self, ClockState, ClockStateArgs, Countdown},
+};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Runs a countdown clock headless (w/o any terminal) for given `seconds`
+/// by ticking it as fast as possible.
+/// It returns all transitions (mode changes, events, blinking) of the clock
+/// prefixed by its simulated time.
+pub fn simulate(initial_value: Duration, seconds: u64) -> Vec<String> {
+    let (app_tx, mut app_rx) = mpsc::unbounded_channel();
+    let tick_value = Duration::from_millis(TICK_VALUE_MS);
+    let mut clock = ClockState::<Countdown>::new(ClockStateArgs {
+        initial_value,
+        current_value: initial_value,
+        tick_value,
+        with_decis: true,
+        app_tx: Some(app_tx),
+    });
+
+    let mut lines = vec![];
+    let mut log = |elapsed: Duration, msg: String| {
+        lines.push(format!(
+            "{} {}",
+            DurationEx::from(elapsed).to_string_with_decis(),
+            msg
+        ));
+    };
+
+    clock.run();
+    log(Duration::ZERO, format!("start {clock}"));
+
+    let mut elapsed = Duration::ZERO;
+    let mut mode = clock.get_mode().clone();
+    let mut blink = false;
+    let mut done_count = clock.get_done_count();
+
+    while elapsed < Duration::from_secs(seconds) {
+        elapsed += tick_value;
+        // same as `Countdown` widget does by handling `TuiEvent::Tick`
+        if clock.is_done() {
+            clock.update_done_count();
+        } else {
+            clock.advance(tick_value);
+        }
+
+        if clock.get_mode() != &mode {
+            mode = clock.get_mode().clone();
+            log(elapsed, format!("mode {mode}"));
+        }
+        while let Ok(event) = app_rx.try_recv() {
+            if let AppEvent::ClockDone(type_id, name, _) = event {
+                log(elapsed, format!("event ClockDone({type_id:?}, {name})"));
+            }
+        }
+        let next_blink = clock::should_blink(clock.get_done_count());
+        if next_blink != blink {
+            blink = next_blink;
+            log(
+                elapsed,
+                format!("blink {}", if blink { "on" } else { "off" }),
+            );
+        }
+        if done_count.is_some() && clock.get_done_count().is_none() {
+            log(elapsed, "done counted".into());
+        }
+        done_count = clock.get_done_count();
+    }
+
+    log(elapsed, format!("end {clock}"));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate() {
+        let lines = simulate(Duration::from_secs(1), 5);
+        assert_eq!(
+            lines,
+            vec![
+                "0.0 start 1.0 >",
+                "1.0 mode done",
+                "1.0 event ClockDone(Countdown, Countdown)",
+                "1.1 blink on",
+                "1.5 blink off",
+                "1.9 blink on",
+                "2.3 blink off",
+                "2.7 blink on",
+                "3.1 blink off",
+                "3.1 done counted",
+                "5.0 end 0.0 done",
+            ]
+        );
+    }
+}
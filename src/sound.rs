@@ -1,9 +1,14 @@
-use rodio::{Decoder, DeviceSinkBuilder, MixerDeviceSink, Source, source::Buffered};
+use rodio::{Decoder, DeviceSinkBuilder, Float, MixerDeviceSink, Player, Source, source::Buffered};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 use thiserror::Error;
+use tracing::warn;
+
+use crate::config::SoundsConfig;
 
 #[derive(Debug, Error)]
 pub enum SoundError {
@@ -15,6 +20,14 @@ pub enum SoundError {
     Decoder(String),
 }
 
+/// Time to fade out a playing sound by `Sound::stop` (instead of cutting it abruptly)
+const FADE_OUT: Duration = Duration::from_millis(300);
+/// Number of volume steps to fade out a sound
+const FADE_OUT_STEPS: u32 = 10;
+
+/// File extensions of supported sound formats
+const SUPPORTED_EXTENSIONS: [&str; 4] = ["mp3", "wav", "ogg", "flac"];
+
 pub fn validate_sound_file(path: &PathBuf) -> Result<&PathBuf, SoundError> {
     // validate path
     if !path.exists() {
@@ -25,19 +38,50 @@ pub fn validate_sound_file(path: &PathBuf) -> Result<&PathBuf, SoundError> {
     // Validate file extension
     path.extension()
         .and_then(|ext| ext.to_str())
-        .filter(|ext| ["mp3", "wav"].contains(&ext.to_lowercase().as_str()))
+        .filter(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
         .ok_or_else(|| {
             SoundError::File(
-                "Unsupported file extension. Only .mp3 and .wav are supported".to_owned(),
+                "Unsupported file extension. Only .mp3, .wav, .ogg and .flac are supported"
+                    .to_owned(),
             )
         })?;
 
     Ok(path)
 }
 
+/// Sounds configured by `[sounds]` table of config file
+#[derive(Default)]
+pub struct Sounds {
+    pub work_start: Option<Sound>,
+    pub pause_start: Option<Sound>,
+    pub long_pause: Option<Sound>,
+    pub done: Option<Sound>,
+}
+
+impl Sounds {
+    /// Loads all configured sounds. Invalid files are skipped with a warning.
+    pub fn new(config: &SoundsConfig) -> Self {
+        let load = |path: &Option<PathBuf>| {
+            let path = path.as_ref()?;
+            validate_sound_file(path)
+                .and_then(|_| Sound::new(path.clone()))
+                .inspect_err(|err| warn!("Skip sound file {:?}: {}", path, err))
+                .ok()
+        };
+        Self {
+            work_start: load(&config.work_start),
+            pause_start: load(&config.pause_start),
+            long_pause: load(&config.long_pause),
+            done: load(&config.done),
+        }
+    }
+}
+
 pub struct Sound {
     buffer: Arc<Buffered<Decoder<BufReader<File>>>>,
     stream: MixerDeviceSink,
+    /// Players of all sounds still playing. Each `play` gets its own one to not cut off others.
+    players: Vec<Player>,
 }
 
 impl Sound {
@@ -49,11 +93,85 @@ impl Sound {
         let decoder = Decoder::try_from(file).map_err(|e| SoundError::Decoder(e.to_string()))?;
         let buffer = Arc::new(decoder.buffered());
 
-        Ok(Self { buffer, stream })
+        Ok(Self {
+            buffer,
+            stream,
+            players: Vec::new(),
+        })
     }
 
-    pub fn play(&self) -> Result<(), SoundError> {
-        self.stream.mixer().add((*self.buffer).clone());
+    pub fn play(&mut self) -> Result<(), SoundError> {
+        // drop players of finished sounds
+        self.players.retain(|player| !player.empty());
+        let player = Player::connect_new(self.stream.mixer());
+        player.append((*self.buffer).clone());
+        self.players.push(player);
         Ok(())
     }
+
+    /// Fades out all playing sounds within `FADE_OUT`.
+    /// Fading happens in its own thread to not block the app.
+    pub fn stop(&mut self) {
+        let players: Vec<Player> = self
+            .players
+            .drain(..)
+            .filter(|player| !player.empty())
+            .collect();
+        if !players.is_empty() {
+            thread::spawn(move || {
+                let volumes: Vec<Float> = players.iter().map(Player::volume).collect();
+                for step in (0..FADE_OUT_STEPS).rev() {
+                    for (player, volume) in players.iter().zip(&volumes) {
+                        player.set_volume(volume * step as Float / FADE_OUT_STEPS as Float);
+                    }
+                    thread::sleep(FADE_OUT / FADE_OUT_STEPS);
+                }
+                players.iter().for_each(Player::stop);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (Empty) file in a temp. directory, which is removed by dropping it
+    struct TempFile(PathBuf);
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    /// Creates an (empty) file with given extension in a temp. directory
+    fn temp_file(ext: &str) -> TempFile {
+        let path = std::env::temp_dir().join(format!("timr-tui-sound-test.{ext}"));
+        File::create(&path).unwrap();
+        TempFile(path)
+    }
+
+    #[test]
+    fn test_validate_sound_file_supported() {
+        for ext in ["mp3", "wav", "ogg", "flac", "OGG", "Flac"] {
+            let file = temp_file(ext);
+            assert!(
+                validate_sound_file(&file.0).is_ok(),
+                "{ext} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_sound_file_unsupported() {
+        let file = temp_file("aac");
+        assert!(validate_sound_file(&file.0).is_err());
+    }
+
+    #[test]
+    fn test_validate_sound_file_missing() {
+        let path = std::env::temp_dir().join("timr-tui-sound-test-missing.ogg");
+        assert!(validate_sound_file(&path).is_err());
+    }
 }
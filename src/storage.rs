@@ -1,24 +1,33 @@
 use crate::{
-    common::{AppTimeFormat, Content, Style, Toggle},
+    common::{AppTimeFormat, Content, DecimalSeparator, Style, Theme, Toggle},
+    config::DefaultsConfig,
     duration::ONE_MINUTE,
     event::Event,
-    widgets::pomodoro::{Mode as PomodoroMode, PauseDuration},
+    widgets::{
+        clock::{DEFAULT_JUMP_STEP, Mode as ClockMode},
+        pomodoro::{Mode as PomodoroMode, PauseDuration},
+    },
 };
 use color_eyre::eyre::Result;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use std::time::Duration;
 
 const DEFAULT_WORK: Duration = ONE_MINUTE.saturating_mul(25); /* 25min */
 const DEFAULT_PAUSE: Duration = ONE_MINUTE.saturating_mul(5); /* 5min */
-const DEFAULT_COUNTDOWN: Duration = ONE_MINUTE.saturating_mul(10); /* 10min */
+pub const DEFAULT_COUNTDOWN: Duration = ONE_MINUTE.saturating_mul(10); /* 10min */
 const DEFAULT_PAUSE_DURATION: PauseDuration = PauseDuration::Fixed(DEFAULT_PAUSE);
 
 fn default_pause_duration() -> PauseDuration {
     DEFAULT_PAUSE_DURATION
 }
 
+fn default_jump_step() -> u32 {
+    DEFAULT_JUMP_STEP
+}
+
 fn deserialize_app_time_format<'de, D>(deserializer: D) -> Result<AppTimeFormat, D::Error>
 where
     D: Deserializer<'de>,
@@ -31,11 +40,58 @@ where
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Mode of a clock to restore it as it was
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClockModeStorage {
+    Initial,
+    Running,
+    Paused,
+    Done,
+}
+
+impl ClockModeStorage {
+    /// Whether a clock has been running (`true`) or paused (`false`).
+    /// `None` for any other mode, which is inferred by values of a clock.
+    pub fn running(self) -> Option<bool> {
+        match self {
+            Self::Running => Some(true),
+            Self::Paused => Some(false),
+            Self::Initial | Self::Done => None,
+        }
+    }
+}
+
+impl From<&ClockMode> for ClockModeStorage {
+    fn from(mode: &ClockMode) -> Self {
+        match mode {
+            ClockMode::Initial => Self::Initial,
+            ClockMode::Tick => Self::Running,
+            ClockMode::Pause => Self::Paused,
+            // mode before editing
+            ClockMode::Editable(_, prev) => Self::from(prev.as_ref()),
+            ClockMode::Done => Self::Done,
+        }
+    }
+}
+
+/// Values of an additional countdown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CountdownStorage {
+    pub name: Option<String>,
+    pub initial_value: Duration,
+    pub current_value: Duration,
+    pub elapsed_value: Duration,
+    #[serde(default)]
+    pub clock_mode: Option<ClockModeStorage>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct AppStorage {
     pub content: Content,
     pub show_menu: bool,
     #[serde(default)]
+    pub show_menu_by_content: BTreeMap<Content, bool>,
+    #[serde(default)]
     pub vim: Toggle,
     pub notification: Toggle,
     pub blink: Toggle,
@@ -43,11 +99,30 @@ pub struct AppStorage {
     pub app_time_format: AppTimeFormat,
     pub style: Style,
     pub with_decis: bool,
+    #[serde(default)]
+    pub decimal_separator: DecimalSeparator,
+    #[serde(default)]
+    pub zen: bool,
+    #[serde(default)]
+    pub dim: bool,
+    /// Lines to move content down (or up, if negative) from the center
+    #[serde(default)]
+    pub voffset: i16,
+    #[serde(default)]
+    pub theme: Option<Theme>,
+    #[serde(default = "default_jump_step")]
+    pub jump_step: u32,
     pub pomodoro_mode: PomodoroMode,
     pub pomodoro_count: u64,
     pub pomodoro_auto_switch: bool,
     #[serde(default)]
     pub pomodoro_max_rounds: Option<u64>,
+    #[serde(default)]
+    pub pomodoro_goal: Option<u64>,
+    #[serde(default)]
+    pub pomodoro_goal_progress: u64,
+    #[serde(default)]
+    pub pomodoro_goal_date: Option<time::Date>,
     // pomodoro -> work
     pub inital_value_work: Duration,
     pub current_value_work: Duration,
@@ -55,14 +130,52 @@ pub struct AppStorage {
     #[serde(default = "default_pause_duration")]
     pub pause_duration: PauseDuration,
     pub current_value_pause: Duration,
+    /// Mode of current pomodoro clock on quit (`None` to infer it by its values)
+    #[serde(default)]
+    pub clock_mode_pomodoro: Option<ClockModeStorage>,
     // countdown
     pub inital_value_countdown: Duration,
     pub current_value_countdown: Duration,
     pub elapsed_value_countdown: Duration,
+    #[serde(default)]
+    pub count_up_countdown: bool,
+    /// Don't count elapsed time (MET) after countdown is done
+    #[serde(default)]
+    pub no_met_countdown: bool,
+    #[serde(default)]
+    pub warn_secs_countdown: u64,
+    #[serde(default)]
+    pub pause_at_countdown: Option<Duration>,
+    #[serde(default)]
+    pub name_countdown: Option<String>,
+    /// Durations to count down one after another
+    #[serde(default)]
+    pub sequence_countdown: Vec<Duration>,
+    /// Index of current step of `sequence_countdown`
+    #[serde(default)]
+    pub sequence_index_countdown: usize,
+    /// Countdowns next to the active one
+    #[serde(default)]
+    pub other_countdowns: Vec<CountdownStorage>,
+    /// Mode of countdown on quit (`None` to infer it by its values)
+    #[serde(default)]
+    pub clock_mode_countdown: Option<ClockModeStorage>,
+    /// Absolute time countdown finishes (if anchored on it) to recompute its time left
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub target_countdown: Option<time::OffsetDateTime>,
     // timer
     pub current_value_timer: Duration,
+    /// Mode of timer on quit (`None` to infer it by its values)
+    #[serde(default)]
+    pub clock_mode_timer: Option<ClockModeStorage>,
     // event
     pub event: Event,
+    /// Upcoming milestones after `event`
+    #[serde(default)]
+    pub milestones_event: Vec<Event>,
+    // alarm
+    #[serde(default)]
+    pub alarm: Option<time::Time>,
     // footer
     pub footer_app_time: Toggle,
 }
@@ -72,36 +185,80 @@ impl Default for AppStorage {
         AppStorage {
             content: Content::default(),
             show_menu: true,
+            show_menu_by_content: BTreeMap::new(),
             vim: Toggle::Off,
             notification: Toggle::Off,
             blink: Toggle::Off,
             app_time_format: AppTimeFormat::default(),
             style: Style::default(),
+            decimal_separator: DecimalSeparator::default(),
+            zen: false,
+            dim: false,
+            voffset: 0,
+            theme: None,
+            jump_step: DEFAULT_JUMP_STEP,
             with_decis: false,
             pomodoro_mode: PomodoroMode::Work,
             pomodoro_count: 1,
             pomodoro_auto_switch: false,
             pomodoro_max_rounds: None,
+            pomodoro_goal: None,
+            pomodoro_goal_progress: 0,
+            pomodoro_goal_date: None,
             // pomodoro -> work
             inital_value_work: DEFAULT_WORK,
             current_value_work: DEFAULT_WORK,
             // pomodoro -> pause
             pause_duration: DEFAULT_PAUSE_DURATION,
             current_value_pause: DEFAULT_PAUSE,
+            clock_mode_pomodoro: None,
             // countdown
             inital_value_countdown: DEFAULT_COUNTDOWN,
             current_value_countdown: DEFAULT_COUNTDOWN,
             elapsed_value_countdown: Duration::ZERO,
+            count_up_countdown: false,
+            no_met_countdown: false,
+            warn_secs_countdown: 0,
+            pause_at_countdown: None,
+            name_countdown: None,
+            sequence_countdown: Vec::new(),
+            sequence_index_countdown: 0,
+            other_countdowns: Vec::new(),
+            clock_mode_countdown: None,
+            target_countdown: None,
             // timer
             current_value_timer: Duration::ZERO,
+            clock_mode_timer: None,
             // event
             event: Event::default(),
+            milestones_event: Vec::new(),
+            // alarm
+            alarm: None,
             // footer
             footer_app_time: Toggle::Off,
         }
     }
 }
 
+impl AppStorage {
+    /// Same as `AppStorage::default`, but with durations set by config file (if any)
+    pub fn with_defaults(defaults: &DefaultsConfig) -> Self {
+        let stg = AppStorage::default();
+        let work = defaults.work.unwrap_or(stg.inital_value_work);
+        let pause = defaults.pause.unwrap_or(DEFAULT_PAUSE);
+        let countdown = defaults.countdown.unwrap_or(stg.inital_value_countdown);
+        AppStorage {
+            inital_value_work: work,
+            current_value_work: work,
+            pause_duration: PauseDuration::Fixed(pause),
+            current_value_pause: pause,
+            inital_value_countdown: countdown,
+            current_value_countdown: countdown,
+            ..stg
+        }
+    }
+}
+
 pub struct Storage {
     data_dir: PathBuf,
 }
@@ -127,3 +284,142 @@ impl Storage {
         Ok(data)
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::common::Theme;
+    use time::macros::{date, datetime, time};
+
+    /// `AppStorage` with (non default) values of all fields
+    pub fn stg() -> AppStorage {
+        AppStorage {
+            content: Content::Pomodoro,
+            show_menu: false,
+            show_menu_by_content: BTreeMap::from([(Content::Timer, true)]),
+            vim: Toggle::On,
+            notification: Toggle::On,
+            blink: Toggle::On,
+            app_time_format: AppTimeFormat::Hh12MmSs,
+            style: Style::Cross,
+            with_decis: true,
+            decimal_separator: DecimalSeparator::Comma,
+            zen: true,
+            dim: true,
+            voffset: -2,
+            theme: Some(Theme::Nord),
+            jump_step: 5,
+            pomodoro_mode: PomodoroMode::Pause,
+            pomodoro_count: 3,
+            pomodoro_auto_switch: true,
+            pomodoro_max_rounds: Some(4),
+            pomodoro_goal: Some(8),
+            pomodoro_goal_progress: 2,
+            pomodoro_goal_date: Some(date!(2024 - 06 - 10)),
+            inital_value_work: ONE_MINUTE * 50,
+            current_value_work: ONE_MINUTE * 40,
+            pause_duration: PauseDuration::Fixed(ONE_MINUTE * 10),
+            current_value_pause: ONE_MINUTE * 7,
+            clock_mode_pomodoro: Some(ClockModeStorage::Running),
+            inital_value_countdown: ONE_MINUTE * 3,
+            current_value_countdown: ONE_MINUTE * 2,
+            elapsed_value_countdown: Duration::ZERO,
+            count_up_countdown: true,
+            no_met_countdown: true,
+            warn_secs_countdown: 10,
+            pause_at_countdown: Some(ONE_MINUTE),
+            name_countdown: Some("tea".into()),
+            sequence_countdown: vec![ONE_MINUTE * 3, ONE_MINUTE],
+            sequence_index_countdown: 0,
+            other_countdowns: vec![CountdownStorage {
+                name: Some("egg".into()),
+                initial_value: ONE_MINUTE * 6,
+                current_value: ONE_MINUTE * 4,
+                elapsed_value: Duration::ZERO,
+                clock_mode: Some(ClockModeStorage::Running),
+            }],
+            clock_mode_countdown: Some(ClockModeStorage::Paused),
+            target_countdown: None,
+            current_value_timer: ONE_MINUTE * 12,
+            clock_mode_timer: Some(ClockModeStorage::Running),
+            event: Event {
+                date_time: datetime!(2025-01-01 00:00),
+                title: Some("new year".into()),
+            },
+            milestones_event: vec![Event {
+                date_time: datetime!(2025-12-24 18:00),
+                title: None,
+            }],
+            alarm: Some(time!(7:30)),
+            footer_app_time: Toggle::On,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let json = serde_json::to_string(&stg()).unwrap();
+        let stg_json: AppStorage = serde_json::from_str(&json).unwrap();
+        assert_eq!(stg_json, stg());
+    }
+
+    #[test]
+    fn test_round_trip_default() {
+        let json = serde_json::to_string(&AppStorage::default()).unwrap();
+        let stg_json: AppStorage = serde_json::from_str(&json).unwrap();
+        assert_eq!(stg_json, AppStorage::default());
+    }
+
+    #[test]
+    fn test_target_countdown() {
+        let stg = AppStorage {
+            target_countdown: Some(datetime!(2025-01-01 00:00 +1)),
+            ..AppStorage::default()
+        };
+        let json = serde_json::to_string(&stg).unwrap();
+        assert!(json.contains(r#""target_countdown":"2025-01-01T00:00:00+01:00""#));
+        let stg_json: AppStorage = serde_json::from_str(&json).unwrap();
+        assert_eq!(stg_json, stg);
+    }
+
+    #[test]
+    fn test_clock_mode_storage() {
+        assert_eq!(
+            ClockModeStorage::from(&ClockMode::Tick),
+            ClockModeStorage::Running
+        );
+        // mode before editing
+        assert_eq!(
+            ClockModeStorage::from(&ClockMode::Editable(
+                crate::widgets::clock::Time::Minutes,
+                Box::new(ClockMode::Pause)
+            )),
+            ClockModeStorage::Paused
+        );
+        assert_eq!(ClockModeStorage::Running.running(), Some(true));
+        assert_eq!(ClockModeStorage::Paused.running(), Some(false));
+        assert_eq!(ClockModeStorage::Done.running(), None);
+    }
+
+    #[test]
+    fn test_with_defaults_empty() {
+        let stg = AppStorage::with_defaults(&DefaultsConfig::default());
+        assert_eq!(stg.inital_value_work, DEFAULT_WORK);
+        assert_eq!(stg.pause_duration, DEFAULT_PAUSE_DURATION);
+        assert_eq!(stg.inital_value_countdown, DEFAULT_COUNTDOWN);
+    }
+
+    #[test]
+    fn test_with_defaults() {
+        let stg = AppStorage::with_defaults(&DefaultsConfig {
+            work: Some(ONE_MINUTE * 50),
+            pause: Some(ONE_MINUTE * 10),
+            countdown: Some(ONE_MINUTE * 3),
+        });
+        assert_eq!(stg.inital_value_work, ONE_MINUTE * 50);
+        assert_eq!(stg.current_value_work, ONE_MINUTE * 50);
+        assert_eq!(stg.pause_duration, PauseDuration::Fixed(ONE_MINUTE * 10));
+        assert_eq!(stg.current_value_pause, ONE_MINUTE * 10);
+        assert_eq!(stg.inital_value_countdown, ONE_MINUTE * 3);
+        assert_eq!(stg.current_value_countdown, ONE_MINUTE * 3);
+    }
+}
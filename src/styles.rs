@@ -0,0 +1,70 @@
+use crate::{
+    common::Style,
+    widgets::clock_elements::{
+        COLON_WIDTH, Colon, DIGIT_HEIGHT, DIGIT_SPACE_WIDTH, DIGIT_WIDTH, Digit,
+    },
+};
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+};
+
+/// Renders a sample (`88:88`) of every `Style` (w/o any terminal)
+/// and returns it as plain text lines, each sample is prefixed by its style name.
+pub fn list_styles() -> Vec<String> {
+    Style::value_variants()
+        .iter()
+        .flat_map(|style| {
+            let name = style
+                .to_possible_value()
+                .map(|v| v.get_name().to_owned())
+                .unwrap_or_default();
+            std::iter::once(name).chain(render_sample(style.get_digit_symbol()))
+        })
+        .collect()
+}
+
+/// Renders `88:88` by given symbol
+fn render_sample(symbol: &str) -> Vec<String> {
+    let widths = [
+        DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
+        DIGIT_WIDTH,
+        COLON_WIDTH,
+        DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
+        DIGIT_WIDTH,
+    ];
+    // skip bottom border of digits
+    let area = Rect::new(0, 0, widths.iter().sum(), DIGIT_HEIGHT - 1);
+    let mut buf = Buffer::empty(area);
+    let [d1, d2, colon, d3, d4] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
+    for digit_area in [d1, d2, d3, d4] {
+        Digit::new(8, false, symbol).render(digit_area, &mut buf);
+    }
+    Colon::new(symbol).render(colon, &mut buf);
+
+    (area.top()..area.bottom())
+        .map(|y| {
+            let row: String = (area.left()..area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect();
+            row.trim_end().to_owned()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_styles() {
+        let lines = list_styles();
+        // name + 5 rows for each style
+        assert_eq!(lines.len(), Style::value_variants().len() * 6);
+        assert_eq!(lines[0], "full");
+        assert_eq!(lines[1], "█████ █████    █████ █████");
+        assert_eq!(lines[2], "██ ██ ██ ██ ██ ██ ██ ██ ██");
+    }
+}
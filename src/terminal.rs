@@ -2,18 +2,24 @@ use std::io;
 
 use color_eyre::eyre::Result;
 use crossterm::{
-    cursor, execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+    cursor,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    style::Print,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, SetTitle},
 };
 use ratatui::{Terminal as RatatuiTerminal, backend::CrosstermBackend};
 
 pub type Terminal = RatatuiTerminal<CrosstermBackend<io::Stdout>>;
 
-pub fn setup() -> Result<Terminal> {
+pub fn setup(mouse: bool) -> Result<Terminal> {
     let mut stdout = std::io::stdout();
     crossterm::terminal::enable_raw_mode()?;
     set_panic_hook();
     execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    if mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let mut terminal = RatatuiTerminal::new(CrosstermBackend::new(stdout))?;
     terminal.clear()?;
     terminal.hide_cursor()?;
@@ -21,11 +27,28 @@ pub fn setup() -> Result<Terminal> {
 }
 
 pub fn teardown() -> Result<()> {
-    execute!(io::stdout(), LeaveAlternateScreen, cursor::Show)?;
+    execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        cursor::Show
+    )?;
     crossterm::terminal::disable_raw_mode()?;
     Ok(())
 }
 
+/// Saves current title of terminal on its stack (xterm `XTWINOPS`) to restore it by `restore_title`
+pub fn save_title() -> Result<()> {
+    execute!(io::stdout(), Print("\x1b[22;0t"))?;
+    Ok(())
+}
+
+/// Clears title of terminal and restores the one saved by `save_title` (if supported)
+pub fn restore_title() -> Result<()> {
+    execute!(io::stdout(), SetTitle(""), Print("\x1b[23;0t"))?;
+    Ok(())
+}
+
 // Panic hook
 // see https://ratatui.rs/tutorials/counter-app/error-handling/#setup-hooks
 fn set_panic_hook() {
@@ -1,3 +1,6 @@
+pub mod alarm;
+#[cfg(test)]
+pub mod alarm_test;
 pub mod clock;
 pub mod clock_elements;
 #[cfg(test)]
@@ -6,6 +9,9 @@ pub mod clock_elements_test;
 pub mod clock_test;
 #[cfg(test)]
 pub mod clock_widget_test;
+pub mod command;
+#[cfg(test)]
+pub mod command_test;
 pub mod countdown;
 #[cfg(test)]
 pub mod countdown_test;
@@ -19,6 +25,9 @@ pub mod footer_test;
 pub mod header;
 #[cfg(test)]
 pub mod header_test;
+pub mod help;
+#[cfg(test)]
+pub mod help_test;
 pub mod local_time;
 #[cfg(test)]
 pub mod local_time_test;
@@ -27,6 +36,11 @@ pub mod pomodoro;
 pub mod pomodoro_test;
 pub mod progressbar;
 #[cfg(test)]
+pub mod progressbar_test;
+pub mod settings;
+#[cfg(test)]
+pub mod settings_test;
+#[cfg(test)]
 pub mod test_utils;
 pub mod timer;
 #[cfg(test)]
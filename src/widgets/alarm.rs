@@ -0,0 +1,278 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Modifier,
+    text::Line,
+    widgets::{StatefulWidget, Widget},
+};
+use time::{OffsetDateTime, Time, macros::format_description};
+
+use crate::{
+    common::{
+        AppTime, AppTimeFormat, ClockName, ClockTypeId, DecimalSeparator, Flip, Spacing,
+        Style as DigitStyle,
+    },
+    duration::{DurationEx, ONE_DAY},
+    events::{AppEvent, AppEventTx, TuiEvent, TuiEventHandler},
+    widgets::{clock, clock_elements::DIGIT_HEIGHT},
+};
+use std::{cmp::max, time::Duration};
+
+/// State for `AlarmWidget`
+pub struct AlarmState {
+    alarm: Option<Time>,
+    app_time: OffsetDateTime,
+    with_decis: bool,
+    /// counter to simulate `DONE` state
+    /// Default value: `None`
+    done_count: Option<u64>,
+    app_tx: AppEventTx,
+}
+
+pub struct AlarmStateArgs {
+    pub app_time: AppTime,
+    pub alarm: Option<Time>,
+    pub with_decis: bool,
+    pub app_tx: AppEventTx,
+}
+
+impl AlarmState {
+    pub fn new(args: AlarmStateArgs) -> Self {
+        let AlarmStateArgs {
+            app_time,
+            alarm,
+            with_decis,
+            app_tx,
+        } = args;
+
+        Self {
+            alarm,
+            app_time: OffsetDateTime::from(app_time),
+            with_decis,
+            done_count: None,
+            app_tx,
+        }
+    }
+
+    pub fn get_alarm(&self) -> Option<Time> {
+        self.alarm
+    }
+
+    // Sets `app_time`
+    pub fn set_app_time(&mut self, app_time: AppTime) {
+        let prev_time = self.app_time.time();
+        self.app_time = OffsetDateTime::from(app_time);
+
+        // Since updating `app_time` is like a `Tick`, we check `done` state here
+        if let Some(alarm) = self.alarm
+            && is_crossed(prev_time, self.app_time.time(), alarm)
+        {
+            self.done_count = Some(clock::MAX_DONE_COUNT);
+            _ = self.app_tx.send(AppEvent::ClockDone(
+                ClockTypeId::Alarm,
+                ClockName::from("Alarm"),
+                None,
+            ));
+        }
+        // count (possible) `done`
+        self.done_count = clock::count_clock_done(self.done_count);
+    }
+
+    pub fn set_with_decis(&mut self, with_decis: bool) {
+        self.with_decis = with_decis;
+    }
+
+    /// Time left until the alarm goes off next time (today or tomorrow)
+    pub fn get_duration(&self) -> Option<Duration> {
+        self.alarm
+            .map(|alarm| duration_until(self.app_time.time(), alarm))
+    }
+
+    pub fn get_percentage_done(&self) -> Option<u16> {
+        self.get_duration().map(|left| {
+            let elapsed = ONE_DAY.saturating_sub(left);
+            (elapsed.as_millis() * 100 / ONE_DAY.as_millis()) as u16
+        })
+    }
+}
+
+/// Checks whether `alarm` has been passed by moving from `prev` to `current`.
+/// Moving over midnight is handled as well.
+fn is_crossed(prev: Time, current: Time, alarm: Time) -> bool {
+    if prev <= current {
+        prev < alarm && alarm <= current
+    } else {
+        // midnight has been passed
+        prev < alarm || alarm <= current
+    }
+}
+
+/// Time left from `current` until `alarm`.
+/// If `alarm` has already been passed today, it's re-armed for the next day.
+fn duration_until(current: Time, alarm: Time) -> Duration {
+    let since_midnight = |t: Time| {
+        let (h, m, s, nanos) = t.as_hms_nano();
+        Duration::new(
+            u64::from(h) * 3600 + u64::from(m) * 60 + u64::from(s),
+            nanos,
+        )
+    };
+    let current = since_midnight(current);
+    let alarm = since_midnight(alarm);
+    if alarm > current {
+        alarm - current
+    } else {
+        ONE_DAY - (current - alarm)
+    }
+}
+
+impl TuiEventHandler for AlarmState {
+    fn update(&mut self, event: TuiEvent) -> Option<TuiEvent> {
+        // we don't handle any event in this widget,
+        Some(event)
+    }
+}
+
+#[derive(Debug)]
+pub struct AlarmWidget {
+    pub style: DigitStyle,
+    pub blink: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
+    /// Renders a line of time left, time of alarm and percentage done below label
+    pub status_line: bool,
+    /// Format of time of alarm in status line
+    pub app_time_format: AppTimeFormat,
+    /// Space between digits and around colons
+    pub spacing: Spacing,
+    /// Dims digits (`--dim`)
+    pub dim: bool,
+}
+
+impl StatefulWidget for AlarmWidget {
+    type State = AlarmState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let (Some(alarm), Some(duration)) = (state.alarm, state.get_duration()) else {
+            let label = Line::raw("No alarm set".to_uppercase());
+            let area = area.centered(
+                Constraint::Length(label.width() as u16),
+                Constraint::Length(1),
+            );
+            label.render(area, buf);
+            return;
+        };
+
+        let with_decis = state.with_decis;
+        let duration = DurationEx::from(duration);
+        let clock_format = clock::format_by_duration(&duration);
+        let clock_widths =
+            clock::clock_horizontal_lengths(&clock_format, with_decis, true, self.spacing);
+        let clock_width = clock_widths.iter().sum();
+
+        let alarm_label = alarm
+            .format(format_description!("[hour]:[minute]"))
+            .unwrap_or_else(|e| format!("time format error: {}", e));
+        let label = Line::raw(format!("Alarm {}", alarm_label).to_uppercase());
+        let label_status = self.status_line.then(|| {
+            Line::raw(
+                clock::status_line(
+                    duration,
+                    AppTime::Local(state.app_time).saturating_add(duration.into()),
+                    state.get_percentage_done().unwrap_or_default(),
+                    &self.app_time_format,
+                )
+                .to_uppercase(),
+            )
+        });
+        let label_status_height = label_status.as_ref().map_or(0, |_| 1);
+
+        let area = area.centered(
+            Constraint::Length(max(
+                clock_width,
+                max(
+                    label.width() as u16,
+                    label_status.as_ref().map_or(0, |l| l.width() as u16),
+                ),
+            )),
+            Constraint::Length(DIGIT_HEIGHT + 1 /* label */ + label_status_height),
+        );
+        let [v1, v2, v3] = Layout::vertical(Constraint::from_lengths([
+            DIGIT_HEIGHT,
+            1,
+            label_status_height,
+        ]))
+        .areas(area);
+
+        // To simulate a blink effect, just use an "empty" symbol (string)
+        // It's "empty" all digits and creates an "empty" render area
+        let symbol = if self.blink && clock::should_blink(state.done_count) {
+            " "
+        } else {
+            self.style.get_digit_symbol()
+        };
+
+        clock::render_clock(
+            v1,
+            buf,
+            clock::RenderClockState {
+                with_decis,
+                decimal_separator: self.decimal_separator,
+                flip: self.flip,
+                duration,
+                editable_time: None,
+                format: clock_format,
+                symbol,
+                widths: clock_widths,
+                with_labels: true,
+                spacing: self.spacing,
+            },
+        );
+        if self.dim {
+            buf.set_style(v1, Modifier::DIM);
+        }
+        label.centered().render(v2, buf);
+        if let Some(label_status) = label_status {
+            label_status.centered().render(v3, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::time;
+
+    #[test]
+    fn test_is_crossed() {
+        // same day
+        assert!(is_crossed(time!(6:59:59.9), time!(7:00), time!(7:00)));
+        assert!(!is_crossed(time!(7:00), time!(7:00:00.1), time!(7:00)));
+        assert!(!is_crossed(time!(6:00), time!(6:30), time!(7:00)));
+        // over midnight
+        assert!(is_crossed(time!(23:59:59.9), time!(0:00), time!(0:00)));
+        assert!(is_crossed(
+            time!(23:59:59.9),
+            time!(0:00:00.1),
+            time!(23:59:59.95)
+        ));
+        assert!(!is_crossed(
+            time!(23:59:59.9),
+            time!(0:00:00.1),
+            time!(7:00)
+        ));
+    }
+
+    #[test]
+    fn test_duration_until() {
+        assert_eq!(
+            duration_until(time!(6:00), time!(7:00)),
+            Duration::from_secs(3600)
+        );
+        // re-armed for next day
+        assert_eq!(duration_until(time!(7:00), time!(7:00)), ONE_DAY);
+        assert_eq!(
+            duration_until(time!(8:00), time!(7:00)),
+            ONE_DAY - Duration::from_secs(3600)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    common::{AppTime, AppTimeFormat, DecimalSeparator, Spacing, Style},
+    widgets::{
+        alarm::{AlarmState, AlarmStateArgs, AlarmWidget},
+        test_utils::{DrawArgs, FIXED_TIME, app_channel, app_tx, draw},
+    },
+};
+use insta::assert_snapshot;
+use ratatui::{Terminal, backend::TestBackend};
+use time::macros::time;
+
+fn args() -> AlarmStateArgs {
+    AlarmStateArgs {
+        app_time: AppTime::Utc(FIXED_TIME),
+        alarm: Some(time!(7:00)),
+        with_decis: false,
+        app_tx: app_tx(),
+    }
+}
+
+fn st_with_args(args: AlarmStateArgs) -> AlarmState {
+    AlarmState::new(args)
+}
+
+fn w() -> AlarmWidget {
+    AlarmWidget {
+        style: Style::default(),
+        blink: false,
+        decimal_separator: DecimalSeparator::default(),
+        flip: None,
+        status_line: false,
+        app_time_format: AppTimeFormat::default(),
+        spacing: Spacing::default(),
+        dim: false,
+    }
+}
+
+fn terminal(w: AlarmWidget, st: AlarmState) -> Terminal<TestBackend> {
+    draw(DrawArgs {
+        widget: w,
+        state: st,
+        width: 70,
+        height: 16,
+    })
+}
+
+#[test]
+fn test_alarm_armed() {
+    let t = terminal(w(), st_with_args(args()));
+    assert_snapshot!("alarm_armed", t.backend());
+}
+
+#[test]
+fn test_alarm_status_line() {
+    let t = terminal(
+        AlarmWidget {
+            status_line: true,
+            ..w()
+        },
+        st_with_args(args()),
+    );
+    assert_snapshot!("alarm_status_line", t.backend());
+}
+
+#[test]
+fn test_alarm_not_set() {
+    let t = terminal(
+        w(),
+        st_with_args(AlarmStateArgs {
+            alarm: None,
+            ..args()
+        }),
+    );
+    assert_snapshot!("alarm_not_set", t.backend());
+}
+
+#[test]
+fn test_alarm_fires_once() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_with_args(AlarmStateArgs {
+        app_time: AppTime::Utc(FIXED_TIME.replace_time(time!(6:59:59.9))),
+        app_tx: tx,
+        ..args()
+    });
+    st.set_app_time(AppTime::Utc(FIXED_TIME.replace_time(time!(7:00))));
+    st.set_app_time(AppTime::Utc(FIXED_TIME.replace_time(time!(7:00:00.1))));
+    assert!(rx.try_recv().is_ok());
+    assert!(rx.try_recv().is_err());
+}
@@ -1,27 +1,30 @@
 use std::marker::PhantomData;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{fmt, ops::Mul};
 use strum::Display;
+use time::OffsetDateTime;
 
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
-    style::{Modifier, Style},
-    text::Span,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{StatefulWidget, Widget},
 };
 
-use crate::widgets::clock_elements::FOUR_DIGITS_WIDTH;
 use crate::{
-    common::{ClockDescription, ClockName, ClockTypeId, Style as DigitStyle},
+    common::{
+        AppTime, AppTimeFormat, ClockDescription, ClockName, ClockTypeId, DecimalSeparator, Flip,
+        Spacing, Style as DigitStyle,
+    },
     duration::{
         ClockDuration, DurationEx, MAX_DURATION, ONE_DAY, ONE_DECI_SECOND, ONE_HOUR, ONE_MINUTE,
         ONE_SECOND, ONE_YEAR,
     },
     events::{AppEvent, AppEventTx},
     widgets::clock_elements::{
-        COLON_WIDTH, Colon, DIGIT_HEIGHT, DIGIT_LABEL_WIDTH, DIGIT_SPACE_WIDTH, DIGIT_WIDTH,
-        DOT_WIDTH, Digit, Dot, THREE_DIGITS_WIDTH, TWO_DIGITS_WIDTH,
+        Colon, DIGIT_HEIGHT, DIGIT_LABEL_WIDTH, DIGIT_SPACE_WIDTH, DIGIT_WIDTH, DOT_WIDTH, Digit,
+        Dot,
     },
 };
 
@@ -72,6 +75,8 @@ impl fmt::Display for Mode {
 pub enum Format {
     S,
     Ss,
+    /// Seconds only (up to `999`), see `format_by_seconds`
+    Sss,
     MSs,
     MmSs,
     HMmSs,
@@ -139,6 +144,17 @@ pub fn format_by_duration<D: ClockDuration>(d: &D) -> Format {
     }
 }
 
+/// `Format` to render total seconds only (w/o escalating to minutes),
+/// which is limited to three digits (`999`).
+pub fn format_by_seconds<D: ClockDuration>(d: &D) -> Option<Format> {
+    match d.seconds() {
+        0..10 => Some(Format::S),
+        10..100 => Some(Format::Ss),
+        100..1000 => Some(Format::Sss),
+        _ => None,
+    }
+}
+
 pub fn time_by_format(format: &Format) -> Time {
     match format {
         Format::YDddHhMmSs
@@ -156,7 +172,7 @@ pub fn time_by_format(format: &Format) -> Time {
         Format::DddHhMmSs | Format::DdHhMmSs | Format::DHhMmSs => Time::Days,
         Format::HhMmSs | Format::HMmSs => Time::Hours,
         Format::MmSs | Format::MSs => Time::Minutes,
-        Format::Ss | Format::S => Time::Seconds,
+        Format::Sss | Format::Ss | Format::S => Time::Seconds,
     }
 }
 
@@ -174,6 +190,41 @@ pub fn count_by_mode(times: u32, mode: &Mode) -> Duration {
 
 const RANGE_OF_DONE_COUNT: u64 = 4;
 pub const MAX_DONE_COUNT: u64 = RANGE_OF_DONE_COUNT * 5;
+/// Default number of units to change a value by jumping in edit mode
+pub const DEFAULT_JUMP_STEP: u32 = 10;
+
+/// Max. time between two edits to count them as repeated (e.g. by holding a key)
+const EDIT_REPEAT_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Number of units to edit by, accelerated by the number of repeated edits
+pub fn edit_acceleration(repeats: u32) -> u32 {
+    match repeats {
+        0..10 => 1,
+        10..20 => 2,
+        20..30 => 5,
+        _ => 10,
+    }
+}
+
+/// Latest edit to detect repeated edits in same direction
+struct EditRepeat {
+    up: bool,
+    count: u32,
+    at: Instant,
+}
+
+/// Wall-clock span of a clock from its first run, incl. time paused since then
+#[derive(Default)]
+struct Session {
+    started_at: Option<OffsetDateTime>,
+    paused_at: Option<OffsetDateTime>,
+    paused: Duration,
+}
+
+/// Time between two `OffsetDateTime` or zero, if `end` is before `start`
+fn time_between(start: OffsetDateTime, end: OffsetDateTime) -> Duration {
+    (end - start).try_into().unwrap_or_default()
+}
 
 pub struct ClockState<T> {
     type_id: ClockTypeId,
@@ -183,6 +234,11 @@ pub struct ClockState<T> {
     current_value: DurationEx,
     prev_value: DurationEx,
     tick_value: DurationEx,
+    /// Max. value to reach by ticking or editing. It can't exceed `MAX_DURATION`.
+    max_value: DurationEx,
+    /// Number of units to change a value by jumping in edit mode
+    jump_step: u32,
+    edit_repeat: Option<EditRepeat>,
     mode: Mode,
     format: Format,
     pub with_decis: bool,
@@ -192,6 +248,11 @@ pub struct ClockState<T> {
     /// Updates happened in `update_done_count`
     /// Default value: `None`
     done_count: Option<u64>,
+    session: Session,
+    /// Current time to measure `session` by (see `set_app_time`)
+    app_time: Option<OffsetDateTime>,
+    /// Wraps value to zero by reaching `max_value` instead of being done (timer only)
+    wrap: bool,
     phantom: PhantomData<T>,
 }
 
@@ -209,6 +270,44 @@ impl<T> ClockState<T> {
         self
     }
 
+    pub fn with_app_time(mut self, app_time: AppTime) -> Self {
+        self.set_app_time(app_time);
+        self
+    }
+
+    pub fn with_max_value(mut self, value: Duration) -> Self {
+        self.set_max_value(value);
+        self
+    }
+
+    pub fn get_max_value(&self) -> &DurationEx {
+        &self.max_value
+    }
+
+    /// Sets max. value (capped by `MAX_DURATION`).
+    /// Initial and current values exceeding it are capped as well.
+    pub fn set_max_value(&mut self, value: Duration) {
+        let value = value.min(MAX_DURATION);
+        self.max_value = value.into();
+        self.initial_value = Duration::from(self.initial_value).min(value).into();
+        self.current_value = Duration::from(self.current_value).min(value).into();
+        self.prev_value = Duration::from(self.prev_value).min(value).into();
+        self.update_format();
+    }
+
+    pub fn with_jump_step(mut self, step: u32) -> Self {
+        self.jump_step = step;
+        self
+    }
+
+    pub fn get_jump_step(&self) -> u32 {
+        self.jump_step
+    }
+
+    pub fn set_jump_step(&mut self, step: u32) {
+        self.jump_step = step;
+    }
+
     pub fn get_name_or_default(&self) -> ClockName {
         self.name
             .clone()
@@ -229,6 +328,7 @@ impl<T> ClockState<T> {
 
     pub fn with_mode(mut self, mode: Mode) -> Self {
         self.mode = mode;
+        self.update_session();
         self
     }
 
@@ -241,18 +341,62 @@ impl<T> ClockState<T> {
     }
 
     pub fn run(&mut self) {
-        self.mode = Mode::Tick
+        self.mode = Mode::Tick;
+        self.update_session();
+    }
+
+    pub fn pause(&mut self) {
+        self.mode = Mode::Pause;
+        self.update_session();
     }
 
     pub fn is_running(&self) -> bool {
         self.mode == Mode::Tick
     }
 
+    /// Runs or pauses the clock explicitly.
+    /// Note: Clocks in `Initial` (not started yet) mode are not paused,
+    /// clocks in `Done` or `Editable` mode are not changed at all.
+    pub fn set_running(&mut self, running: bool) {
+        match self.mode {
+            Mode::Initial | Mode::Pause if running => self.run(),
+            Mode::Tick if !running => self.pause(),
+            _ => {}
+        }
+    }
+
     pub fn toggle_pause(&mut self) {
         self.mode = if self.mode == Mode::Tick {
             Mode::Pause
         } else {
             Mode::Tick
+        };
+        self.update_session();
+    }
+
+    /// Sets current time to measure a session by
+    pub fn set_app_time(&mut self, app_time: AppTime) {
+        self.app_time = Some(app_time.into());
+    }
+
+    /// Starts a session by first run and tracks time paused (incl. editing) since then
+    fn update_session(&mut self) {
+        let Some(now) = self.app_time else {
+            return;
+        };
+        let session = &mut self.session;
+        match (
+            self.mode == Mode::Tick,
+            session.started_at,
+            session.paused_at,
+        ) {
+            (true, None, _) => session.started_at = Some(now),
+            (true, Some(_), Some(paused_at)) => {
+                session.paused += time_between(paused_at, now);
+                session.paused_at = None;
+            }
+            (false, Some(_), None) => session.paused_at = Some(now),
+            _ => {}
         }
     }
 
@@ -309,28 +453,36 @@ impl<T> ClockState<T> {
                 }
             }
         };
+        self.update_session();
     }
 
-    fn edit_current_up(&mut self, times: u32) {
+    /// Returns `false` if value is not changed because it would exceed `max_value`
+    fn edit_current_up(&mut self, times: u32) -> bool {
         let count_value = count_by_mode(times, self.get_mode());
 
-        if self
-            .get_current_value()
-            .le(&MAX_DURATION.saturating_sub(count_value).into())
+        if self.get_current_value().le(&Duration::from(self.max_value)
+            .saturating_sub(count_value)
+            .into())
         {
             self.current_value = self.get_current_value().saturating_add(count_value.into());
             self.update_format();
+            true
+        } else {
+            false
         }
     }
 
-    fn edit_current_down(&mut self, times: u32) {
-        let count_value = count_by_mode(times, self.get_mode()).into();
+    /// Returns `false` if value is stopped at zero (instead of going below it)
+    fn edit_current_down(&mut self, times: u32) -> bool {
+        let count_value: DurationEx = count_by_mode(times, self.get_mode()).into();
+        let stopped_at_zero = count_value.gt(self.get_current_value());
 
         self.current_value = self.get_current_value().saturating_sub(count_value);
 
         self.update_format();
         let updated_format = *self.get_format();
         self.downgrade_mode_by_format(&updated_format);
+        !stopped_at_zero
     }
 
     pub fn is_edit_mode(&self) -> bool {
@@ -441,6 +593,7 @@ impl<T> ClockState<T> {
         self.mode = Mode::Initial;
         self.current_value = self.initial_value;
         self.done_count = None;
+        self.session = Session::default();
         self.update_format();
     }
 
@@ -452,6 +605,76 @@ impl<T> ClockState<T> {
         self.is_done() && self.done_count.is_none()
     }
 
+    /// Edits up and flashes a message if max. value has been reached
+    fn edit_up_by(&mut self, times: u32) {
+        if !self.edit_current_up(times) {
+            self.flash("max reached");
+        }
+    }
+
+    /// Edits down and flashes a message if zero has been reached
+    fn edit_down_by(&mut self, times: u32) {
+        if !self.edit_current_down(times) {
+            self.flash("zero reached");
+        }
+    }
+
+    /// Edits up or down by units accelerated by repeated edits (in same direction) until `now`
+    pub fn edit_repeated(&mut self, up: bool, now: Instant) {
+        let times = self.next_edit_times(up, now);
+        if up {
+            self.edit_up_by(times);
+        } else {
+            self.edit_down_by(times);
+        }
+    }
+
+    /// Number of units for next edit (`up` or down) at `now`.
+    /// Repeated edits in same direction are accelerated.
+    fn next_edit_times(&mut self, up: bool, now: Instant) -> u32 {
+        let count = match &self.edit_repeat {
+            Some(latest)
+                if latest.up == up && now.duration_since(latest.at) <= EDIT_REPEAT_INTERVAL =>
+            {
+                latest.count + 1
+            }
+            _ => 0,
+        };
+        self.edit_repeat = Some(EditRepeat { up, count, at: now });
+        edit_acceleration(count)
+    }
+
+    fn flash(&self, message: &str) {
+        if let Some(tx) = &self.app_tx {
+            _ = tx.send(AppEvent::Flash(message.to_owned()));
+        }
+    }
+
+    fn tick_nanos(&self) -> u128 {
+        Duration::from(self.tick_value).as_nanos().max(1)
+    }
+
+    /// Total duration of given number of `ticks`, computed by nanoseconds (`u128`) to not overflow.
+    /// It saturates at `Duration::MAX`, which is far beyond `MAX_DURATION`.
+    fn ticks_to_duration(&self, ticks: u128) -> Duration {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        let nanos = ticks.saturating_mul(Duration::from(self.tick_value).as_nanos());
+        match u64::try_from(nanos / NANOS_PER_SEC) {
+            // remainder is less than `NANOS_PER_SEC`, which fits into `u32`
+            Ok(secs) => Duration::new(secs, (nanos % NANOS_PER_SEC) as u32),
+            Err(_) => Duration::MAX,
+        }
+    }
+
+    pub fn get_done_count(&self) -> Option<u64> {
+        self.done_count
+    }
+
+    /// Whether the clock will be changed by next `Tick` (running or counting `done`)
+    pub fn is_ticking(&self) -> bool {
+        self.is_running() || self.done_count.is_some()
+    }
+
     fn done(&mut self) {
         if !self.is_done() {
             self.mode = Mode::Done;
@@ -480,6 +703,30 @@ impl<T> ClockState<T> {
     }
 }
 
+impl<T> ClockState<T> {
+    /// Same as `to_string`, but with a custom decimal separator (e.g. `4:32,5 >`)
+    pub fn to_string_with_separator(&self, separator: DecimalSeparator) -> String {
+        let value = if self.with_decis {
+            self.current_value
+                .to_string_with_separator(separator.as_char())
+        } else {
+            self.current_value.to_string()
+        };
+        format!("{} {}", value, self.mode)
+    }
+}
+
+/// Displays current value and mode in a single line, e.g. `4:32 >`
+impl<T> fmt::Display for ClockState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.to_string_with_separator(DecimalSeparator::Dot)
+        )
+    }
+}
+
 /// Safe way to count a possible `done` value
 pub fn count_clock_done(value: Option<u64>) -> Option<u64> {
     // Safe substraction for `Some(value > 1)`
@@ -507,6 +754,9 @@ impl ClockState<Countdown> {
             current_value: current_value.into(),
             prev_value: current_value.into(),
             tick_value: tick_value.into(),
+            max_value: MAX_DURATION.into(),
+            jump_step: DEFAULT_JUMP_STEP,
+            edit_repeat: None,
             mode: if current_value == Duration::ZERO {
                 Mode::Done
             } else if current_value == initial_value {
@@ -518,6 +768,9 @@ impl ClockState<Countdown> {
             with_decis,
             app_tx,
             done_count: None,
+            session: Session::default(),
+            app_time: None,
+            wrap: false,
             phantom: PhantomData,
         };
         // update format once
@@ -533,6 +786,19 @@ impl ClockState<Countdown> {
         }
     }
 
+    /// Applies many ticks at once, same as calling `tick` `duration / tick_value` times.
+    /// Note: A remainder of `duration` (less than `tick_value`) is ignored.
+    pub fn advance(&mut self, duration: Duration) {
+        if self.mode == Mode::Tick {
+            let ticks = duration.as_nanos() / self.tick_nanos();
+            self.current_value = self
+                .current_value
+                .saturating_sub(self.ticks_to_duration(ticks).into());
+            self.check_done();
+            self.update_format();
+        }
+    }
+
     fn check_done(&mut self) {
         if self.current_value.eq(&Duration::ZERO.into()) {
             self.done();
@@ -549,6 +815,17 @@ impl ClockState<Countdown> {
         (elapsed.millis() * 100 / self.initial_value.millis()) as u16
     }
 
+    /// Status line of time left by given `app_time`, see `status_line`
+    pub fn get_status_line(&self, app_time: AppTime, format: &AppTimeFormat) -> String {
+        let left = *self.get_current_value();
+        status_line(
+            left,
+            app_time.saturating_add(left.into()),
+            self.get_percentage_done(),
+            format,
+        )
+    }
+
     pub fn edit_next(&mut self) {
         self.edit_mode_next();
     }
@@ -558,19 +835,19 @@ impl ClockState<Countdown> {
     }
 
     pub fn edit_up(&mut self) {
-        self.edit_current_up(1);
+        self.edit_repeated(true, Instant::now());
     }
 
     pub fn edit_down(&mut self) {
-        self.edit_current_down(1);
+        self.edit_repeated(false, Instant::now());
     }
 
     pub fn edit_jump_up(&mut self) {
-        self.edit_current_up(10);
+        self.edit_up_by(self.jump_step);
     }
 
     pub fn edit_jump_down(&mut self) {
-        self.edit_current_down(10);
+        self.edit_down_by(self.jump_step);
     }
 }
 
@@ -578,6 +855,25 @@ impl ClockState<Countdown> {
 pub struct Timer {}
 
 impl ClockState<Timer> {
+    /// Time since first run (if any) until `app_time`, whether running or paused
+    pub fn get_wall_time(&self) -> Option<Duration> {
+        let now = self.app_time?;
+        self.session
+            .started_at
+            .map(|started_at| time_between(started_at, now))
+    }
+
+    /// Time running since first run (if any), which is wall time without any pauses
+    pub fn get_running_time(&self) -> Option<Duration> {
+        let now = self.app_time?;
+        let paused = self.session.paused
+            + self
+                .session
+                .paused_at
+                .map_or(Duration::ZERO, |paused_at| time_between(paused_at, now));
+        self.get_wall_time().map(|wall| wall.saturating_sub(paused))
+    }
+
     pub fn new(args: ClockStateArgs) -> Self {
         let ClockStateArgs {
             initial_value,
@@ -594,6 +890,9 @@ impl ClockState<Timer> {
             current_value: current_value.into(),
             prev_value: current_value.into(),
             tick_value: tick_value.into(),
+            max_value: MAX_DURATION.into(),
+            jump_step: DEFAULT_JUMP_STEP,
+            edit_repeat: None,
             mode: if current_value == initial_value {
                 Mode::Initial
             } else if current_value >= MAX_DURATION {
@@ -605,6 +904,9 @@ impl ClockState<Timer> {
             with_decis,
             app_tx,
             done_count: None,
+            session: Session::default(),
+            app_time: None,
+            wrap: false,
             phantom: PhantomData,
         };
         // update format once
@@ -620,9 +922,37 @@ impl ClockState<Timer> {
         }
     }
 
+    /// Applies many ticks at once, same as calling `tick` `duration / tick_value` times.
+    /// Note: A remainder of `duration` (less than `tick_value`) is ignored.
+    #[cfg(test)]
+    pub fn advance(&mut self, duration: Duration) {
+        if self.mode == Mode::Tick {
+            let tick_nanos = self.tick_nanos();
+            // ticking stops by reaching (or exceeding) `max_value`
+            let left = Duration::from(self.max_value).saturating_sub(self.current_value.into());
+            let ticks =
+                (duration.as_nanos() / tick_nanos).min(left.as_nanos().div_ceil(tick_nanos));
+            self.current_value = self
+                .current_value
+                .saturating_add(self.ticks_to_duration(ticks).into());
+            self.check_done();
+            self.update_format();
+        }
+    }
+
+    /// Wraps to zero by reaching `max_value` (e.g. for demos) instead of stopping
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     fn check_done(&mut self) {
-        if self.current_value.ge(&MAX_DURATION.into()) {
-            self.done();
+        if self.current_value.ge(&self.max_value) {
+            if self.wrap {
+                self.current_value = Duration::ZERO.into();
+            } else {
+                self.done();
+            }
         }
     }
 
@@ -635,19 +965,19 @@ impl ClockState<Timer> {
     }
 
     pub fn edit_up(&mut self) {
-        self.edit_current_up(1);
+        self.edit_repeated(true, Instant::now());
     }
 
     pub fn edit_down(&mut self) {
-        self.edit_current_down(1);
+        self.edit_repeated(false, Instant::now());
     }
 
     pub fn edit_jump_up(&mut self) {
-        self.edit_current_up(10);
+        self.edit_up_by(self.jump_step);
     }
 
     pub fn edit_jump_down(&mut self) {
-        self.edit_current_down(10);
+        self.edit_down_by(self.jump_step);
     }
 }
 
@@ -657,6 +987,23 @@ where
 {
     style: DigitStyle,
     blink: bool,
+    decimal_separator: DecimalSeparator,
+    flip: Option<Flip>,
+    /// Render passed time (`initial_value - current_value`) instead of `current_value`
+    count_up: bool,
+    /// Color of digits
+    color: Option<Color>,
+    /// Renders digits as empty space, e.g. to blink
+    hidden: bool,
+    /// Renders labels of years (`Y`) and days (`D`)
+    unit_labels: bool,
+    spacing: Spacing,
+    /// Hides seconds (and deciseconds) of values (with hours at least) exceeding it
+    hide_seconds_after: Option<Duration>,
+    /// Renders total seconds only (see `format_by_seconds`)
+    seconds_only: bool,
+    /// Dims digits (`--dim`)
+    dim: bool,
     phantom: PhantomData<T>,
 }
 
@@ -664,16 +1011,148 @@ impl<T> ClockWidget<T>
 where
     T: std::fmt::Debug,
 {
-    pub fn new(style: DigitStyle, blink: bool) -> Self {
+    pub fn new(
+        style: DigitStyle,
+        blink: bool,
+        decimal_separator: DecimalSeparator,
+        flip: Option<Flip>,
+    ) -> Self {
         Self {
             style,
             blink,
+            decimal_separator,
+            flip,
+            count_up: false,
+            color: None,
+            hidden: false,
+            unit_labels: true,
+            spacing: Spacing::default(),
+            hide_seconds_after: None,
+            seconds_only: false,
+            dim: false,
             phantom: PhantomData,
         }
     }
 
-    pub fn get_width(&self, format: &Format, with_decis: bool) -> u16 {
-        clock_horizontal_lengths(format, with_decis).iter().sum()
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    pub fn color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn count_up(mut self, count_up: bool) -> Self {
+        self.count_up = count_up;
+        self
+    }
+
+    pub fn unit_labels(mut self, unit_labels: bool) -> Self {
+        self.unit_labels = unit_labels;
+        self
+    }
+
+    pub fn spacing(mut self, spacing: Spacing) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn hide_seconds_after(mut self, hide_seconds_after: Option<Duration>) -> Self {
+        self.hide_seconds_after = hide_seconds_after;
+        self
+    }
+
+    pub fn seconds_only(mut self, seconds_only: bool) -> Self {
+        self.seconds_only = seconds_only;
+        self
+    }
+
+    pub fn dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
+    /// Value and its `Format` to render.
+    /// While editing it's always `current_value` (and its `Format`).
+    /// With `seconds_only` values up to `999` seconds are rendered as seconds,
+    /// greater values fall back to their regular `Format`.
+    pub fn get_value_to_render(&self, state: &ClockState<T>) -> (DurationEx, Format) {
+        let (value, format) = match state.get_mode() {
+            Mode::Editable(_, _) => return (state.current_value, state.format),
+            _ if self.count_up => {
+                let passed = state.initial_value.saturating_sub(state.current_value);
+                (passed, format_by_duration(&passed))
+            }
+            _ => (state.current_value, state.format),
+        };
+        let format = format_by_seconds(&value)
+            .filter(|_| self.seconds_only)
+            .unwrap_or(format);
+        (value, format)
+    }
+
+    /// Whether to render value w/o seconds (and deciseconds).
+    /// Note: Seconds are never hidden while editing.
+    pub fn hides_seconds(&self, state: &ClockState<T>) -> bool {
+        let (value, format) = self.get_value_to_render(state);
+        !state.is_edit_mode()
+            && format >= Format::HMmSs
+            && self
+                .hide_seconds_after
+                .is_some_and(|after| Duration::from(value) > after)
+    }
+
+    /// Value to render as plain text (w/o mode), e.g. `4:32` or `4:32.5` with deciseconds.
+    pub fn get_text(&self, state: &ClockState<T>) -> String {
+        let (value, format) = self.get_value_to_render(state);
+        if self.hides_seconds(state) {
+            // drop `:ss`
+            let text = value.to_string();
+            return match text.rsplit_once(':') {
+                Some((rest, _)) => rest.to_owned(),
+                None => text,
+            };
+        }
+        let text = if format == Format::Sss {
+            value.seconds().to_string()
+        } else {
+            value.to_string()
+        };
+        if state.with_decis {
+            format!(
+                "{text}{}{}",
+                self.decimal_separator.as_char(),
+                value.decis()
+            )
+        } else {
+            text
+        }
+    }
+
+    /// Horizontal lengths to render given state.
+    /// Lengths of hidden seconds are kept, but zeroed to skip rendering them.
+    fn get_widths(&self, state: &ClockState<T>) -> Vec<u16> {
+        let (_, format) = self.get_value_to_render(state);
+        let hides_seconds = self.hides_seconds(state);
+        let mut widths = clock_horizontal_lengths(
+            &format,
+            state.with_decis && !hides_seconds,
+            self.unit_labels,
+            self.spacing,
+        );
+        if hides_seconds {
+            // last `:` + `s_s`
+            let len = widths.len();
+            widths[len - 2..].fill(0);
+        }
+        widths
+    }
+
+    /// Width to render given state, which depends on its current value
+    pub fn get_width(&self, state: &ClockState<T>) -> u16 {
+        self.get_widths(state).iter().sum()
     }
 
     pub fn get_height(&self) -> u16 {
@@ -681,6 +1160,29 @@ where
     }
 }
 
+/// Color shifting from green (0%) to yellow (50%) to red (100%)
+/// to get a sense of time left at a glance.
+pub fn urgency_color(percentage_done: u16) -> Color {
+    let p = u32::from(percentage_done.min(100));
+    let (r, g) = if p <= 50 {
+        (255 * p / 50, 255)
+    } else {
+        (255, 255 * (100 - p) / 50)
+    };
+    Color::Rgb(r as u8, g as u8, 0)
+}
+
+/// Summary of time left, time to finish and percentage done,
+/// e.g. `04:32 · ends 17:42 · 55%`
+pub fn status_line(
+    left: DurationEx,
+    ends: AppTime,
+    percentage_done: u16,
+    format: &AppTimeFormat,
+) -> String {
+    format!("{left} · ends {} · {percentage_done}%", ends.format(format))
+}
+
 /// Helper to check whether to blink the clock while rendering.
 /// Its logic is based on a given `count` value.
 pub fn should_blink(count_value: Option<u64>) -> bool {
@@ -693,8 +1195,13 @@ pub fn should_blink(count_value: Option<u64>) -> bool {
 }
 
 // Helper to get horizontal lengths of a clock
-// depending on given `Format` and `with_decis` params
-pub fn clock_horizontal_lengths(format: &Format, with_decis: bool) -> Vec<u16> {
+// depending on given `Format`, `with_decis`, `with_labels` and `spacing` params
+pub fn clock_horizontal_lengths(
+    format: &Format,
+    with_decis: bool,
+    with_labels: bool,
+    spacing: Spacing,
+) -> Vec<u16> {
     let add_decis = |mut lengths: Vec<u16>, with_decis: bool| -> Vec<u16> {
         if with_decis {
             lengths.extend_from_slice(&[
@@ -705,253 +1212,269 @@ pub fn clock_horizontal_lengths(format: &Format, with_decis: bool) -> Vec<u16> {
         lengths
     };
 
-    const LABEL_WIDTH: u16 = DIGIT_LABEL_WIDTH + DIGIT_SPACE_WIDTH;
+    let four_digits_width = spacing.digits_width(4);
+    let three_digits_width = spacing.digits_width(3);
+    let two_digits_width = spacing.digits_width(2);
+    let colon_width = spacing.colon_width();
+
+    // w/o labels keep a wider space only to separate years, days and hours
+    let label_width = if with_labels {
+        DIGIT_LABEL_WIDTH + spacing.digit_space_width()
+    } else {
+        DIGIT_SPACE_WIDTH * 2
+    };
 
     match format {
         Format::YyyyDddHhMmSs => add_decis(
             vec![
-                FOUR_DIGITS_WIDTH,  // y_y_y_y
-                LABEL_WIDTH,        // _l__
-                THREE_DIGITS_WIDTH, // d_d_d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                four_digits_width,  // y_y_y_y
+                label_width,        // _l__
+                three_digits_width, // d_d_d
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::YyyyDdHhMmSs => add_decis(
             vec![
-                FOUR_DIGITS_WIDTH, // y_y_y_y
-                LABEL_WIDTH,       // _l__
-                TWO_DIGITS_WIDTH,  // d_d
-                LABEL_WIDTH,       // _l__
-                TWO_DIGITS_WIDTH,  // h_h
-                COLON_WIDTH,       // :
-                TWO_DIGITS_WIDTH,  // m_m
-                COLON_WIDTH,       // :
-                TWO_DIGITS_WIDTH,  // s_s
+                four_digits_width, // y_y_y_y
+                label_width,       // _l__
+                two_digits_width,  // d_d
+                label_width,       // _l__
+                two_digits_width,  // h_h
+                colon_width,       // :
+                two_digits_width,  // m_m
+                colon_width,       // :
+                two_digits_width,  // s_s
             ],
             with_decis,
         ),
         Format::YyyyDHhMmSs => add_decis(
             vec![
-                FOUR_DIGITS_WIDTH, // y_y_y_y
-                LABEL_WIDTH,       // _l__
+                four_digits_width, // y_y_y_y
+                label_width,       // _l__
                 DIGIT_WIDTH,       // d
-                LABEL_WIDTH,       // _l__
-                TWO_DIGITS_WIDTH,  // h_h
-                COLON_WIDTH,       // :
-                TWO_DIGITS_WIDTH,  // m_m
-                COLON_WIDTH,       // :
-                TWO_DIGITS_WIDTH,  // s_s
+                label_width,       // _l__
+                two_digits_width,  // h_h
+                colon_width,       // :
+                two_digits_width,  // m_m
+                colon_width,       // :
+                two_digits_width,  // s_s
             ],
             with_decis,
         ),
         Format::YyyDddHhMmSs => add_decis(
             vec![
-                THREE_DIGITS_WIDTH, // y_y_y
-                LABEL_WIDTH,        // _l__
-                THREE_DIGITS_WIDTH, // d_d_d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                three_digits_width, // y_y_y
+                label_width,        // _l__
+                three_digits_width, // d_d_d
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::YyyDdHhMmSs => add_decis(
             vec![
-                THREE_DIGITS_WIDTH, // y_y_y
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // d_d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                three_digits_width, // y_y_y
+                label_width,        // _l__
+                two_digits_width,   // d_d
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::YyyDHhMmSs => add_decis(
             vec![
-                THREE_DIGITS_WIDTH, // y_y_y
-                LABEL_WIDTH,        // _l__
+                three_digits_width, // y_y_y
+                label_width,        // _l__
                 DIGIT_WIDTH,        // d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::YyDddHhMmSs => add_decis(
             vec![
-                TWO_DIGITS_WIDTH,   // y_y
-                LABEL_WIDTH,        // _l__
-                THREE_DIGITS_WIDTH, // d_d_d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                two_digits_width,   // y_y
+                label_width,        // _l__
+                three_digits_width, // d_d_d
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::YyDdHhMmSs => add_decis(
             vec![
-                TWO_DIGITS_WIDTH, // y_y
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // d_d
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                two_digits_width, // y_y
+                label_width,      // _l__
+                two_digits_width, // d_d
+                label_width,      // _l__
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::YyDHhMmSs => add_decis(
             vec![
-                TWO_DIGITS_WIDTH, // y_y
-                LABEL_WIDTH,      // _l__
+                two_digits_width, // y_y
+                label_width,      // _l__
                 DIGIT_WIDTH,      // d
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                label_width,      // _l__
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::YDddHhMmSs => add_decis(
             vec![
                 DIGIT_WIDTH,        // Y
-                LABEL_WIDTH,        // _l__
-                THREE_DIGITS_WIDTH, // d_d_d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                label_width,        // _l__
+                three_digits_width, // d_d_d
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::YDdHhMmSs => add_decis(
             vec![
                 DIGIT_WIDTH,      // Y
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // d_d
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                label_width,      // _l__
+                two_digits_width, // d_d
+                label_width,      // _l__
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::YDHhMmSs => add_decis(
             vec![
                 DIGIT_WIDTH,      // Y
-                LABEL_WIDTH,      // _l__
+                label_width,      // _l__
                 DIGIT_WIDTH,      // d
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                label_width,      // _l__
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
 
         Format::DddHhMmSs => add_decis(
             vec![
-                THREE_DIGITS_WIDTH, // d_d_d
-                LABEL_WIDTH,        // _l__
-                TWO_DIGITS_WIDTH,   // h_h
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // m_m
-                COLON_WIDTH,        // :
-                TWO_DIGITS_WIDTH,   // s_s
+                three_digits_width, // d_d_d
+                label_width,        // _l__
+                two_digits_width,   // h_h
+                colon_width,        // :
+                two_digits_width,   // m_m
+                colon_width,        // :
+                two_digits_width,   // s_s
             ],
             with_decis,
         ),
         Format::DdHhMmSs => add_decis(
             vec![
-                TWO_DIGITS_WIDTH, // d_d
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                two_digits_width, // d_d
+                label_width,      // _l__
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::DHhMmSs => add_decis(
             vec![
                 DIGIT_WIDTH,      // D
-                LABEL_WIDTH,      // _l__
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                label_width,      // _l__
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::HhMmSs => add_decis(
             vec![
-                TWO_DIGITS_WIDTH, // h_h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                two_digits_width, // h_h
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::HMmSs => add_decis(
             vec![
                 DIGIT_WIDTH,      // h
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                colon_width,      // :
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::MmSs => add_decis(
             vec![
-                TWO_DIGITS_WIDTH, // m_m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                two_digits_width, // m_m
+                colon_width,      // :
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
         Format::MSs => add_decis(
             vec![
                 DIGIT_WIDTH,      // m
-                COLON_WIDTH,      // :
-                TWO_DIGITS_WIDTH, // s_s
+                colon_width,      // :
+                two_digits_width, // s_s
+            ],
+            with_decis,
+        ),
+        Format::Sss => add_decis(
+            vec![
+                three_digits_width, // s_s_s
             ],
             with_decis,
         ),
         Format::Ss => add_decis(
             vec![
-                TWO_DIGITS_WIDTH, // s_s
+                two_digits_width, // s_s
             ],
             with_decis,
         ),
@@ -969,9 +1492,14 @@ pub struct RenderClockState<'a, D: ClockDuration> {
     pub format: Format,
     pub editable_time: Option<Time>,
     pub with_decis: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
     pub symbol: &'a str,
     pub widths: Vec<u16>,
     pub duration: D,
+    /// Renders labels of years (`Y`) and days (`D`)
+    pub with_labels: bool,
+    pub spacing: Spacing,
 }
 
 // Helper to render a clock
@@ -979,10 +1507,14 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
     let RenderClockState {
         format,
         with_decis,
+        decimal_separator,
+        flip,
         symbol,
         widths,
         editable_time,
         duration,
+        with_labels,
+        spacing,
     } = state;
 
     let width = widths.iter().sum();
@@ -997,46 +1529,51 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
 
     let render_four_digits = |d1, d2, d3, d4, editable, area, buf: &mut Buffer| {
         let [a1, a2, a3, a4] = Layout::horizontal(Constraint::from_lengths([
-            DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
-            DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
-            DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
+            DIGIT_WIDTH + spacing.digit_space_width(),
+            DIGIT_WIDTH + spacing.digit_space_width(),
+            DIGIT_WIDTH + spacing.digit_space_width(),
             DIGIT_WIDTH,
         ]))
         .areas(area);
-        Digit::new(d1, editable, symbol).render(a1, buf);
-        Digit::new(d2, editable, symbol).render(a2, buf);
-        Digit::new(d3, editable, symbol).render(a3, buf);
-        Digit::new(d4, editable, symbol).render(a4, buf);
+        Digit::new(d1, editable, symbol).flip(flip).render(a1, buf);
+        Digit::new(d2, editable, symbol).flip(flip).render(a2, buf);
+        Digit::new(d3, editable, symbol).flip(flip).render(a3, buf);
+        Digit::new(d4, editable, symbol).flip(flip).render(a4, buf);
     };
 
     let render_three_digits = |d1, d2, d3, editable, area, buf: &mut Buffer| {
         let [a1, a2, a3] = Layout::horizontal(Constraint::from_lengths([
-            DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
-            DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
+            DIGIT_WIDTH + spacing.digit_space_width(),
+            DIGIT_WIDTH + spacing.digit_space_width(),
             DIGIT_WIDTH,
         ]))
         .areas(area);
-        Digit::new(d1, editable, symbol).render(a1, buf);
-        Digit::new(d2, editable, symbol).render(a2, buf);
-        Digit::new(d3, editable, symbol).render(a3, buf);
+        Digit::new(d1, editable, symbol).flip(flip).render(a1, buf);
+        Digit::new(d2, editable, symbol).flip(flip).render(a2, buf);
+        Digit::new(d3, editable, symbol).flip(flip).render(a3, buf);
     };
 
     let render_two_digits = |d1, d2, editable, area, buf: &mut Buffer| {
         let [a1, a2] = Layout::horizontal(Constraint::from_lengths([
-            DIGIT_WIDTH + DIGIT_SPACE_WIDTH,
+            DIGIT_WIDTH + spacing.digit_space_width(),
             DIGIT_WIDTH,
         ]))
         .areas(area);
-        Digit::new(d1, editable, symbol).render(a1, buf);
-        Digit::new(d2, editable, symbol).render(a2, buf);
+        Digit::new(d1, editable, symbol).flip(flip).render(a1, buf);
+        Digit::new(d2, editable, symbol).flip(flip).render(a2, buf);
     };
 
     let render_colon = |area, buf: &mut Buffer| {
-        Colon::new(symbol).render(area, buf);
+        Colon::new(symbol)
+            .flip(flip)
+            .width(spacing.colon_width())
+            .render(area, buf);
     };
 
     let render_dot = |area, buf: &mut Buffer| {
-        Dot::new(symbol).render(area, buf);
+        Dot::new(symbol, decimal_separator == DecimalSeparator::Comma)
+            .flip(flip)
+            .render(area, buf);
     };
 
     let render_yyyy = |area, buf| {
@@ -1073,7 +1610,9 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
     };
 
     let render_y = |area, buf| {
-        Digit::new(duration.years() % 10, edit_years, symbol).render(area, buf);
+        Digit::new(duration.years() % 10, edit_years, symbol)
+            .flip(flip)
+            .render(area, buf);
     };
 
     let render_ddd = |area, buf| {
@@ -1098,7 +1637,9 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
     };
 
     let render_d = |area, buf| {
-        Digit::new(duration.days_mod() % 10, edit_days, symbol).render(area, buf);
+        Digit::new(duration.days_mod() % 10, edit_days, symbol)
+            .flip(flip)
+            .render(area, buf);
     };
 
     let render_hh = |area, buf| {
@@ -1112,7 +1653,9 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
     };
 
     let render_h = |area, buf| {
-        Digit::new(duration.hours_mod() % 10, edit_hours, symbol).render(area, buf);
+        Digit::new(duration.hours_mod() % 10, edit_hours, symbol)
+            .flip(flip)
+            .render(area, buf);
     };
 
     let render_mm = |area, buf| {
@@ -1126,7 +1669,9 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
     };
 
     let render_m = |area, buf| {
-        Digit::new(duration.minutes_mod() % 10, edit_minutes, symbol).render(area, buf);
+        Digit::new(duration.minutes_mod() % 10, edit_minutes, symbol)
+            .flip(flip)
+            .render(area, buf);
     };
 
     let render_ss = |area, buf| {
@@ -1140,14 +1685,44 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
     };
 
     let render_s = |area, buf| {
-        Digit::new(duration.seconds_mod() % 10, edit_secs, symbol).render(area, buf);
+        Digit::new(duration.seconds_mod() % 10, edit_secs, symbol)
+            .flip(flip)
+            .render(area, buf);
+    };
+
+    // Total seconds of formats w/o minutes, which are same as `seconds_mod` below a minute,
+    // but might exceed it by rendering seconds only (see `format_by_seconds`)
+    let render_total_sss = |area, buf| {
+        render_three_digits(
+            (duration.seconds() / 100) % 10,
+            (duration.seconds() / 10) % 10,
+            duration.seconds() % 10,
+            edit_secs,
+            area,
+            buf,
+        );
+    };
+
+    let render_total_ss = |area, buf| {
+        render_two_digits(
+            (duration.seconds() / 10) % 10,
+            duration.seconds() % 10,
+            edit_secs,
+            area,
+            buf,
+        );
     };
 
     let render_ds = |area, buf| {
-        Digit::new(duration.decis(), edit_decis, symbol).render(area, buf);
+        Digit::new(duration.decis(), edit_decis, symbol)
+            .flip(flip)
+            .render(area, buf);
     };
 
     let render_label = |l: &str, area, buf: &mut Buffer| {
+        if !with_labels {
+            return;
+        }
         Span::styled(
             format!(" {l}").to_uppercase(),
             Style::default().add_modifier(Modifier::BOLD),
@@ -1642,15 +2217,25 @@ pub fn render_clock<D: ClockDuration>(area: Rect, buf: &mut Buffer, state: Rende
             render_colon(c_ms, buf);
             render_ss(s_s, buf);
         }
+        Format::Sss if with_decis => {
+            let [s_s_s, dot, ds] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
+            render_total_sss(s_s_s, buf);
+            render_dot(dot, buf);
+            render_ds(ds, buf);
+        }
+        Format::Sss => {
+            let [s_s_s] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
+            render_total_sss(s_s_s, buf);
+        }
         Format::Ss if state.with_decis => {
             let [s_s, dot, ds] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-            render_ss(s_s, buf);
+            render_total_ss(s_s, buf);
             render_dot(dot, buf);
             render_ds(ds, buf);
         }
         Format::Ss => {
             let [s_s] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-            render_ss(s_s, buf);
+            render_total_ss(s_s, buf);
         }
         Format::S if with_decis => {
             let [s, dot, ds] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
@@ -1672,29 +2257,57 @@ where
     type State = ClockState<T>;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let with_decis = state.with_decis;
-        let format = state.format;
-        let widths = clock_horizontal_lengths(&format, with_decis);
-
-        // To simulate a blink effect, just use an "empty" symbol (string)
-        // It's "empty" all digits and creates an "empty" render area
-        let symbol = if self.blink && should_blink(state.done_count) {
-            " "
+        let with_decis = state.with_decis && !self.hides_seconds(state);
+        let (duration, format) = self.get_value_to_render(state);
+        let widths = self.get_widths(state);
+        let hidden = self.hidden || (self.blink && should_blink(state.done_count));
+
+        // Area is too small for digits: fallback to render value as plain text
+        if area.width < widths.iter().sum() || area.height < self.get_height() {
+            if !hidden {
+                let value = if with_decis {
+                    duration.to_string_with_separator(self.decimal_separator.as_char())
+                } else {
+                    duration.to_string()
+                };
+                Line::raw(value)
+                    .centered()
+                    .render(area.centered_vertically(Constraint::Length(1)), buf);
+            }
         } else {
-            self.style.get_digit_symbol()
-        };
+            // To simulate a blink effect, just use an "empty" symbol (string)
+            // It's "empty" all digits and creates an "empty" render area
+            let symbol = if hidden {
+                " "
+            } else {
+                self.style.get_digit_symbol()
+            };
 
-        let render_state = RenderClockState {
-            with_decis,
-            duration: state.current_value,
-            editable_time: match state.get_mode() {
-                Mode::Editable(time, _) => Some(*time),
-                _ => None,
-            },
-            format,
-            symbol,
-            widths,
-        };
-        render_clock(area, buf, render_state);
+            let render_state = RenderClockState {
+                with_decis,
+                decimal_separator: self.decimal_separator,
+                flip: self.flip,
+                duration,
+                editable_time: match state.get_mode() {
+                    Mode::Editable(time, _) => Some(*time),
+                    _ => None,
+                },
+                format,
+                symbol,
+                widths,
+                with_labels: self.unit_labels,
+                spacing: self.spacing,
+            };
+            render_clock(area, buf, render_state);
+        }
+
+        if let Some(color) = self.color {
+            buf.set_style(area, Style::new().fg(color));
+        }
+
+        // dim digits of a paused clock to see its state at a glance (or all by `--dim`)
+        if self.dim || state.mode == Mode::Pause {
+            buf.set_style(area, Style::new().add_modifier(Modifier::DIM));
+        }
     }
 }
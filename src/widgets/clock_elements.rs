@@ -1,3 +1,4 @@
+use crate::common::{Flip, Spacing};
 use ratatui::{
     buffer::Buffer,
     layout::{Position, Rect},
@@ -7,14 +8,37 @@ use ratatui::{
 pub const DIGIT_SIZE: usize = 5;
 pub const DIGIT_WIDTH: u16 = DIGIT_SIZE as u16;
 pub const DIGIT_HEIGHT: u16 = DIGIT_SIZE as u16 + 1 /* border height */;
-pub const TWO_DIGITS_WIDTH: u16 = DIGIT_WIDTH + DIGIT_SPACE_WIDTH + DIGIT_WIDTH; // digit-space-digit
-pub const THREE_DIGITS_WIDTH: u16 = TWO_DIGITS_WIDTH + DIGIT_SPACE_WIDTH + DIGIT_WIDTH; // digit-space-digit-space-digit
-pub const FOUR_DIGITS_WIDTH: u16 = THREE_DIGITS_WIDTH + DIGIT_SPACE_WIDTH + DIGIT_WIDTH; // digit-space-digit-space-digit-space-digit
-pub const COLON_WIDTH: u16 = 4; // incl. padding left + padding right
+pub const COLON_WIDTH: u16 = COLON_DOTS_WIDTH + 2 * DIGIT_SPACE_WIDTH; // incl. padding left + padding right
+pub const COLON_DOTS_WIDTH: u16 = 2;
 pub const DOT_WIDTH: u16 = 4; // incl. padding left + padding right
 pub const DIGIT_SPACE_WIDTH: u16 = 1; // space between digits
 pub const DIGIT_LABEL_WIDTH: u16 = 3; // label (single char) incl. padding left + padding right
 
+impl Spacing {
+    /// Space between digits
+    pub fn digit_space_width(&self) -> u16 {
+        match self {
+            // Note: A space of at least one char is needed to keep digits readable
+            Spacing::Tight | Spacing::Normal => DIGIT_SPACE_WIDTH,
+            Spacing::Wide => DIGIT_SPACE_WIDTH * 2,
+        }
+    }
+
+    /// Width of a colon incl. padding left + padding right
+    pub fn colon_width(&self) -> u16 {
+        match self {
+            // thin dots (1 char) incl. padding
+            Spacing::Tight => 3,
+            Spacing::Normal | Spacing::Wide => COLON_DOTS_WIDTH + 2 * self.digit_space_width(),
+        }
+    }
+
+    /// Width of given number of digits incl. space between them
+    pub fn digits_width(&self, count: u16) -> u16 {
+        count * DIGIT_WIDTH + count.saturating_sub(1) * self.digit_space_width()
+    }
+}
+
 #[rustfmt::skip]
 const DIGIT_0: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
     1, 1, 1, 1, 1,
@@ -105,6 +129,42 @@ const DIGIT_9: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
     1, 1, 1, 1, 1,
 ];
 
+#[rustfmt::skip]
+const CHAR_A: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_B: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 0,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 0,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 0,
+];
+
+#[rustfmt::skip]
+const CHAR_C: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_D: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 0,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 0,
+];
+
 #[rustfmt::skip]
 const CHAR_E: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
     1, 1, 1, 1, 1,
@@ -114,10 +174,275 @@ const CHAR_E: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
     1, 1, 1, 1, 1,
 ];
 
+#[rustfmt::skip]
+const CHAR_F: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 0, 0,
+    1, 1, 1, 1, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+];
+
+#[rustfmt::skip]
+const CHAR_G: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_H: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_I: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_J: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    0, 0, 0, 1, 1,
+    0, 0, 0, 1, 1,
+    0, 0, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_K: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 0,
+    1, 1, 1, 0, 0,
+    1, 1, 1, 1, 0,
+    1, 1, 0, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_L: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_M: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 0, 1, 0, 1,
+    1, 0, 1, 0, 1,
+    1, 0, 1, 0, 1,
+    1, 0, 1, 0, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_N: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+];
+
+const CHAR_O: [u8; DIGIT_SIZE * DIGIT_SIZE] = DIGIT_0;
+
+#[rustfmt::skip]
+const CHAR_P: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+    1, 1, 0, 0, 0,
+    1, 1, 0, 0, 0,
+];
+
+#[rustfmt::skip]
+const CHAR_Q: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+    0, 0, 0, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_R: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 0,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+];
+
+const CHAR_S: [u8; DIGIT_SIZE * DIGIT_SIZE] = DIGIT_5;
+
+#[rustfmt::skip]
+const CHAR_T: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+];
+
+#[rustfmt::skip]
+const CHAR_U: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_V: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    0, 1, 1, 1, 0,
+    0, 0, 1, 0, 0,
+];
+
+#[rustfmt::skip]
+const CHAR_W: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 0, 1, 0, 1,
+    1, 0, 1, 0, 1,
+    1, 0, 1, 0, 1,
+    1, 0, 1, 0, 1,
+    1, 1, 1, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_X: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 1, 1,
+    0, 1, 1, 1, 0,
+    0, 0, 1, 0, 0,
+    0, 1, 1, 1, 0,
+    1, 1, 0, 1, 1,
+];
+
+#[rustfmt::skip]
+const CHAR_Y: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 0, 1, 1,
+    1, 1, 0, 1, 1,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+    0, 1, 1, 1, 0,
+];
+
+#[rustfmt::skip]
+const CHAR_Z: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
+    1, 1, 1, 1, 1,
+    0, 0, 0, 1, 1,
+    0, 1, 1, 1, 0,
+    1, 1, 0, 0, 0,
+    1, 1, 1, 1, 1,
+];
+
+/// Patterns of digits and (uppercase) letters
+fn char_patterns(c: char) -> Option<[u8; DIGIT_SIZE * DIGIT_SIZE]> {
+    match c.to_ascii_uppercase() {
+        '0' => Some(DIGIT_0),
+        '1' => Some(DIGIT_1),
+        '2' => Some(DIGIT_2),
+        '3' => Some(DIGIT_3),
+        '4' => Some(DIGIT_4),
+        '5' => Some(DIGIT_5),
+        '6' => Some(DIGIT_6),
+        '7' => Some(DIGIT_7),
+        '8' => Some(DIGIT_8),
+        '9' => Some(DIGIT_9),
+        'A' => Some(CHAR_A),
+        'B' => Some(CHAR_B),
+        'C' => Some(CHAR_C),
+        'D' => Some(CHAR_D),
+        'E' => Some(CHAR_E),
+        'F' => Some(CHAR_F),
+        'G' => Some(CHAR_G),
+        'H' => Some(CHAR_H),
+        'I' => Some(CHAR_I),
+        'J' => Some(CHAR_J),
+        'K' => Some(CHAR_K),
+        'L' => Some(CHAR_L),
+        'M' => Some(CHAR_M),
+        'N' => Some(CHAR_N),
+        'O' => Some(CHAR_O),
+        'P' => Some(CHAR_P),
+        'Q' => Some(CHAR_Q),
+        'R' => Some(CHAR_R),
+        'S' => Some(CHAR_S),
+        'T' => Some(CHAR_T),
+        'U' => Some(CHAR_U),
+        'V' => Some(CHAR_V),
+        'W' => Some(CHAR_W),
+        'X' => Some(CHAR_X),
+        'Y' => Some(CHAR_Y),
+        'Z' => Some(CHAR_Z),
+        _ => None,
+    }
+}
+
+/// Whether given char can be rendered by `Char` (or `Word`)
+pub fn is_supported_char(c: char) -> bool {
+    char_patterns(c).is_some()
+}
+
+/// Mirrors a position within `area` (if needed)
+fn flip_position(p: Position, area: Rect, flip: Option<Flip>) -> Position {
+    match flip {
+        Some(Flip::Horizontal) => Position {
+            x: (area.right() - 1 - p.x) + area.left(),
+            ..p
+        },
+        Some(Flip::Vertical) => Position {
+            y: (area.bottom() - 1 - p.y) + area.top(),
+            ..p
+        },
+        None => p,
+    }
+}
+
+/// Sets `symbol` to all `positions` - mirrored within `area` (if needed)
+fn render_positions(
+    positions: impl IntoIterator<Item = Position>,
+    symbol: &str,
+    area: Rect,
+    flip: Option<Flip>,
+    buf: &mut Buffer,
+) {
+    for p in positions {
+        if !area.contains(p) {
+            continue;
+        }
+        if let Some(cell) = buf.cell_mut(flip_position(p, area, flip)) {
+            cell.set_symbol(symbol);
+        }
+    }
+}
+
+/// Area of a single element, which might be smaller than the area it's rendered in
+fn element_area(area: Rect, width: u16) -> Rect {
+    Rect {
+        width: width.min(area.width),
+        ..area
+    }
+}
+
 pub struct Digit<'a> {
     digit: u64,
     with_border: bool,
     symbol: &'a str,
+    flip: Option<Flip>,
 }
 
 impl<'a> Digit<'a> {
@@ -126,71 +451,152 @@ impl<'a> Digit<'a> {
             digit,
             with_border,
             symbol,
+            flip: None,
         }
     }
+
+    pub fn flip(mut self, flip: Option<Flip>) -> Self {
+        self.flip = flip;
+        self
+    }
 }
 
 impl Widget for Digit<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Values out of range are rendered as an error (`E`)
+        let c = u32::try_from(self.digit)
+            .ok()
+            .and_then(|d| char::from_digit(d, 10))
+            .unwrap_or('E');
+        Char::new(c, self.with_border, self.symbol)
+            .flip(self.flip)
+            .render(area, buf);
+    }
+}
+
+/// Single char (digit or letter) rendered in the same 5x5 grid as `Digit`.
+/// Unsupported chars are rendered as empty space.
+pub struct Char<'a> {
+    c: char,
+    with_border: bool,
+    symbol: &'a str,
+    flip: Option<Flip>,
+}
+
+impl<'a> Char<'a> {
+    pub fn new(c: char, with_border: bool, symbol: &'a str) -> Self {
+        Self {
+            c,
+            with_border,
+            symbol,
+            flip: None,
+        }
+    }
+
+    pub fn flip(mut self, flip: Option<Flip>) -> Self {
+        self.flip = flip;
+        self
+    }
+}
+
+impl Widget for Char<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let left = area.left();
         let top = area.top();
+        let element_area = element_area(area, DIGIT_WIDTH);
 
-        let patterns = match self.digit {
-            0 => DIGIT_0,
-            1 => DIGIT_1,
-            2 => DIGIT_2,
-            3 => DIGIT_3,
-            4 => DIGIT_4,
-            5 => DIGIT_5,
-            6 => DIGIT_6,
-            7 => DIGIT_7,
-            8 => DIGIT_8,
-            9 => DIGIT_9,
-            _ => CHAR_E,
-        };
-
-        patterns.iter().enumerate().for_each(|(i, item)| {
-            let x = i % DIGIT_SIZE;
-            let y = i / DIGIT_SIZE;
-            if *item == 1 {
-                let p = Position {
-                    x: left + x as u16,
-                    y: top + y as u16,
-                };
-                if let Some(cell) = buf.cell_mut(p) {
-                    cell.set_symbol(self.symbol);
-                }
-            }
-        });
+        if let Some(patterns) = char_patterns(self.c) {
+            let positions = patterns
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| **item == 1)
+                .map(|(i, _)| Position {
+                    x: left + (i % DIGIT_SIZE) as u16,
+                    y: top + (i / DIGIT_SIZE) as u16,
+                });
+            render_positions(positions, self.symbol, element_area, self.flip, buf);
+        }
 
         // Add border at the bottom
         if self.with_border {
-            for x in 0..area.width {
-                let p = Position {
-                    x: left + x,
-                    y: top + area.height - 1,
-                };
-                if let Some(cell) = buf.cell_mut(p) {
-                    cell.set_symbol("─");
-                }
+            let positions = (0..area.width).map(|x| Position {
+                x: left + x,
+                y: top + area.height - 1,
+            });
+            render_positions(positions, "─", area, self.flip, buf);
+        }
+    }
+}
+
+/// Word of digits and letters rendered by `Char`.
+/// Unsupported chars are skipped.
+pub struct Word<'a> {
+    text: &'a str,
+    symbol: &'a str,
+    flip: Option<Flip>,
+}
+
+impl<'a> Word<'a> {
+    pub fn new(text: &'a str, symbol: &'a str) -> Self {
+        Self {
+            text,
+            symbol,
+            flip: None,
+        }
+    }
+
+    pub fn flip(mut self, flip: Option<Flip>) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    pub fn get_width(&self) -> u16 {
+        let count = self.text.chars().count() as u16;
+        (count * (DIGIT_WIDTH + DIGIT_SPACE_WIDTH)).saturating_sub(DIGIT_SPACE_WIDTH)
+    }
+}
+
+impl Widget for Word<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for (i, c) in self.text.chars().enumerate() {
+            let area = Rect {
+                x: area.x + i as u16 * (DIGIT_WIDTH + DIGIT_SPACE_WIDTH),
+                width: DIGIT_WIDTH,
+                ..area
             }
+            .intersection(area);
+            Char::new(c, false, self.symbol)
+                .flip(self.flip)
+                .render(area, buf);
         }
     }
 }
 
 pub struct Dot<'a> {
     symbol: &'a str,
+    /// Renders a comma (dot with a tail) instead of a dot
+    comma: bool,
+    flip: Option<Flip>,
 }
 
 impl<'a> Dot<'a> {
-    pub fn new(symbol: &'a str) -> Self {
-        Self { symbol }
+    pub fn new(symbol: &'a str, comma: bool) -> Self {
+        Self {
+            symbol,
+            comma,
+            flip: None,
+        }
+    }
+
+    pub fn flip(mut self, flip: Option<Flip>) -> Self {
+        self.flip = flip;
+        self
     }
 }
 
 impl Widget for Dot<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let positions = [
+        let mut positions = vec![
             Position {
                 x: area.left() + 1,
                 y: area.top() + area.height - 2,
@@ -201,52 +607,57 @@ impl Widget for Dot<'_> {
             },
         ];
 
-        for pos in positions {
-            if let Some(cell) = buf.cell_mut(pos) {
-                cell.set_symbol(self.symbol);
-            }
+        if self.comma {
+            // tail
+            positions.push(Position {
+                x: area.left() + 1,
+                y: area.top() + area.height - 1,
+            });
         }
+
+        let area = element_area(area, DOT_WIDTH);
+        render_positions(positions, self.symbol, area, self.flip, buf);
     }
 }
 
 pub struct Colon<'a> {
     symbol: &'a str,
+    flip: Option<Flip>,
+    /// Width incl. padding left + padding right
+    width: u16,
 }
 
 impl<'a> Colon<'a> {
     pub fn new(symbol: &'a str) -> Self {
-        Self { symbol }
+        Self {
+            symbol,
+            flip: None,
+            width: COLON_WIDTH,
+        }
+    }
+
+    pub fn flip(mut self, flip: Option<Flip>) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
     }
 }
 
 impl Widget for Colon<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let left = area.left();
+        // narrow colons (e.g. of `Spacing::Tight`) have thinner dots to keep a padding
+        let dots_width = COLON_DOTS_WIDTH.min(self.width.saturating_sub(2));
+        let left = area.left() + (self.width - dots_width) / 2 /* padding left */;
         let top = area.top();
 
-        let positions = [
-            Position {
-                x: left + 1,
-                y: top + 1,
-            },
-            Position {
-                x: left + 2,
-                y: top + 1,
-            },
-            Position {
-                x: left + 1,
-                y: top + 3,
-            },
-            Position {
-                x: left + 2,
-                y: top + 3,
-            },
-        ];
+        let positions = (left..left + dots_width)
+            .flat_map(|x| [Position { x, y: top + 1 }, Position { x, y: top + 3 }]);
 
-        for pos in positions {
-            if let Some(cell) = buf.cell_mut(pos) {
-                cell.set_symbol(self.symbol);
-            }
-        }
+        let area = element_area(area, self.width);
+        render_positions(positions, self.symbol, area, self.flip, buf);
     }
 }
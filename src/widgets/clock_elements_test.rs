@@ -1,3 +1,4 @@
+use crate::common::Flip;
 use crate::widgets::clock_elements::*;
 use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
 
@@ -318,7 +319,7 @@ fn test_char_e() {
 #[test]
 fn test_dot() {
     let mut b = b();
-    Dot::new("█").render(D_RECT, &mut b);
+    Dot::new("█", false).render(D_RECT, &mut b);
     #[rustfmt::skip]
     let expected = Buffer::with_lines([
         "     ",
@@ -331,6 +332,22 @@ fn test_dot() {
     assert_eq!(b, expected);
 }
 
+#[test]
+fn test_dot_comma() {
+    let mut b = b();
+    Dot::new("█", true).render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "     ",
+        "     ",
+        "     ",
+        "     ",
+        " ██  ",
+        " █   ",
+    ]);
+    assert_eq!(b, expected);
+}
+
 #[test]
 fn test_colon() {
     let mut b = b();
@@ -346,3 +363,131 @@ fn test_colon() {
     ]);
     assert_eq!(b, expected);
 }
+
+#[test]
+fn test_char_a() {
+    let mut b = b();
+    Char::new('A', false, "█").render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "█████",
+        "██ ██",
+        "█████",
+        "██ ██",
+        "██ ██",
+        "     ",
+    ]);
+    assert_eq!(b, expected, "w/o border");
+
+    Char::new('A', true, "█").render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "█████",
+        "██ ██",
+        "█████",
+        "██ ██",
+        "██ ██",
+        "─────",
+    ]);
+    assert_eq!(b, expected, "w/ border");
+}
+
+#[test]
+fn test_char_z() {
+    let mut b = b();
+    Char::new('Z', false, "█").render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "█████",
+        "   ██",
+        " ███ ",
+        "██   ",
+        "█████",
+        "     ",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_char_lowercase() {
+    let mut upper = b();
+    Char::new('K', false, "█").render(D_RECT, &mut upper);
+    let mut lower = b();
+    Char::new('k', false, "█").render(D_RECT, &mut lower);
+    assert_eq!(upper, lower);
+}
+
+#[test]
+fn test_char_digit() {
+    let mut c = b();
+    Char::new('7', false, "█").render(D_RECT, &mut c);
+    let mut d = b();
+    Digit::new(7, false, "█").render(D_RECT, &mut d);
+    assert_eq!(c, d);
+}
+
+#[test]
+fn test_char_unsupported() {
+    let mut b = b();
+    Char::new('?', false, "█").render(D_RECT, &mut b);
+    assert_eq!(b, Buffer::empty(D_RECT));
+}
+
+#[test]
+fn test_chars_supported() {
+    assert!(('A'..='Z').all(is_supported_char));
+    assert!(('0'..='9').all(is_supported_char));
+    assert!(!is_supported_char('!'));
+}
+
+#[test]
+fn test_d1_flip() {
+    let mut b = b();
+    Digit::new(1, false, "█")
+        .flip(Some(Flip::Horizontal))
+        .render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "██   ",
+        "██   ",
+        "██   ",
+        "██   ",
+        "██   ",
+        "     ",
+    ]);
+    assert_eq!(b, expected, "horizontal");
+
+    let mut b = self::b();
+    Digit::new(7, true, "█")
+        .flip(Some(Flip::Vertical))
+        .render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "─────",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "█████",
+    ]);
+    assert_eq!(b, expected, "vertical w/ border");
+}
+
+#[test]
+fn test_dot_comma_flip() {
+    let rect = Rect::new(0, 0, DOT_WIDTH, DIGIT_HEIGHT);
+    let mut b = Buffer::empty(rect);
+    Dot::new("█", true)
+        .flip(Some(Flip::Vertical))
+        .render(rect, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        " █  ",
+        " ██ ",
+        "    ",
+        "    ",
+        "    ",
+        "    ",
+    ]);
+    assert_eq!(b, expected);
+}
@@ -1,12 +1,16 @@
 use crate::{
-    common::ClockTypeId,
+    common::{AppTime, ClockTypeId, DecimalSeparator},
     duration::{
         DurationEx, MAX_DURATION, ONE_DAY, ONE_DECI_SECOND, ONE_HOUR, ONE_MINUTE, ONE_SECOND,
         ONE_YEAR,
     },
-    widgets::clock::*,
+    events::AppEvent,
+    widgets::{
+        clock::*,
+        test_utils::{FIXED_TIME, app_channel},
+    },
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 fn default_args() -> ClockStateArgs {
     ClockStateArgs {
@@ -222,6 +226,27 @@ fn test_format_by_duration_boundaries() {
     );
 }
 
+#[test]
+fn test_format_by_seconds() {
+    assert_eq!(
+        format_by_seconds::<DurationEx>(&(ONE_SECOND * 9).into()),
+        Some(Format::S)
+    );
+    assert_eq!(
+        format_by_seconds::<DurationEx>(&(ONE_SECOND * 90).into()),
+        Some(Format::Ss)
+    );
+    assert_eq!(
+        format_by_seconds::<DurationEx>(&(ONE_SECOND * 999).into()),
+        Some(Format::Sss)
+    );
+    // fall back to regular format
+    assert_eq!(
+        format_by_seconds::<DurationEx>(&(ONE_SECOND * 1000).into()),
+        None
+    );
+}
+
 #[test]
 fn test_format_by_duration_days() {
     // DHhMmSs
@@ -415,6 +440,84 @@ fn test_edit_up_stays_in_days() {
     assert!(matches!(c.get_mode(), Mode::Editable(Time::Days, _)));
 }
 
+#[test]
+fn test_edit_up_max_value() {
+    let mut c = ClockState::<Countdown>::new(ClockStateArgs {
+        initial_value: ONE_DAY - ONE_MINUTE,
+        current_value: ONE_DAY - ONE_MINUTE,
+        tick_value: ONE_DECI_SECOND,
+        with_decis: false,
+        app_tx: None,
+    })
+    .with_max_value(ONE_DAY);
+
+    c.toggle_edit();
+    c.edit_up(); // +1m
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_DAY);
+    c.edit_up(); // +1m (exceeding max. value)
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_DAY);
+}
+
+#[test]
+fn test_max_value_caps_values() {
+    let c = ClockState::<Countdown>::new(ClockStateArgs {
+        initial_value: ONE_DAY * 2,
+        current_value: ONE_DAY + ONE_HOUR,
+        tick_value: ONE_DECI_SECOND,
+        with_decis: false,
+        app_tx: None,
+    })
+    .with_max_value(ONE_DAY);
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_DAY);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_DAY);
+    // can't exceed `MAX_DURATION`
+    let c = c.with_max_value(MAX_DURATION + ONE_DAY);
+    assert_eq!(Duration::from(*c.get_max_value()), MAX_DURATION);
+}
+
+#[test]
+fn test_timer_done_by_max_value() {
+    let mut c = ClockState::<Timer>::new(ClockStateArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_HOUR - ONE_DECI_SECOND,
+        tick_value: ONE_DECI_SECOND,
+        with_decis: false,
+        app_tx: None,
+    })
+    .with_max_value(ONE_HOUR);
+    c.run();
+    c.tick();
+    assert!(c.is_done());
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_HOUR);
+}
+
+#[test]
+fn test_timer_wrap_vs_stop_by_max_value() {
+    let timer = |wrap: bool| {
+        let mut c = ClockState::<Timer>::new(ClockStateArgs {
+            initial_value: Duration::ZERO,
+            current_value: MAX_DURATION - ONE_DECI_SECOND,
+            tick_value: ONE_DECI_SECOND,
+            with_decis: false,
+            app_tx: None,
+        })
+        .with_wrap(wrap);
+        c.run();
+        c.tick();
+        c
+    };
+    // stop (default)
+    let c = timer(false);
+    assert!(c.is_done());
+    assert_eq!(Duration::from(*c.get_current_value()), MAX_DURATION);
+    // wrap
+    let mut c = timer(true);
+    assert!(c.is_running());
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    c.tick();
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_DECI_SECOND);
+}
+
 #[test]
 fn test_edit_up_overflow_protection() {
     let mut c = ClockState::<Timer>::new(ClockStateArgs {
@@ -1122,3 +1225,264 @@ fn test_edit_down_hhmmss() {
     c.edit_down();
     assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
 }
+
+#[test]
+fn test_display() {
+    let mut c = ClockState::<Countdown>::new(ClockStateArgs {
+        initial_value: ONE_MINUTE * 5,
+        current_value: ONE_MINUTE * 4 + ONE_SECOND * 32,
+        tick_value: ONE_DECI_SECOND,
+        with_decis: false,
+        app_tx: None,
+    });
+    assert_eq!(c.to_string(), "4:32 ||");
+    c.run();
+    assert_eq!(c.to_string(), "4:32 >");
+    c.with_decis = true;
+    assert_eq!(c.to_string(), "4:32.0 >");
+    assert_eq!(
+        c.to_string_with_separator(DecimalSeparator::Comma),
+        "4:32,0 >"
+    );
+}
+
+#[test]
+fn test_advance_countdown() {
+    // compare `advance(n * tick)` against `n` calls of `tick`
+    for n in [0, 1, 5, 10, 99, 100, 101, 1000] {
+        let args = || ClockStateArgs {
+            initial_value: ONE_SECOND * 10,
+            current_value: ONE_SECOND * 10,
+            ..default_args()
+        };
+        let (tx, mut rx) = app_channel();
+        let mut a = ClockState::<Countdown>::new(ClockStateArgs {
+            app_tx: Some(tx),
+            ..args()
+        });
+        let mut b = ClockState::<Countdown>::new(args());
+        a.run();
+        b.run();
+        a.advance(ONE_DECI_SECOND * n);
+        for _ in 0..n {
+            b.tick();
+        }
+        assert_eq!(a.get_current_value(), b.get_current_value(), "n={n}");
+        assert_eq!(a.get_mode(), b.get_mode(), "n={n}");
+        assert_eq!(a.get_format(), b.get_format(), "n={n}");
+        assert_eq!(a.get_done_count(), b.get_done_count(), "n={n}");
+        // `done` event is sent once
+        assert_eq!(rx.try_recv().is_ok(), a.is_done(), "n={n}");
+        assert!(rx.try_recv().is_err(), "n={n}");
+    }
+}
+
+#[test]
+fn test_advance_timer() {
+    // compare `advance(n * tick)` against `n` calls of `tick`
+    for (current_value, n) in [
+        (Duration::ZERO, 0),
+        (Duration::ZERO, 1),
+        (Duration::ZERO, 601),
+        (ONE_MINUTE * 59, 700),
+        // reaching `MAX_DURATION`
+        (MAX_DURATION - ONE_SECOND, 9),
+        (MAX_DURATION - ONE_SECOND, 10),
+        (MAX_DURATION - ONE_SECOND, 20),
+        // exceeding `MAX_DURATION`
+        (MAX_DURATION - ONE_SECOND - Duration::from_millis(50), 20),
+    ] {
+        let args = || ClockStateArgs {
+            initial_value: Duration::ZERO,
+            current_value,
+            ..default_args()
+        };
+        let mut a = ClockState::<Timer>::new(args());
+        let mut b = ClockState::<Timer>::new(args());
+        a.run();
+        b.run();
+        a.advance(ONE_DECI_SECOND * n);
+        for _ in 0..n {
+            b.tick();
+        }
+        assert_eq!(a.get_current_value(), b.get_current_value(), "n={n}");
+        assert_eq!(a.get_mode(), b.get_mode(), "n={n}");
+        assert_eq!(a.get_format(), b.get_format(), "n={n}");
+    }
+}
+
+#[test]
+fn test_advance_ignores_remainder() {
+    let mut c = ClockState::<Countdown>::new(default_args());
+    c.run();
+    c.advance(ONE_DECI_SECOND * 3 + Duration::from_millis(99));
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR - ONE_DECI_SECOND * 3
+    );
+}
+
+#[test]
+fn test_advance_many_ticks() {
+    // more than `u32::MAX` ticks (~13.6 years of deciseconds)
+    let years = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+    let mut c = ClockState::<Countdown>::new(ClockStateArgs {
+        initial_value: MAX_DURATION,
+        current_value: MAX_DURATION,
+        ..default_args()
+    });
+    c.run();
+    c.advance(years);
+    assert_eq!(Duration::from(*c.get_current_value()), MAX_DURATION - years);
+}
+
+#[test]
+fn test_advance_paused() {
+    let mut c = ClockState::<Countdown>::new(default_args());
+    c.advance(ONE_MINUTE);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_HOUR);
+}
+
+#[test]
+fn test_set_running() {
+    let mut c = ClockState::<Countdown>::new(default_args());
+    // initial clock is not paused
+    c.set_running(false);
+    assert_eq!(c.get_mode(), &Mode::Initial);
+    c.set_running(true);
+    assert_eq!(c.get_mode(), &Mode::Tick);
+    c.set_running(false);
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    c.set_running(true);
+    assert_eq!(c.get_mode(), &Mode::Tick);
+    // done clock is not changed
+    c.advance(ONE_HOUR);
+    c.set_running(true);
+    assert_eq!(c.get_mode(), &Mode::Done);
+}
+
+#[test]
+fn test_urgency_color() {
+    use ratatui::style::Color;
+    assert_eq!(urgency_color(0), Color::Rgb(0, 255, 0));
+    assert_eq!(urgency_color(25), Color::Rgb(127, 255, 0));
+    assert_eq!(urgency_color(50), Color::Rgb(255, 255, 0));
+    assert_eq!(urgency_color(75), Color::Rgb(255, 127, 0));
+    assert_eq!(urgency_color(100), Color::Rgb(255, 0, 0));
+    // out of range
+    assert_eq!(urgency_color(200), Color::Rgb(255, 0, 0));
+}
+
+#[test]
+fn test_edit_jump_step() {
+    let mut c = ClockState::<Countdown>::new(default_args()).with_jump_step(5);
+    // default edit mode: minutes
+    c.toggle_edit();
+    c.edit_jump_up();
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_MINUTE * 5
+    );
+    c.edit_jump_down();
+    c.edit_jump_down();
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR - ONE_MINUTE * 5
+    );
+}
+
+#[test]
+fn test_edit_flash() {
+    let (tx, mut rx) = app_channel();
+    let mut c = ClockState::<Countdown>::new(ClockStateArgs {
+        initial_value: ONE_SECOND * 30,
+        current_value: ONE_SECOND * 30,
+        app_tx: Some(tx),
+        ..default_args()
+    })
+    .with_max_value(ONE_MINUTE);
+    c.toggle_edit();
+    // 30s -> 40s -> 50s -> 60s
+    for _ in 0..3 {
+        c.edit_jump_up();
+    }
+    assert!(rx.try_recv().is_err());
+    // rejected: max reached
+    c.edit_up();
+    assert_eq!(rx.try_recv(), Ok(AppEvent::Flash("max reached".into())));
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_MINUTE);
+    // stopped at zero
+    c.edit_next();
+    c.edit_down();
+    assert!(rx.try_recv().is_err());
+    c.edit_down();
+    assert_eq!(rx.try_recv(), Ok(AppEvent::Flash("zero reached".into())));
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+}
+
+#[test]
+fn test_edit_acceleration() {
+    assert_eq!(edit_acceleration(0), 1);
+    assert_eq!(edit_acceleration(10), 2);
+    assert_eq!(edit_acceleration(20), 5);
+    assert_eq!(edit_acceleration(30), 10);
+}
+
+#[test]
+fn test_edit_repeated() {
+    let mut c = ClockState::<Countdown>::new(default_args());
+    let now = Instant::now();
+    let repeat = Duration::from_millis(100);
+    // default edit mode: minutes
+    c.toggle_edit();
+    // 10x by 1 + 5x by 2
+    for i in 0..15 {
+        c.edit_repeated(true, now + repeat * i);
+    }
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_MINUTE * 20
+    );
+    // changing direction starts by 1 again
+    c.edit_repeated(false, now + repeat * 15);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_MINUTE * 19
+    );
+    // a pause between edits starts by 1 again
+    // 9x by 1 + 1x by 2
+    for i in 16..26 {
+        c.edit_repeated(false, now + repeat * i);
+    }
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_MINUTE * 8
+    );
+    c.edit_repeated(false, now + repeat * 26 + ONE_SECOND);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_MINUTE * 7
+    );
+}
+
+#[test]
+fn test_timer_wall_time() {
+    let at = |d: Duration| AppTime::Utc(FIXED_TIME + d);
+    let mut c = ClockState::<Timer>::new(default_args()).with_app_time(at(Duration::ZERO));
+    assert_eq!(c.get_wall_time(), None);
+    assert_eq!(c.get_running_time(), None);
+    c.run();
+    c.pause();
+    // a minute later, paused all the time
+    c.set_app_time(at(ONE_MINUTE));
+    assert_eq!(c.get_wall_time(), Some(ONE_MINUTE));
+    assert_eq!(c.get_running_time(), Some(Duration::ZERO));
+    // running again for another minute
+    c.toggle_pause();
+    c.set_app_time(at(ONE_MINUTE * 2));
+    assert_eq!(c.get_wall_time(), Some(ONE_MINUTE * 2));
+    assert_eq!(c.get_running_time(), Some(ONE_MINUTE));
+    // reset ends session
+    c.reset();
+    assert_eq!(c.get_wall_time(), None);
+}
@@ -1,18 +1,18 @@
 use crate::{
-    common::Style,
+    common::{DecimalSeparator, Spacing, Style},
     duration::{MAX_DURATION, parse_duration, parse_long_duration},
     widgets::{
-        clock::{ClockState, ClockStateArgs, ClockWidget, Timer},
-        clock_elements::DIGIT_HEIGHT,
+        clock::{ClockState, ClockStateArgs, ClockWidget, Mode, Timer},
+        clock_elements::{DIGIT_HEIGHT, DIGIT_WIDTH, DOT_WIDTH},
         test_utils::{DrawArgs, draw},
     },
 };
 use insta::assert_snapshot;
-use ratatui::{Terminal, backend::TestBackend};
+use ratatui::{Terminal, backend::TestBackend, style::Modifier};
 use std::time::Duration;
 
 fn w() -> ClockWidget<Timer> {
-    ClockWidget::new(Style::default(), false)
+    ClockWidget::new(Style::default(), false, DecimalSeparator::default(), None)
 }
 
 fn args() -> ClockStateArgs {
@@ -283,6 +283,20 @@ fn test_clock_decis() {
     assert_snapshot!("clock_decis", t.backend());
 }
 
+#[test]
+fn test_clock_decis_comma() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: Duration::from_secs(7),
+        with_decis: true,
+        ..args()
+    });
+    let t = terminal(
+        ClockWidget::new(Style::default(), false, DecimalSeparator::Comma, None),
+        st,
+    );
+    assert_snapshot!("clock_decis_comma", t.backend());
+}
+
 #[test]
 fn test_clock_style() {
     let st = st_with_args(ClockStateArgs {
@@ -290,6 +304,139 @@ fn test_clock_style() {
         with_decis: true,
         ..args()
     });
-    let t = terminal(ClockWidget::new(Style::Braille, false), st);
+    let t = terminal(
+        ClockWidget::new(Style::Braille, false, DecimalSeparator::default(), None),
+        st,
+    );
     assert_snapshot!("clock_style", t.backend());
 }
+
+#[test]
+fn test_clock_no_unit_labels() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: MAX_DURATION,
+        ..args()
+    });
+    let widget = w().unit_labels(false);
+    // 2 labels, each 2 chars narrower
+    assert_eq!(widget.get_width(&st) + 4, w().get_width(&st));
+    let t = terminal(widget, st);
+    assert_snapshot!("clock_no_unit_labels", t.backend());
+}
+
+#[test]
+fn test_clock_spacing_tight() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("10:45:08").unwrap(),
+        ..args()
+    });
+    let widget = w().spacing(Spacing::Tight);
+    // 2 colons (each 1 char narrower)
+    assert_eq!(widget.get_width(&st) + 2, w().get_width(&st));
+    let t = terminal(widget, st);
+    assert_snapshot!("clock_spacing_tight", t.backend());
+}
+
+#[test]
+fn test_clock_spacing_wide() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("10:45:08").unwrap(),
+        ..args()
+    });
+    let widget = w().spacing(Spacing::Wide);
+    // 3 digit spaces + 2 colons (each 2 chars wider)
+    assert_eq!(widget.get_width(&st), w().get_width(&st) + 3 + 4);
+    let t = terminal(widget, st);
+    assert_snapshot!("clock_spacing_wide", t.backend());
+}
+
+#[test]
+fn test_clock_too_small() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("12:34").unwrap(),
+        ..args()
+    });
+    let t = draw(DrawArgs {
+        widget: w(),
+        state: st,
+        width: 20,
+        height: 3,
+    });
+    assert_snapshot!("clock_too_small", t.backend());
+}
+
+#[test]
+fn test_clock_pause_dim() {
+    let st = || {
+        st_with_args(ClockStateArgs {
+            current_value: Duration::from_secs(7),
+            ..args()
+        })
+    };
+    let is_dim = |t: &Terminal<TestBackend>| {
+        t.backend().buffer()[(0, 0)]
+            .modifier
+            .contains(Modifier::DIM)
+    };
+    let paused = st();
+    assert_eq!(paused.get_mode(), &Mode::Pause);
+    assert!(is_dim(&terminal(w(), paused)));
+    let mut running = st();
+    running.run();
+    assert!(!is_dim(&terminal(w(), running)));
+}
+
+#[test]
+fn test_clock_hide_seconds() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("10:45:08").unwrap(),
+        with_decis: true,
+        ..args()
+    });
+    let widget = w().hide_seconds_after(Some(Duration::from_hours(1)));
+    assert!(widget.hides_seconds(&st));
+    // w/o `:`, `s_s`, `.` and `ds`
+    let spacing = Spacing::default();
+    assert_eq!(
+        widget.get_width(&st),
+        w().get_width(&st)
+            - spacing.colon_width()
+            - spacing.digits_width(2)
+            - DOT_WIDTH
+            - DIGIT_WIDTH
+    );
+    let t = terminal(widget, st);
+    assert_snapshot!("clock_hide_seconds", t.backend());
+}
+
+#[test]
+fn test_clock_hide_seconds_below_threshold() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("0:59:59").unwrap(),
+        ..args()
+    });
+    let widget = w().hide_seconds_after(Some(Duration::from_hours(1)));
+    assert!(!widget.hides_seconds(&st));
+    assert_eq!(widget.get_width(&st), w().get_width(&st));
+}
+
+#[test]
+fn test_clock_hide_seconds_needs_hours() {
+    let st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("45:08").unwrap(),
+        ..args()
+    });
+    let widget = w().hide_seconds_after(Some(Duration::from_mins(1)));
+    assert!(!widget.hides_seconds(&st));
+}
+
+#[test]
+fn test_clock_hide_seconds_not_while_editing() {
+    let mut st = st_with_args(ClockStateArgs {
+        current_value: parse_duration("10:45:08").unwrap(),
+        ..args()
+    });
+    st.toggle_edit();
+    let widget = w().hide_seconds_after(Some(Duration::from_hours(1)));
+    assert!(!widget.hides_seconds(&st));
+}
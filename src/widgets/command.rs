@@ -0,0 +1,239 @@
+use clap::ValueEnum;
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Position, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Clear, StatefulWidget, Widget},
+};
+use std::time::Duration;
+use tui_input::Input;
+use tui_input::backend::crossterm::EventHandler;
+
+use crate::{
+    common::{Content, Style},
+    duration::parse_long_duration,
+    event::{Event, parse_event},
+};
+
+/// Actions to run from command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// `countdown <DURATION>`: restarts countdown by given value
+    Countdown(Duration),
+    /// `style <STYLE>`
+    Style(Style),
+    /// `event <DATETIME>` or `event time=<DATETIME>,title=<TITLE>`
+    Event(Event),
+    /// `mode <CONTENT>`: switches screen
+    Mode(Content),
+    /// `quit` or `q`
+    Quit,
+}
+
+/// Names of all commands (long version) to suggest
+const COMMANDS: [&str; 5] = ["countdown", "style", "event", "mode", "quit"];
+
+/// Commands which need a value
+fn needs_value(name: &str) -> bool {
+    !matches!(name, "quit" | "q" | "")
+}
+
+/// Possible values of a command to suggest (if any)
+fn values(name: &str) -> Vec<String> {
+    fn names<T: ValueEnum>() -> Vec<String> {
+        T::value_variants()
+            .iter()
+            .filter_map(|v| v.to_possible_value())
+            .map(|v| v.get_name().to_owned())
+            .collect()
+    }
+    match name {
+        "style" | "s" => names::<Style>(),
+        "mode" | "m" => names::<Content>(),
+        _ => vec![],
+    }
+}
+
+/// Suggests completed inputs by prefix of command names (or their values)
+pub fn suggest(input: &str) -> Vec<String> {
+    let input = input.trim_start();
+    match input.split_once(char::is_whitespace) {
+        None => COMMANDS
+            .iter()
+            .filter(|name| name.starts_with(input))
+            .map(|name| {
+                if needs_value(name) {
+                    format!("{name} ")
+                } else {
+                    name.to_string()
+                }
+            })
+            .collect(),
+        Some((name, value)) => {
+            let value = value.trim_start();
+            values(name)
+                .into_iter()
+                .filter(|v| v.starts_with(value) && *v != value)
+                .map(|v| format!("{name} {v}"))
+                .collect()
+        }
+    }
+}
+
+/// Parses a `Command` from an input like `countdown 5:00` by using the parsers of the CLI args
+pub fn parse_command(input: &str) -> Result<Command, String> {
+    let input = input.trim();
+    let (name, value) = input
+        .split_once(char::is_whitespace)
+        .map(|(name, value)| (name, value.trim()))
+        .unwrap_or((input, ""));
+    if needs_value(name) && value.is_empty() {
+        return Err(format!("missing value of '{name}'"));
+    }
+    match name {
+        "countdown" | "c" => parse_long_duration(value)
+            .map(Command::Countdown)
+            .map_err(|err| err.to_string()),
+        "style" | "s" => Style::from_str(value, true).map(Command::Style),
+        "event" | "e" => parse_event(value).map(Command::Event),
+        "mode" | "m" => Content::from_str(value, true).map(Command::Mode),
+        "quit" | "q" => Ok(Command::Quit),
+        "" => Err("missing command".into()),
+        _ => Err(format!("unknown command '{name}'")),
+    }
+}
+
+/// State for `CommandWidget`
+#[derive(Debug, Clone, Default)]
+pub struct CommandState {
+    show: bool,
+    input: Input,
+    /// Completed inputs (see `suggest`)
+    suggestions: Vec<String>,
+    /// Index of selected item of `suggestions`
+    selected: usize,
+    /// Cursor position of latest render
+    cursor: Option<Position>,
+}
+
+impl CommandState {
+    pub fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    pub fn open(&mut self) {
+        self.show = true;
+        self.input.reset();
+        self.update_suggestions();
+    }
+
+    pub fn hide(&mut self) {
+        self.show = false;
+        self.cursor = None;
+    }
+
+    pub fn get_selected(&self) -> Option<&str> {
+        self.suggestions.get(self.selected).map(String::as_str)
+    }
+
+    fn update_suggestions(&mut self) {
+        self.suggestions = suggest(self.input.value());
+        self.selected = 0;
+    }
+
+    pub fn get_cursor(&self) -> Option<Position> {
+        self.cursor
+    }
+
+    /// Handles `KeyEvent`'s of command line.
+    /// Returns the parsed `Command` (or an error) by submitting an input with `Enter`.
+    pub fn update(&mut self, key: KeyEvent) -> Option<Result<Command, String>> {
+        match key.code {
+            KeyCode::Esc => self.hide(),
+            KeyCode::Enter => {
+                let result = parse_command(self.input.value());
+                self.hide();
+                return Some(result);
+            }
+            // select and accept suggestions
+            KeyCode::Down if !self.suggestions.is_empty() => {
+                self.selected = (self.selected + 1) % self.suggestions.len();
+            }
+            KeyCode::Up if !self.suggestions.is_empty() => {
+                self.selected = self
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(self.suggestions.len() - 1);
+            }
+            KeyCode::Tab => {
+                if let Some(value) = self.get_selected() {
+                    self.input = Input::new(value.to_owned());
+                    self.update_suggestions();
+                }
+            }
+            // remove `:` by deleting an empty input (same as in vim)
+            KeyCode::Backspace if self.input.value().is_empty() => self.hide(),
+            _ => {
+                if self
+                    .input
+                    .handle_event(&CrosstermEvent::Key(key))
+                    .is_some_and(|changed| changed.value)
+                {
+                    self.update_suggestions();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// One-line input at the bottom of given area to enter a `Command`.
+/// Suggestions are listed in a line above (same as Vim's `wildmenu`).
+pub struct CommandWidget;
+
+impl StatefulWidget for CommandWidget {
+    type State = CommandState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let [_, suggestions_area, area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        if !state.suggestions.is_empty() {
+            Clear.render(suggestions_area, buf);
+            let spans = state
+                .suggestions
+                .iter()
+                .enumerate()
+                .flat_map(|(index, value)| {
+                    // list the completed part only, e.g. `braille` of `style braille`
+                    let label = value.trim_end().rsplit(' ').next().unwrap_or_default();
+                    let style = if index == state.selected {
+                        Modifier::REVERSED
+                    } else {
+                        Modifier::empty()
+                    };
+                    [Span::raw(" "), Span::styled(label.to_owned(), style)]
+                });
+            Line::from_iter(spans).render(suggestions_area, buf);
+        }
+
+        // scroll input to keep cursor visible
+        let width = area.width.saturating_sub(1) as usize;
+        let scroll = state.input.visual_scroll(width);
+
+        Clear.render(area, buf);
+        Line::from(vec![
+            Span::styled(":", Modifier::BOLD),
+            Span::raw(state.input.value().chars().skip(scroll).collect::<String>()),
+        ])
+        .render(area, buf);
+
+        let cursor_x = area.x + 1 + state.input.visual_cursor().saturating_sub(scroll) as u16;
+        state.cursor = Some(Position::new(cursor_x.min(area.right()), area.y));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    common::{Content, Style},
+    widgets::{
+        command::{Command, CommandState, CommandWidget, parse_command, suggest},
+        test_utils::{DrawArgs, draw},
+    },
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use insta::assert_snapshot;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    widgets::StatefulWidget,
+};
+use std::time::Duration;
+use time::macros::datetime;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn type_in(st: &mut CommandState, value: &str) {
+    for c in value.chars() {
+        assert_eq!(st.update(key(KeyCode::Char(c))), None);
+    }
+}
+
+#[test]
+fn test_parse_command() {
+    assert_eq!(
+        parse_command("countdown 5:00"),
+        Ok(Command::Countdown(Duration::from_secs(300)))
+    );
+    assert_eq!(
+        parse_command(" c 1d 0:30 "),
+        Ok(Command::Countdown(
+            Duration::from_secs(24 * 60 * 60) + Duration::from_secs(30)
+        ))
+    );
+    assert_eq!(
+        parse_command("style braille"),
+        Ok(Command::Style(Style::Braille))
+    );
+    assert_eq!(
+        parse_command("mode timer"),
+        Ok(Command::Mode(Content::Timer))
+    );
+    assert_eq!(parse_command("q"), Ok(Command::Quit));
+
+    let Ok(Command::Event(event)) = parse_command("event 2025-12-25 00:00:00") else {
+        panic!("event expected");
+    };
+    assert_eq!(event.date_time, datetime!(2025-12-25 00:00:00));
+}
+
+#[test]
+fn test_parse_command_errors() {
+    assert_eq!(parse_command(""), Err("missing command".into()));
+    assert_eq!(parse_command("foo 1"), Err("unknown command 'foo'".into()));
+    assert_eq!(
+        parse_command("countdown"),
+        Err("missing value of 'countdown'".into())
+    );
+    assert!(parse_command("style foo").is_err());
+    assert!(parse_command("event tomorrow").is_err());
+}
+
+#[test]
+fn test_command_submit() {
+    let mut st = CommandState::default();
+    st.open();
+    assert!(st.is_shown());
+    type_in(&mut st, "mode event");
+    assert_eq!(
+        st.update(key(KeyCode::Enter)),
+        Some(Ok(Command::Mode(Content::Event)))
+    );
+    assert!(!st.is_shown());
+    // input is cleared by opening again
+    st.open();
+    assert_eq!(
+        st.update(key(KeyCode::Enter)),
+        Some(Err("missing command".into()))
+    );
+}
+
+#[test]
+fn test_command_cancel() {
+    let mut st = CommandState::default();
+    st.open();
+    type_in(&mut st, "q");
+    assert_eq!(st.update(key(KeyCode::Esc)), None);
+    assert!(!st.is_shown());
+    // backspace on empty input closes it
+    st.open();
+    type_in(&mut st, "q");
+    st.update(key(KeyCode::Backspace));
+    assert!(st.is_shown());
+    st.update(key(KeyCode::Backspace));
+    assert!(!st.is_shown());
+}
+
+#[test]
+fn test_command_render() {
+    let mut st = CommandState::default();
+    st.open();
+    type_in(&mut st, "style braille");
+    let t = draw(DrawArgs {
+        widget: CommandWidget,
+        state: st.clone(),
+        width: 30,
+        height: 3,
+    });
+    assert_snapshot!("command", t.backend());
+
+    let area = Rect::new(0, 0, 30, 3);
+    CommandWidget.render(area, &mut Buffer::empty(area), &mut st);
+    assert_eq!(st.get_cursor(), Some(Position::new(14, 2)));
+}
+
+#[test]
+fn test_suggest() {
+    assert_eq!(suggest("sty"), vec!["style "]);
+    assert_eq!(suggest("q"), vec!["quit"]);
+    assert_eq!(suggest("").len(), 5);
+    assert_eq!(suggest("style b"), vec!["style braille"]);
+    assert_eq!(suggest("style ").len(), 7);
+    assert_eq!(suggest("mode ti"), vec!["mode timer"]);
+    // exact values are skipped
+    assert!(suggest("mode timer").is_empty());
+    // no values to suggest
+    assert!(suggest("countdown 1").is_empty());
+    assert!(suggest("foo").is_empty());
+}
+
+#[test]
+fn test_command_accept_suggestion() {
+    let mut st = CommandState::default();
+    st.open();
+    type_in(&mut st, "st");
+    assert_eq!(st.get_selected(), Some("style "));
+    st.update(key(KeyCode::Tab));
+    assert_eq!(st.get_selected(), Some("style full"));
+    st.update(key(KeyCode::Down));
+    assert_eq!(st.get_selected(), Some("style light"));
+    // wrap around
+    st.update(key(KeyCode::Up));
+    st.update(key(KeyCode::Up));
+    assert_eq!(st.get_selected(), Some("style braille"));
+    st.update(key(KeyCode::Tab));
+    assert_eq!(st.get_selected(), None);
+    assert_eq!(
+        st.update(key(KeyCode::Enter)),
+        Some(Ok(Command::Style(Style::Braille)))
+    );
+}
+
+#[test]
+fn test_command_render_suggestions() {
+    let mut st = CommandState::default();
+    st.open();
+    type_in(&mut st, "style ");
+    st.update(key(KeyCode::Down));
+    let t = draw(DrawArgs {
+        widget: CommandWidget,
+        state: st,
+        width: 60,
+        height: 3,
+    });
+    assert_snapshot!("command_suggestions", t.backend());
+}
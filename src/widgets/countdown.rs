@@ -1,14 +1,19 @@
 use crate::{
-    common::{AppTime, AppTimeFormat, ClockName, Style},
+    common::{
+        AppTime, AppTimeFormat, ClockName, DecimalSeparator, Flip, Spacing, Style,
+        with_local_offset,
+    },
     constants::TICK_VALUE_MS,
-    duration::{DurationEx, MAX_DURATION},
-    events::{AppEventTx, TuiEvent, TuiEventHandler},
+    duration::{DurationEx, ONE_MINUTE},
+    events::{AppEvent, AppEventTx, TuiEvent, TuiEventHandler},
     widgets::{
         clock::{self, ClockState, ClockStateArgs, ClockWidget, Mode as ClockMode},
+        clock_elements::{DIGIT_HEIGHT, Word},
         edit_time::{EditTimeState, EditTimeStateArgs, EditTimeWidget},
+        progressbar::BlockBar,
     },
 };
-use crossterm::event::{Event as CrosstermEvent, KeyModifiers};
+use crossterm::event::{Event as CrosstermEvent, KeyModifiers, MouseEventKind};
 use ratatui::{
     buffer::Buffer,
     crossterm::event::KeyCode,
@@ -16,8 +21,9 @@ use ratatui::{
     text::Line,
     widgets::{StatefulWidget, Widget},
 };
+use std::collections::VecDeque;
 use std::ops::Sub;
-use std::{cmp::max, time::Duration};
+use std::{cmp::max, fmt, time::Duration};
 use time::OffsetDateTime;
 
 pub struct CountdownStateArgs {
@@ -29,6 +35,90 @@ pub struct CountdownStateArgs {
     pub with_decis: bool,
     pub app_tx: AppEventTx,
     pub vim_motions: bool,
+    /// Delay to start countdown automatically. `Duration::ZERO` means starting immediately.
+    pub autostart: Option<Duration>,
+    /// Text to render instead of digits if countdown is done
+    pub done_text: Option<String>,
+    /// Absolute time to count down to (if any)
+    pub target: Option<OffsetDateTime>,
+    /// Render passed time instead of time left
+    pub count_up: bool,
+    /// Blink within the final seconds. `0` to disable it.
+    pub warn_secs: u64,
+    /// Pause once by reaching this time left (if any)
+    pub pause_at: Option<Duration>,
+    /// Cue (`AppEvent::CountdownMinuteCue`) by reaching each whole minute left
+    pub minute_cue: bool,
+    /// Seconds to reset countdown after it's done (if any)
+    pub auto_reset_after: Option<u64>,
+    /// Name to label countdown (if any)
+    pub name: Option<String>,
+    /// Durations to count down one after another (if any)
+    pub sequence: Option<SequenceState>,
+    /// Durations to load by pressing their number (`1` .. `9`)
+    pub presets: Vec<Duration>,
+    /// Start countdown at next whole second of `app_time`
+    pub align_to_minute: bool,
+    /// Count elapsed time (MET) after countdown is done
+    pub met: bool,
+}
+
+/// Durations to count down one after another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceState {
+    steps: Vec<Duration>,
+    /// Index of current step
+    index: usize,
+}
+
+impl SequenceState {
+    /// Returns `None` if there are no steps.
+    /// An `index` out of range falls back to the last step.
+    pub fn new(steps: Vec<Duration>, index: usize) -> Option<Self> {
+        let index = index.min(steps.len().checked_sub(1)?);
+        Some(Self { steps, index })
+    }
+
+    pub fn get_steps(&self) -> &[Duration] {
+        &self.steps
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_current(&self) -> Duration {
+        self.steps[self.index]
+    }
+
+    pub fn get_upcoming(&self) -> Option<Duration> {
+        self.steps.get(self.index + 1).copied()
+    }
+
+    /// Moves to next step and returns its duration.
+    /// Returns `None` if the last step has been reached.
+    pub fn advance(&mut self) -> Option<Duration> {
+        let upcoming = self.get_upcoming()?;
+        self.index += 1;
+        Some(upcoming)
+    }
+
+    /// Moves back to first step and returns its duration
+    pub fn restart(&mut self) -> Duration {
+        self.index = 0;
+        self.get_current()
+    }
+}
+
+/// Displays current step and upcoming duration (if any), e.g. `step 3/6 next 5:00`
+impl fmt::Display for SequenceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "step {}/{}", self.index + 1, self.steps.len())?;
+        if let Some(upcoming) = self.get_upcoming() {
+            write!(f, " next {}", DurationEx::from(upcoming))?;
+        }
+        Ok(())
+    }
 }
 
 /// State for Countdown Widget
@@ -44,8 +134,40 @@ pub struct CountdownState {
     target_time: OffsetDateTime,
     /// Edit by local time
     edit_time: Option<EditTimeState>,
+    /// Absolute time to finish, set by editing by local time.
+    /// While running, the countdown is synced with it (instead of ticking a fixed duration).
+    target_anchor: Option<OffsetDateTime>,
     /// Whether Vim motions are enabled
     vim_motions: bool,
+    /// Time left to start countdown automatically ("get ready")
+    preroll: Option<DurationEx>,
+    /// Text to render instead of digits if countdown is done
+    done_text: Option<String>,
+    /// Render passed time instead of time left
+    count_up: bool,
+    /// Blink within the final seconds. `0` to disable it.
+    warn_secs: u64,
+    /// Pause once by reaching this time left (if any)
+    pause_at: Option<Duration>,
+    /// Cue (`AppEvent::CountdownMinuteCue`) by reaching each whole minute left
+    minute_cue: bool,
+    /// Seconds to reset countdown after it's done (if any)
+    auto_reset_after: Option<u64>,
+    /// Ticks counted since countdown is done (to `auto_reset_after`)
+    done_ticks: u64,
+    /// Name to label countdown (if any)
+    name: Option<String>,
+    /// Durations to count down one after another (if any)
+    sequence: Option<SequenceState>,
+    /// Durations to load by pressing their number (`1` .. `9`)
+    presets: Vec<Duration>,
+    /// Start countdown at next whole second of `app_time`
+    align_to_minute: bool,
+    /// Time countdown has been requested to start at next whole second (if any)
+    aligning_since: Option<OffsetDateTime>,
+    /// Count elapsed time (MET) after countdown is done
+    met: bool,
+    app_tx: AppEventTx,
 }
 
 impl CountdownState {
@@ -59,16 +181,38 @@ impl CountdownState {
             target_time_format: app_time_format,
             app_tx,
             vim_motions,
+            autostart,
+            done_text,
+            target,
+            count_up,
+            warn_secs,
+            pause_at,
+            minute_cue,
+            auto_reset_after,
+            name,
+            sequence,
+            presets,
+            align_to_minute,
+            met,
         } = args;
 
+        let mut clock = ClockState::<clock::Countdown>::new(ClockStateArgs {
+            initial_value,
+            current_value,
+            tick_value: Duration::from_millis(TICK_VALUE_MS),
+            with_decis,
+            app_tx: Some(app_tx.clone()),
+        });
+        if let Some(name) = &name {
+            clock.set_name(ClockName::from(name.clone()));
+        }
+        // start immediately w/o any delay
+        if autostart == Some(Duration::ZERO) && !clock.is_done() {
+            clock.run();
+        }
+
         Self {
-            clock: ClockState::<clock::Countdown>::new(ClockStateArgs {
-                initial_value,
-                current_value,
-                tick_value: Duration::from_millis(TICK_VALUE_MS),
-                with_decis,
-                app_tx: Some(app_tx.clone()),
-            }),
+            clock,
             elapsed_clock: ClockState::<clock::Timer>::new(ClockStateArgs {
                 initial_value: Duration::ZERO,
                 current_value: elapsed_value,
@@ -89,7 +233,41 @@ impl CountdownState {
             target_time_format: app_time_format,
             target_time: OffsetDateTime::from(app_time),
             edit_time: None,
+            target_anchor: target,
             vim_motions,
+            preroll: autostart
+                .filter(|delay| !delay.is_zero())
+                .map(DurationEx::from),
+            done_text,
+            count_up,
+            warn_secs,
+            pause_at: pause_at.filter(|value| !value.is_zero()),
+            minute_cue,
+            auto_reset_after: auto_reset_after.filter(|secs| *secs > 0),
+            done_ticks: 0,
+            name,
+            sequence,
+            presets,
+            align_to_minute,
+            aligning_since: None,
+            met,
+            app_tx,
+        }
+    }
+
+    pub fn is_preroll(&self) -> bool {
+        self.preroll.is_some()
+    }
+
+    fn tick_preroll(&mut self) {
+        if let Some(preroll) = self.preroll {
+            let preroll = preroll.saturating_sub(Duration::from_millis(TICK_VALUE_MS).into());
+            if Duration::from(preroll).is_zero() {
+                self.preroll = None;
+                self.clock.run();
+            } else {
+                self.preroll = Some(preroll);
+            }
         }
     }
 
@@ -106,12 +284,241 @@ impl CountdownState {
         self.clock.is_running() || self.elapsed_clock.is_running()
     }
 
+    /// Whether any clock (incl. pre-roll) will be changed by next `Tick`
+    pub fn is_ticking(&self) -> bool {
+        self.is_preroll()
+            || self.is_aligning()
+            || self.clock.is_ticking()
+            || self.elapsed_clock.is_ticking()
+            // pending auto reset
+            || (self.clock.is_done() && self.auto_reset_after.is_some())
+    }
+
+    /// Runs or pauses countdown explicitly, it skips a pre-roll (if any).
+    /// Pausing releases countdown from its target (if any).
+    pub fn set_running(&mut self, running: bool) {
+        self.preroll = None;
+        self.aligning_since = None;
+        self.clock.set_running(running);
+        if !running {
+            self.target_anchor = None;
+        }
+    }
+
+    pub fn is_count_up(&self) -> bool {
+        self.count_up
+    }
+
+    pub fn toggle_count_up(&mut self) {
+        self.count_up = !self.count_up;
+    }
+
+    /// Sets max. value of countdown clock (but not of the elapsed clock)
+    pub fn set_max_value(&mut self, value: Duration) {
+        self.clock.set_max_value(value);
+    }
+
+    pub fn set_jump_step(&mut self, step: u32) {
+        self.clock.set_jump_step(step);
+    }
+
+    pub fn get_warn_secs(&self) -> u64 {
+        self.warn_secs
+    }
+
+    pub fn get_pause_at(&self) -> Option<Duration> {
+        self.pause_at
+    }
+
+    pub fn is_minute_cue(&self) -> bool {
+        self.minute_cue
+    }
+
+    pub fn get_auto_reset_after(&self) -> Option<u64> {
+        self.auto_reset_after
+    }
+
+    /// Whether a countdown has been done long enough to reset it (see `auto_reset_after`)
+    fn should_auto_reset(&self) -> bool {
+        self.auto_reset_after.is_some_and(|secs| {
+            Duration::from_millis(self.done_ticks.saturating_mul(TICK_VALUE_MS))
+                >= Duration::from_secs(secs)
+        })
+    }
+
+    pub fn get_presets(&self) -> &[Duration] {
+        &self.presets
+    }
+
+    /// Loads preset of given index as initial value and resets clocks
+    fn load_preset(&mut self, index: usize) {
+        if let Some(preset) = self.presets.get(index) {
+            self.clock.set_initial_value(DurationEx::from(*preset));
+            self.sequence = None;
+            self.reset();
+        }
+    }
+
+    /// Resets both clocks to their initial values and restarts sequence (if any)
+    fn reset(&mut self) {
+        // restart sequence (if any) from its first step
+        if let Some(sequence) = &mut self.sequence {
+            self.clock.set_initial_value(sequence.restart().into());
+        }
+        // reset both clocks to use intial values
+        self.clock.reset();
+        self.elapsed_clock.reset();
+        self.target_anchor = None;
+        self.aligning_since = None;
+
+        // reset `edit_time` back initial value
+        let time = self.time_to_edit();
+        if let Some(edit_time) = &mut self.edit_time {
+            edit_time.set_time(time);
+        }
+    }
+
+    /// Cues by crossing a whole minute from `before` to current value (if `minute_cue` is enabled).
+    /// Minutes are rounded up to cue by reaching e.g. `9:00`, but not by starting at `10:00`.
+    fn check_minute_cue(&mut self, before: Duration) {
+        let minutes = |value: Duration| value.as_millis().div_ceil(ONE_MINUTE.as_millis());
+        let current = Duration::from(*self.clock.get_current_value());
+        if self.minute_cue
+            && self.clock.is_running()
+            && minutes(before) != minutes(current)
+            && !current.is_zero()
+        {
+            _ = self.app_tx.send(AppEvent::CountdownMinuteCue(
+                self.clock.get_name_or_default(),
+                current,
+            ));
+        }
+    }
+
+    /// Toggles pause of `clock`. Pausing releases countdown from its target (if any).
+    fn toggle_pause(&mut self) {
+        self.clock.toggle_pause();
+        if !self.clock.is_running() {
+            self.target_anchor = None;
+        }
+    }
+
+    /// Pauses a running clock once by crossing `pause_at` (if any) from `before` to current value
+    fn check_pause_at(&mut self, before: Duration) {
+        let current = Duration::from(*self.clock.get_current_value());
+        if let Some(pause_at) = self.pause_at
+            && self.clock.is_running()
+            && before > pause_at
+            && current <= pause_at
+            && !current.is_zero()
+        {
+            self.toggle_pause();
+            _ = self.app_tx.send(AppEvent::CountdownPausedAt(
+                self.clock.get_name_or_default(),
+                current,
+            ));
+        }
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Name to label countdown with, `Countdown` by default
+    pub fn get_label_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("Countdown")
+    }
+
+    pub fn get_sequence(&self) -> Option<&SequenceState> {
+        self.sequence.as_ref()
+    }
+
+    /// Continues with next step of a sequence (if any) by running clock again
+    fn next_step(&mut self) {
+        if let Some(duration) = self.sequence.as_mut().and_then(SequenceState::advance) {
+            self.clock.set_initial_value(duration.into());
+            self.clock.reset();
+            self.clock.run();
+            self.target_anchor = None;
+        }
+    }
+
+    pub fn get_done_text(&self) -> Option<&str> {
+        self.done_text.as_deref()
+    }
+
+    /// Absolute time to finish (if anchored on it)
+    pub fn get_target(&self) -> Option<OffsetDateTime> {
+        self.target_anchor
+    }
+
+    pub fn get_target_time_format(&self) -> Option<AppTimeFormat> {
+        self.target_time_format
+    }
+
+    /// Whether to hide digits to blink within the final `warn_secs`.
+    /// Blinking is driven by the time left (similar to `done` blinking driven by `done_count`).
+    fn should_warn_blink(&self) -> bool {
+        let current = Duration::from(*self.clock.get_current_value());
+        self.warn_secs > 0
+            && self.clock.is_running()
+            && current <= Duration::from_secs(self.warn_secs)
+            && clock::should_blink(Some(
+                (current.as_millis() / u128::from(TICK_VALUE_MS)) as u64,
+            ))
+    }
+
     pub fn get_elapsed_value(&self) -> &DurationEx {
         self.elapsed_clock.get_current_value()
     }
 
     pub fn set_app_time(&mut self, app_time: AppTime) {
         self.app_time = app_time;
+        // start at first whole second after start has been requested
+        if let Some(since) = self.aligning_since
+            && OffsetDateTime::from(app_time).unix_timestamp() > since.unix_timestamp()
+        {
+            self.aligning_since = None;
+            self.clock.run();
+        }
+    }
+
+    pub fn is_align_to_minute(&self) -> bool {
+        self.align_to_minute
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.met
+    }
+
+    /// Whether elapsed time (MET) of a done countdown is paused
+    pub fn is_met_paused(&self) -> bool {
+        self.clock.is_done() && self.met && *self.elapsed_clock.get_mode() == ClockMode::Pause
+    }
+
+    /// Pauses or resumes elapsed time (MET) of a done countdown.
+    /// Note: A not started elapsed clock is paused, too,
+    /// to prevent it from being started by next tick.
+    pub fn toggle_met_pause(&mut self) {
+        if !self.clock.is_done() || !self.met {
+            return;
+        }
+        if self.elapsed_clock.is_running() || self.elapsed_clock.is_initial() {
+            self.elapsed_clock.pause();
+        } else {
+            self.elapsed_clock.run();
+        }
+    }
+
+    pub fn set_met(&mut self, met: bool) {
+        self.met = met;
+        if !met {
+            self.elapsed_clock.reset();
+        }
+    }
+
+    pub fn is_aligning(&self) -> bool {
+        self.aligning_since.is_some()
     }
 
     pub fn set_app_time_format(&mut self, value: Option<AppTimeFormat>) {
@@ -132,23 +539,42 @@ impl CountdownState {
     }
 
     fn max_time_to_edit(&self) -> OffsetDateTime {
-        OffsetDateTime::from(self.app_time)
-            .saturating_add(time::Duration::try_from(MAX_DURATION).unwrap_or(time::Duration::ZERO))
+        OffsetDateTime::from(self.app_time).saturating_add(
+            time::Duration::try_from(Duration::from(*self.clock.get_max_value()))
+                .unwrap_or(time::Duration::ZERO),
+        )
     }
 
     fn edit_time_done(&mut self, edit_time: &mut EditTimeState) {
+        let target = match self.app_time {
+            AppTime::Local(_) => with_local_offset(*edit_time.get_time()),
+            AppTime::Utc(_) => *edit_time.get_time(),
+        };
         // get diff
-        let d: time::Duration = edit_time
-            .get_time()
-            .sub(OffsetDateTime::from(self.app_time));
+        let d: time::Duration = target.sub(OffsetDateTime::from(self.app_time));
         // transfrom
         let dx: DurationEx = Duration::try_from(d).unwrap_or(Duration::ZERO).into();
         // update clock
         self.clock.set_current_value(dx);
+        // anchor countdown on target
+        self.target_anchor = Some(target);
         // remove `edit_time`
         self.edit_time = None;
     }
 
+    /// Syncs (running) countdown with given `target` by ticking it as often as needed.
+    fn tick_to_target(&mut self, target: OffsetDateTime) {
+        let left = Duration::try_from(target.sub(OffsetDateTime::from(self.app_time)))
+            .unwrap_or(Duration::ZERO);
+        let current: Duration = (*self.clock.get_current_value()).into();
+        let drift = current.saturating_sub(left);
+        let tick = Duration::from_millis(TICK_VALUE_MS);
+        // round up to next full tick to reach `0` at the end
+        let ticks = drift.as_nanos().div_ceil(tick.as_nanos());
+        self.clock
+            .advance(tick.saturating_mul(u32::try_from(ticks).unwrap_or(u32::MAX)));
+    }
+
     pub fn is_clock_edit_mode(&self) -> bool {
         self.clock.is_edit_mode()
     }
@@ -161,15 +587,32 @@ impl CountdownState {
 impl TuiEventHandler for CountdownState {
     fn update(&mut self, event: TuiEvent) -> Option<TuiEvent> {
         match event {
+            TuiEvent::Tick if self.is_preroll() => self.tick_preroll(),
             TuiEvent::Tick => {
                 if !self.clock.is_done() {
-                    self.clock.tick();
+                    self.done_ticks = 0;
+                    let before = Duration::from(*self.clock.get_current_value());
+                    match self.target_anchor {
+                        Some(target) if self.clock.is_running() => self.tick_to_target(target),
+                        _ => self.clock.tick(),
+                    }
+                    if self.clock.is_done() {
+                        self.next_step();
+                    } else {
+                        self.check_minute_cue(before);
+                        self.check_pause_at(before);
+                    }
                     self.target_time = self.time_to_edit();
                 } else {
                     self.clock.update_done_count();
-                    self.elapsed_clock.tick();
-                    if self.elapsed_clock.is_initial() {
-                        self.elapsed_clock.run();
+                    self.done_ticks += 1;
+                    if self.should_auto_reset() {
+                        self.reset();
+                    } else if self.met {
+                        self.elapsed_clock.tick();
+                        if self.elapsed_clock.is_initial() {
+                            self.elapsed_clock.run();
+                        }
                     }
                 }
                 let min_time = self.min_time_to_edit();
@@ -336,25 +779,58 @@ impl TuiEventHandler for CountdownState {
                     _ => return Some(event),
                 }
             }
+            // EDIT CLOCK mode: change value by scrolling
+            TuiEvent::Mouse(mouse) if self.is_clock_edit_mode() => match mouse.kind {
+                MouseEventKind::ScrollUp => self.clock.edit_up(),
+                MouseEventKind::ScrollDown => self.clock.edit_down(),
+                _ => return Some(event),
+            },
+            // EDIT LOCAL TIME mode: change value by scrolling
+            TuiEvent::Mouse(mouse) if self.is_time_edit_mode() => match mouse.kind {
+                // safe unwrap because of previous check in `is_time_edit_mode`
+                MouseEventKind::ScrollUp => self.edit_time.as_mut().unwrap().up(),
+                MouseEventKind::ScrollDown => self.edit_time.as_mut().unwrap().down(),
+                _ => return Some(event),
+            },
+            // any key cancels "get ready" pre-roll
+            TuiEvent::Crossterm(CrosstermEvent::Key(_)) if self.is_preroll() => {
+                self.preroll = None;
+                return self.update(event);
+            }
             // default mode
             TuiEvent::Crossterm(CrosstermEvent::Key(key)) => match key.code {
-                KeyCode::Char('r') => {
-                    // reset both clocks to use intial values
-                    self.clock.reset();
+                // reset elapsed clock only to measure overtime from now,
+                // `clock` is still done
+                KeyCode::Char('R') if self.clock.is_done() => {
                     self.elapsed_clock.reset();
-
-                    // reset `edit_time` back initial value
-                    let time = self.time_to_edit();
-                    if let Some(edit_time) = &mut self.edit_time {
-                        edit_time.set_time(time);
-                    }
+                }
+                // pause / resume elapsed clock only, `clock` is still done
+                KeyCode::Char('s') if self.clock.is_done() => {
+                    self.toggle_met_pause();
+                }
+                KeyCode::Char('r') => self.reset(),
+                // load preset by its number (instead of switching screens)
+                KeyCode::Char(c @ '1'..='9')
+                    if usize::from(c as u8 - b'1') < self.presets.len() =>
+                {
+                    self.load_preset(usize::from(c as u8 - b'1'));
                 }
                 KeyCode::Char(' ') => {
                     // toggle pause status depending on which clock is running
-                    if !self.clock.is_done() {
-                        self.clock.toggle_pause();
+                    if self.is_aligning() {
+                        // cancel a pending start
+                        self.aligning_since = None;
+                    } else if self.align_to_minute
+                        && !self.clock.is_done()
+                        && !self.clock.is_running()
+                        && !self.clock.is_edit_mode()
+                    {
+                        // wait for next whole second to start
+                        self.aligning_since = Some(OffsetDateTime::from(self.app_time));
+                    } else if !self.clock.is_done() {
+                        self.toggle_pause();
                     } else {
-                        self.elapsed_clock.toggle_pause();
+                        self.toggle_met_pause();
                     }
 
                     // finish `edit_time` and continue for using `clock`
@@ -362,6 +838,10 @@ impl TuiEventHandler for CountdownState {
                         self.edit_time_done(edit_time);
                     }
                 }
+                // toggle rendering passed time vs. time left
+                KeyCode::Char('u') => {
+                    self.toggle_count_up();
+                }
                 // Enter edit by local time mode
                 KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     // set `edit_time`
@@ -380,6 +860,7 @@ impl TuiEventHandler for CountdownState {
                 KeyCode::Char('e') => {
                     // toggle edit mode
                     self.clock.toggle_edit();
+                    self.target_anchor = None;
 
                     // pause `elapsed_clock`
                     if self.elapsed_clock.is_running() {
@@ -397,6 +878,28 @@ impl TuiEventHandler for CountdownState {
 pub struct Countdown {
     pub style: Style,
     pub blink: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
+    /// Change color of digits depending on time left
+    pub urgency_colors: bool,
+    /// Renders labels of years (`Y`) and days (`D`)
+    pub unit_labels: bool,
+    /// Renders a line of time left, time to finish and percentage done below label
+    pub status_line: bool,
+    /// Format of time to finish in status line
+    pub app_time_format: AppTimeFormat,
+    /// Space between digits and around colons
+    pub spacing: Spacing,
+    /// Hides seconds of values exceeding it
+    pub hide_seconds_after: Option<Duration>,
+    /// Appends initial value to label, e.g. `COUNTDOWN > / 10:00`
+    pub show_initial: bool,
+    /// Renders seconds only (up to `999`), e.g. `90` instead of `1:30`
+    pub seconds_only: bool,
+    /// Renders a `BlockBar` below label
+    pub bar: bool,
+    /// Dims digits (`--dim`)
+    pub dim: bool,
 }
 
 fn human_days_diff(a: &OffsetDateTime, b: &OffsetDateTime) -> String {
@@ -411,11 +914,44 @@ fn human_days_diff(a: &OffsetDateTime, b: &OffsetDateTime) -> String {
 impl StatefulWidget for Countdown {
     type State = CountdownState;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        // render `edit_time` OR `clock`
-        if let Some(edit_time) = &mut state.edit_time {
+        // render "get ready" pre-roll OR `edit_time` OR `clock`
+        if let Some(preroll) = state.preroll {
+            // round up to show `3-2-1` (but not `2-1-0`)
+            let secs = DurationEx::from(Duration::from_secs(
+                Duration::from(preroll).as_millis().div_ceil(1000) as u64,
+            ));
+            let format = clock::format_by_duration(&secs);
+            let widths =
+                clock::clock_horizontal_lengths(&format, false, self.unit_labels, self.spacing);
+            let label = Line::raw("Get ready".to_uppercase());
+            let area = area.centered(
+                Constraint::Length(max(widths.iter().sum(), label.width() as u16)),
+                Constraint::Length(DIGIT_HEIGHT + 3),
+            );
+            let [_, v1, v2] =
+                Layout::vertical(Constraint::from_lengths([1, DIGIT_HEIGHT, 1])).areas(area);
+            clock::render_clock(
+                v1,
+                buf,
+                clock::RenderClockState {
+                    format,
+                    editable_time: None,
+                    with_decis: false,
+                    decimal_separator: self.decimal_separator,
+                    flip: self.flip,
+                    symbol: self.style.get_digit_symbol(),
+                    widths,
+                    duration: secs,
+                    with_labels: self.unit_labels,
+                    spacing: self.spacing,
+                },
+            );
+            label.centered().render(v2, buf);
+        } else if let Some(edit_time) = &state.edit_time {
             let label = Line::raw(
                 format!(
-                    "Countdown {} {}",
+                    "{} {} {}",
+                    state.get_label_name(),
                     edit_time.get_selected().clone(),
                     human_days_diff(edit_time.get_time(), &state.app_time.into())
                 )
@@ -432,33 +968,77 @@ impl StatefulWidget for Countdown {
             let [v1, v2] =
                 Layout::vertical(Constraint::from_lengths([widget.get_height(), 1])).areas(area);
 
-            widget.render(v1, buf, edit_time);
+            if let Some(edit_time) = &mut state.edit_time {
+                widget.render(v1, buf, edit_time);
+            }
             label.centered().render(v2, buf);
         } else {
-            let label = Line::raw(
-                if state.clock.is_done() {
+            let mut label = Line::raw(
+                if state.clock.is_done() && !state.met {
+                    format!("{} {}", state.get_label_name(), state.clock.get_mode())
+                } else if state.clock.is_done() {
                     if state.clock.with_decis {
                         format!(
-                            "Countdown {} +{}",
+                            "{} {} +{}",
+                            state.get_label_name(),
                             state.clock.get_mode(),
                             state
                                 .elapsed_clock
                                 .get_current_value()
-                                .to_string_with_decis()
+                                .to_string_with_separator(self.decimal_separator.as_char())
                         )
                     } else {
                         format!(
-                            "Countdown {} +{}",
+                            "{} {} +{}",
+                            state.get_label_name(),
                             state.clock.get_mode(),
                             state.elapsed_clock.get_current_value()
                         )
                     }
+                } else if state.count_up {
+                    format!(
+                        "{} {} elapsed",
+                        state.get_label_name(),
+                        state.clock.get_mode()
+                    )
                 } else {
-                    format!("Countdown {}", state.clock.get_mode())
+                    format!("{} {}", state.get_label_name(), state.clock.get_mode())
                 }
                 .to_uppercase(),
             );
-            let widget = ClockWidget::new(self.style, self.blink);
+            if state.is_met_paused() {
+                label.push_span(format!(" MET {}", state.elapsed_clock.get_mode()));
+            }
+            if let Some(sequence) = &state.sequence {
+                label.push_span(format!(" {sequence}").to_uppercase());
+            }
+            if self.show_initial {
+                label.push_span(format!(" / {}", state.clock.get_initial_value()));
+            }
+            let widget =
+                ClockWidget::new(self.style, self.blink, self.decimal_separator, self.flip)
+                    .count_up(state.count_up)
+                    .unit_labels(self.unit_labels)
+                    .spacing(self.spacing)
+                    .hide_seconds_after(self.hide_seconds_after)
+                    .seconds_only(self.seconds_only)
+                    .dim(self.dim)
+                    .hidden(state.should_warn_blink())
+                    .color(
+                        self.urgency_colors
+                            .then(|| clock::urgency_color(state.clock.get_percentage_done())),
+                    );
+            // To simulate a blink effect, just use an "empty" symbol (string)
+            let symbol = if self.blink && clock::should_blink(state.clock.get_done_count()) {
+                " "
+            } else {
+                self.style.get_digit_symbol()
+            };
+            let done_text = state
+                .done_text
+                .as_deref()
+                .filter(|_| state.clock.is_done())
+                .map(|text| Word::new(text, symbol).flip(self.flip));
             let label_target_time = Line::raw(
                 if let Some(tf) = state.target_time_format
                     // hide target time if we edit by time - no duplication of information then
@@ -471,27 +1051,88 @@ impl StatefulWidget for Countdown {
                 .to_uppercase(),
             );
 
+            let label_status = self.status_line.then(|| {
+                Line::raw(
+                    state
+                        .clock
+                        .get_status_line(state.app_time, &self.app_time_format)
+                        .to_uppercase(),
+                )
+            });
+            let label_status_height = label_status.as_ref().map_or(0, |_| 1);
+            let bar_height = if self.bar { 1 } else { 0 };
+
             let area = area.centered(
                 Constraint::Length(max(
                     max(
-                        widget.get_width(state.clock.get_format(), state.clock.with_decis),
+                        match &done_text {
+                            Some(word) => word.get_width(),
+                            None => widget.get_width(&state.clock),
+                        },
                         label.width() as u16,
                     ),
-                    label_target_time.width() as u16,
+                    max(
+                        label_target_time.width() as u16,
+                        label_status.as_ref().map_or(0, |l| l.width() as u16),
+                    ),
                 )),
                 Constraint::Length(
                     // 3 = heights of empty label + `label` + `label_target_time`
-                    widget.get_height() + 3,
+                    widget.get_height() + 3 + bar_height + label_status_height,
                 ),
             );
-            let [v0, v1, v2, v3] =
-                Layout::vertical(Constraint::from_lengths([1, widget.get_height(), 1, 1]))
-                    .areas(area);
+            let [v0, v1, v2, v_bar, v_status, v3] = Layout::vertical(Constraint::from_lengths([
+                1,
+                widget.get_height(),
+                1,
+                bar_height,
+                label_status_height,
+                1,
+            ]))
+            .areas(area);
 
             Line::raw("").centered().render(v0, buf);
-            widget.render(v1, buf, &mut state.clock);
+            match done_text {
+                Some(word) => {
+                    let v1 = v1.centered_horizontally(Constraint::Length(word.get_width()));
+                    word.render(v1, buf);
+                }
+                None => widget.render(v1, buf, &mut state.clock),
+            }
             label.centered().render(v2, buf);
+            if self.bar {
+                BlockBar::new(&state.clock).render(v_bar, buf);
+            }
+            if let Some(label_status) = label_status {
+                label_status.centered().render(v_status, buf);
+            }
             label_target_time.centered().render(v3, buf);
         }
     }
 }
+
+/// List of countdowns, a line for each: name, time left and mode
+pub struct CountdownList {
+    pub decimal_separator: DecimalSeparator,
+}
+
+impl StatefulWidget for CountdownList {
+    type State = VecDeque<CountdownState>;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let rows = Layout::vertical(vec![Constraint::Length(1); state.len()]).split(area);
+        for (countdown, row) in state.iter().zip(rows.iter()) {
+            Line::raw(
+                format!(
+                    "{} {}",
+                    countdown.get_label_name(),
+                    countdown
+                        .clock
+                        .to_string_with_separator(self.decimal_separator)
+                )
+                .to_lowercase(),
+            )
+            .centered()
+            .render(*row, buf);
+        }
+    }
+}
@@ -1,26 +1,35 @@
 use crate::{
-    common::{AppTime, AppTimeFormat, Style},
+    common::{AppTime, AppTimeFormat, DecimalSeparator, Spacing, Style},
     duration::{ONE_MINUTE, ONE_SECOND},
-    events::TuiEventHandler,
+    events::{AppEvent, TuiEvent, TuiEventHandler},
     widgets::{
-        countdown::{Countdown, CountdownState, CountdownStateArgs},
-        test_utils::{DrawArgs, FIXED_TIME, Key, draw},
+        countdown::{Countdown, CountdownList, CountdownState, CountdownStateArgs, SequenceState},
+        test_utils::{DrawArgs, FIXED_TIME, Key, app_channel, app_tx, draw, lines},
     },
 };
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 use insta::assert_snapshot;
-use ratatui::{Terminal, backend::TestBackend};
+use ratatui::{Terminal, backend::TestBackend, style::Modifier};
 use std::time::Duration;
 
 const INITIAL: Duration = ONE_MINUTE.saturating_mul(30);
 
-fn app_tx() -> crate::events::AppEventTx {
-    tokio::sync::mpsc::unbounded_channel().0
-}
-
 fn w() -> Countdown {
     Countdown {
         style: Style::default(),
         blink: false,
+        decimal_separator: DecimalSeparator::default(),
+        flip: None,
+        unit_labels: true,
+        urgency_colors: false,
+        status_line: false,
+        app_time_format: AppTimeFormat::default(),
+        spacing: Spacing::default(),
+        hide_seconds_after: None,
+        show_initial: false,
+        seconds_only: false,
+        bar: false,
+        dim: false,
     }
 }
 
@@ -34,6 +43,19 @@ fn args() -> CountdownStateArgs {
         with_decis: false,
         app_tx: app_tx(),
         vim_motions: false,
+        autostart: None,
+        done_text: None,
+        target: None,
+        count_up: false,
+        warn_secs: 0,
+        pause_at: None,
+        minute_cue: false,
+        auto_reset_after: None,
+        name: None,
+        sequence: None,
+        presets: vec![],
+        align_to_minute: false,
+        met: true,
     }
 }
 
@@ -94,6 +116,96 @@ fn test_countdown_done() {
     assert_snapshot!("countdown_done", t.backend());
 }
 
+#[test]
+fn test_countdown_reset_elapsed() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        elapsed_value: ONE_SECOND.saturating_mul(2),
+        ..args()
+    });
+    st.update(key(KeyCode::Char('R'), KeyModifiers::SHIFT));
+    assert_eq!(Duration::from(*st.get_elapsed_value()), Duration::ZERO);
+    assert!(st.get_clock().is_done());
+}
+
+#[test]
+fn test_countdown_pause_met() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        ..args()
+    });
+    st.update(TuiEvent::Tick);
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    assert!(st.is_met_paused());
+    let elapsed = *st.get_elapsed_value();
+    for _ in 0..20 {
+        st.update(TuiEvent::Tick);
+    }
+    assert_eq!(*st.get_elapsed_value(), elapsed);
+    assert!(st.get_clock().is_done());
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_met_paused", t.backend());
+}
+
+#[test]
+fn test_countdown_resume_met() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        ..args()
+    });
+    st.update(TuiEvent::Tick);
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    assert!(!st.is_met_paused());
+    let elapsed = *st.get_elapsed_value();
+    st.update(TuiEvent::Tick);
+    assert!(*st.get_elapsed_value() > elapsed);
+}
+
+#[test]
+fn test_countdown_pause_met_before_first_tick() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        ..args()
+    });
+    // elapsed clock has not been started yet
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    st.update(TuiEvent::Tick);
+    assert!(st.is_met_paused());
+    assert_eq!(Duration::from(*st.get_elapsed_value()), Duration::ZERO);
+}
+
+#[test]
+fn test_countdown_pause_met_ignored() {
+    // not done
+    let mut st = st();
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    assert!(!st.is_met_paused());
+    // done, but MET disabled
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        met: false,
+        ..args()
+    });
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    assert!(!st.is_met_paused());
+}
+
+#[test]
+fn test_countdown_no_met() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        met: false,
+        ..args()
+    });
+    for _ in 0..20 {
+        st.update(TuiEvent::Tick);
+    }
+    assert_eq!(Duration::from(*st.get_elapsed_value()), Duration::ZERO);
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_no_met", t.backend());
+}
+
 #[test]
 fn test_countdown_edit_minutes() {
     let mut st = st();
@@ -142,3 +254,597 @@ fn test_countdown_until_hh12mm() {
     let t = terminal(w(), st);
     assert_snapshot!("countdown_until_hh12mm", t.backend());
 }
+
+#[test]
+fn test_countdown_autostart() {
+    let st = st_with_args(CountdownStateArgs {
+        autostart: Some(Duration::ZERO),
+        ..args()
+    });
+    assert!(st.is_running());
+    assert!(!st.is_preroll());
+}
+
+#[test]
+fn test_countdown_autostart_delay() {
+    let mut st = st_with_args(CountdownStateArgs {
+        autostart: Some(ONE_SECOND.saturating_mul(3)),
+        ..args()
+    });
+    assert!(st.is_preroll());
+    st.update(TuiEvent::Tick);
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_autostart_delay", t.backend());
+}
+
+#[test]
+fn test_countdown_autostart_delay_done() {
+    let mut st = st_with_args(CountdownStateArgs {
+        autostart: Some(ONE_SECOND),
+        ..args()
+    });
+    for _ in 0..10 {
+        st.update(TuiEvent::Tick);
+    }
+    assert!(!st.is_preroll());
+    assert!(st.is_running());
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> TuiEvent {
+    TuiEvent::Crossterm(Event::Key(KeyEvent::new(code, modifiers)))
+}
+
+/// Edit by local time and apply it
+fn edit_by_time(st: &mut CountdownState) {
+    st.update(key(KeyCode::Char('e'), KeyModifiers::CONTROL));
+    st.update(key(KeyCode::Char('s'), KeyModifiers::NONE));
+}
+
+#[test]
+fn test_countdown_target_anchor() {
+    let mut st = st();
+    edit_by_time(&mut st);
+    st.update(Key::StartStop.into());
+    assert!(st.is_running());
+    // tick once after (missing) 10min
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE * 10));
+    st.update(TuiEvent::Tick);
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        INITIAL - ONE_MINUTE * 10
+    );
+    // reaching target
+    st.set_app_time(AppTime::Utc(FIXED_TIME + INITIAL));
+    st.update(TuiEvent::Tick);
+    assert!(st.get_clock().is_done());
+}
+
+#[test]
+fn test_countdown_target_anchor_pause() {
+    let mut st = st();
+    edit_by_time(&mut st);
+    st.update(Key::StartStop.into());
+    // pause releases target
+    st.update(Key::StartStop.into());
+    st.update(Key::StartStop.into());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE * 10));
+    st.update(TuiEvent::Tick);
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        INITIAL - ONE_SECOND / 10
+    );
+}
+
+#[test]
+fn test_countdown_target_anchor_set_running() {
+    let mut st = st();
+    edit_by_time(&mut st);
+    st.set_running(true);
+    // pausing explicitly releases target, too
+    st.set_running(false);
+    st.set_running(true);
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE * 10));
+    st.update(TuiEvent::Tick);
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        INITIAL - ONE_SECOND / 10
+    );
+}
+
+#[test]
+fn test_countdown_dim() {
+    let is_dim = |dim: bool| {
+        let t = terminal(Countdown { dim, ..w() }, st());
+        t.backend()
+            .buffer()
+            .content()
+            .iter()
+            .filter(|cell| cell.symbol() != " ")
+            .any(|cell| cell.modifier.contains(Modifier::DIM))
+    };
+    assert!(is_dim(true));
+    assert!(!is_dim(false));
+}
+
+#[test]
+fn test_countdown_done_text() {
+    let st = st_with_args(CountdownStateArgs {
+        current_value: Duration::ZERO,
+        elapsed_value: ONE_SECOND.saturating_mul(2),
+        done_text: Some("DONE".into()),
+        ..args()
+    });
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_done_text", t.backend());
+}
+
+#[test]
+fn test_countdown_count_up() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: INITIAL - ONE_MINUTE.saturating_mul(5),
+        count_up: true,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_count_up", t.backend());
+}
+
+#[test]
+fn test_countdown_toggle_count_up() {
+    let mut st = st();
+    st.update(key(KeyCode::Char('u'), KeyModifiers::NONE));
+    assert!(st.is_count_up());
+    st.update(key(KeyCode::Char('u'), KeyModifiers::NONE));
+    assert!(!st.is_count_up());
+}
+
+#[test]
+fn test_countdown_urgency_colors() {
+    use ratatui::style::Color;
+    let st = st_with_args(CountdownStateArgs {
+        current_value: INITIAL / 4,
+        ..args()
+    });
+    let t = terminal(
+        Countdown {
+            urgency_colors: true,
+            ..w()
+        },
+        st,
+    );
+    // 75% done
+    let cell = t
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .find(|c| c.symbol() == "█")
+        .unwrap();
+    assert_eq!(cell.fg, Color::Rgb(255, 127, 0));
+}
+
+#[test]
+fn test_countdown_warn_secs() {
+    let has_digits = |st: CountdownState| {
+        let t = terminal(w(), st);
+        t.backend()
+            .buffer()
+            .content()
+            .iter()
+            .any(|c| c.symbol() == "█")
+    };
+    let st_running = |current_value: Duration| {
+        let mut st = st_with_args(CountdownStateArgs {
+            current_value,
+            warn_secs: 10,
+            ..args()
+        });
+        st.update(Key::StartStop.into());
+        st
+    };
+    // not within final seconds
+    assert!(has_digits(st_running(ONE_SECOND * 20)));
+    // within final seconds: hidden (blink on) ...
+    assert!(!has_digits(st_running(ONE_SECOND * 8)));
+    // ... and visible (blink off)
+    assert!(has_digits(st_running(ONE_SECOND * 8 + ONE_SECOND / 2)));
+    // no blinking if paused
+    let mut st = st_running(ONE_SECOND * 8);
+    st.update(Key::StartStop.into());
+    assert!(has_digits(st));
+}
+
+#[test]
+fn test_countdown_name() {
+    let st = st_with_args(CountdownStateArgs {
+        name: Some("Tea".into()),
+        ..args()
+    });
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_name", t.backend());
+}
+
+#[test]
+fn test_countdown_list() {
+    let mut running = st_with_args(CountdownStateArgs {
+        current_value: ONE_MINUTE * 5,
+        name: Some("Tea".into()),
+        ..args()
+    });
+    running.update(Key::StartStop.into());
+    let t = draw(DrawArgs {
+        widget: CountdownList {
+            decimal_separator: DecimalSeparator::default(),
+        },
+        state: [running, st()].into(),
+        width: 40,
+        height: 2,
+    });
+    assert_snapshot!("countdown_list", t.backend());
+}
+
+fn st_sequence() -> CountdownState {
+    let steps = vec![ONE_SECOND / 10, ONE_MINUTE * 5, ONE_MINUTE * 25];
+    st_with_args(CountdownStateArgs {
+        initial_value: steps[0],
+        current_value: steps[0],
+        sequence: SequenceState::new(steps, 0),
+        ..args()
+    })
+}
+
+#[test]
+fn test_countdown_sequence() {
+    let mut st = st_sequence();
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    // next step is running
+    assert!(st.is_running());
+    assert_eq!(st.get_sequence().map(SequenceState::get_index), Some(1));
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_MINUTE * 5
+    );
+    let t = terminal(w(), st);
+    assert_snapshot!("countdown_sequence", t.backend());
+}
+
+#[test]
+fn test_countdown_sequence_restart() {
+    let mut st = st_sequence();
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    st.update(key(KeyCode::Char('r'), KeyModifiers::NONE));
+    assert_eq!(st.get_sequence().map(SequenceState::get_index), Some(0));
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_SECOND / 10
+    );
+}
+
+#[test]
+fn test_sequence_state() {
+    assert!(SequenceState::new(vec![], 0).is_none());
+    let mut sequence = SequenceState::new(vec![ONE_MINUTE, ONE_SECOND], 5).unwrap();
+    // index out of range -> last step
+    assert_eq!(sequence.get_index(), 1);
+    assert_eq!(sequence.to_string(), "step 2/2");
+    assert_eq!(sequence.advance(), None);
+    assert_eq!(sequence.restart(), ONE_MINUTE);
+    assert_eq!(sequence.to_string(), "step 1/2 next 1");
+}
+
+#[test]
+fn test_countdown_align_to_minute() {
+    let mut st = st_with_args(CountdownStateArgs {
+        app_time: AppTime::Utc(FIXED_TIME + ONE_SECOND / 2),
+        align_to_minute: true,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    // waiting for next whole second
+    assert!(st.is_aligning());
+    assert!(!st.is_running());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_SECOND * 9 / 10));
+    assert!(!st.is_running());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_SECOND));
+    assert!(!st.is_aligning());
+    assert!(st.is_running());
+    // pausing does not need any alignment
+    st.update(Key::StartStop.into());
+    assert!(!st.is_running());
+    assert!(!st.is_aligning());
+}
+
+#[test]
+fn test_countdown_align_to_minute_cancel() {
+    let mut st = st_with_args(CountdownStateArgs {
+        align_to_minute: true,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    assert!(st.is_aligning());
+    st.update(Key::StartStop.into());
+    assert!(!st.is_aligning());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_SECOND));
+    assert!(!st.is_running());
+}
+
+#[test]
+fn test_countdown_status_line() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: INITIAL - ONE_MINUTE,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    let t = terminal(
+        Countdown {
+            status_line: true,
+            ..w()
+        },
+        st,
+    );
+    assert_snapshot!("countdown_status_line", t.backend());
+}
+
+#[test]
+fn test_countdown_show_initial() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: INITIAL - ONE_MINUTE,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    let t = terminal(
+        Countdown {
+            show_initial: true,
+            ..w()
+        },
+        st,
+    );
+    assert_snapshot!("countdown_show_initial", t.backend());
+}
+
+#[test]
+fn test_countdown_seconds_only() {
+    let widget = || Countdown {
+        seconds_only: true,
+        ..w()
+    };
+    let st = st_with_args(CountdownStateArgs {
+        current_value: ONE_SECOND.saturating_mul(90),
+        ..args()
+    });
+    let t = terminal(widget(), st);
+    assert_snapshot!("countdown_seconds_only", t.backend());
+
+    let st = st_with_args(CountdownStateArgs {
+        current_value: ONE_SECOND.saturating_mul(345),
+        ..args()
+    });
+    let t = terminal(widget(), st);
+    assert_snapshot!("countdown_seconds_only_3_digits", t.backend());
+}
+
+#[test]
+fn test_countdown_bar() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: INITIAL.saturating_mul(3) / 4,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    let t = terminal(Countdown { bar: true, ..w() }, st);
+    assert_snapshot!("countdown_bar", t.backend());
+}
+
+#[test]
+fn test_countdown_lines() {
+    let t = draw(DrawArgs {
+        widget: w(),
+        state: st_with_args(CountdownStateArgs {
+            current_value: ONE_SECOND.saturating_mul(42),
+            ..args()
+        }),
+        width: 24,
+        height: 9,
+    });
+    assert_eq!(
+        lines(&t),
+        vec![
+            "",
+            "       ██ ██ █████",
+            "       ██ ██    ██",
+            "       █████ █████",
+            "          ██ ██",
+            "          ██ █████",
+            "",
+            "      COUNTDOWN ||",
+            "",
+        ]
+    );
+}
+
+#[test]
+fn test_pause_at() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: ONE_MINUTE + ONE_SECOND / 10,
+        pause_at: Some(ONE_MINUTE),
+        app_tx: tx,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    // paused once by reaching `pause_at`
+    assert!(!st.is_running());
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_MINUTE
+    );
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(AppEvent::CountdownPausedAt(_, value)) if value == ONE_MINUTE
+    ));
+    // resumed, it keeps running
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    st.update(TuiEvent::Tick);
+    assert!(st.is_running());
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_pause_at_target_anchor() {
+    let mut st = st_with_args(CountdownStateArgs {
+        pause_at: Some(INITIAL - ONE_MINUTE * 5),
+        ..args()
+    });
+    edit_by_time(&mut st);
+    st.update(Key::StartStop.into());
+    // paused by reaching `pause_at` while counting down to target
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE * 6));
+    st.update(TuiEvent::Tick);
+    assert!(!st.is_running());
+    let paused = Duration::from(*st.get_clock().get_current_value());
+    assert_eq!(paused, INITIAL - ONE_MINUTE * 6);
+    // resumed, it's released from target (no jump)
+    st.update(Key::StartStop.into());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE * 10));
+    st.update(TuiEvent::Tick);
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        paused - ONE_SECOND / 10
+    );
+}
+
+#[test]
+fn test_pause_at_started_below() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: ONE_SECOND * 30,
+        pause_at: Some(ONE_MINUTE),
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    assert!(st.is_running());
+}
+
+#[test]
+fn test_minute_cue() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: ONE_MINUTE * 2,
+        minute_cue: true,
+        app_tx: tx,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    // no cue by starting at a whole minute
+    st.update(TuiEvent::Tick);
+    assert!(rx.try_recv().is_err());
+    // cue by reaching `1:00`
+    for _ in 0..599 {
+        st.update(TuiEvent::Tick);
+    }
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_MINUTE
+    );
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(AppEvent::CountdownMinuteCue(_, value)) if value == ONE_MINUTE
+    ));
+    assert!(rx.try_recv().is_err());
+    // no cue by reaching `0:00` (done)
+    for _ in 0..600 {
+        st.update(TuiEvent::Tick);
+    }
+    assert!(st.get_clock().is_done());
+    assert!(!matches!(
+        rx.try_recv(),
+        Ok(AppEvent::CountdownMinuteCue(..))
+    ));
+}
+
+#[test]
+fn test_auto_reset_after() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: ONE_SECOND / 10,
+        auto_reset_after: Some(2),
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    assert!(st.get_clock().is_done());
+    for _ in 0..19 {
+        st.update(TuiEvent::Tick);
+    }
+    assert!(st.get_clock().is_done());
+    // reset after 2 seconds
+    st.update(TuiEvent::Tick);
+    assert!(st.get_clock().is_initial());
+    assert!(!st.is_running());
+    assert_eq!(Duration::from(*st.get_clock().get_current_value()), INITIAL);
+}
+
+#[test]
+fn test_auto_reset_after_is_ticking() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: ONE_SECOND / 10,
+        auto_reset_after: Some(5),
+        met: false,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    assert!(st.get_clock().is_done());
+    // ticking after done blink and w/o MET, until it's reset
+    for _ in 0..49 {
+        assert!(st.is_ticking());
+        st.update(TuiEvent::Tick);
+    }
+    assert!(st.get_clock().is_done());
+    assert!(st.is_ticking());
+    st.update(TuiEvent::Tick);
+    assert!(st.get_clock().is_initial());
+    assert!(!st.is_ticking());
+}
+
+#[test]
+fn test_auto_reset_after_max() {
+    let mut st = st_with_args(CountdownStateArgs {
+        current_value: ONE_SECOND / 10,
+        auto_reset_after: Some(u64::MAX),
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    // no overflow, it's done for (almost) ever
+    st.update(TuiEvent::Tick);
+    assert!(st.get_clock().is_done());
+}
+
+#[test]
+fn test_presets() {
+    let mut st = st_with_args(CountdownStateArgs {
+        presets: vec![ONE_MINUTE * 5, ONE_MINUTE * 10],
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    // load second preset, which resets clock
+    assert!(
+        st.update(key(KeyCode::Char('2'), KeyModifiers::NONE))
+            .is_none()
+    );
+    assert!(st.get_clock().is_initial());
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_MINUTE * 10
+    );
+    // keys w/o preset are not handled (e.g. to switch screens)
+    assert!(
+        st.update(key(KeyCode::Char('3'), KeyModifiers::NONE))
+            .is_some()
+    );
+}
@@ -86,11 +86,11 @@ impl EditTimeState {
         self.max = min;
     }
 
-    pub fn get_time(&mut self) -> &OffsetDateTime {
+    pub fn get_time(&self) -> &OffsetDateTime {
         &self.time
     }
 
-    pub fn get_selected(&mut self) -> &Selected {
+    pub fn get_selected(&self) -> &Selected {
         &self.selected
     }
 
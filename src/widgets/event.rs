@@ -12,14 +12,19 @@ use tui_input::Input;
 use tui_input::backend::crossterm::EventHandler;
 
 use crate::{
-    common::{AppTime, AppTimeFormat, ClockName, ClockTypeId, Style as DigitStyle},
-    duration::CalendarDuration,
+    common::{
+        AppTime, AppTimeFormat, ClockName, ClockTypeId, DateFormat, DecimalSeparator, Flip,
+        Spacing, Style as DigitStyle,
+    },
+    duration::{CalendarDuration, ONE_DAY},
     event::Event,
     events::{AppEvent, AppEventTx, TuiEvent, TuiEventHandler},
     widgets::{clock, clock_elements::DIGIT_HEIGHT},
 };
 use std::{cmp::max, time::Duration};
 
+pub const DEFAULT_SNOOZE: Duration = ONE_DAY;
+
 #[derive(Clone, Copy, Default)]
 enum Editable {
     #[default]
@@ -66,9 +71,16 @@ pub struct EventState {
     start_time: OffsetDateTime,
     with_decis: bool,
     event_time_format: Option<AppTimeFormat>,
+    date_format: DateFormat,
+    /// Upcoming milestones after current event, sorted by time
+    milestones: Vec<Event>,
+    /// Duration to push a passed `event_time` forward by
+    snooze: Duration,
     /// counter to simulate `DONE` state
     /// Default value: `None`
     done_count: Option<u64>,
+    /// Whether `AppEvent::ClockDone` has been sent for current `event_time`
+    has_fired: bool,
     app_tx: AppEventTx,
     // inputs
     input_datetime: Input,
@@ -82,9 +94,12 @@ pub struct EventState {
 pub struct EventStateArgs {
     pub app_time: AppTime,
     pub event: Event,
+    pub milestones: Vec<Event>,
+    pub snooze: Duration,
     pub with_decis: bool,
     pub app_tx: AppEventTx,
     pub event_time_format: Option<AppTimeFormat>,
+    pub date_format: DateFormat,
 }
 
 impl EventState {
@@ -92,12 +107,28 @@ impl EventState {
         let EventStateArgs {
             app_time,
             event,
+            milestones,
+            snooze,
             with_decis,
             app_tx,
             event_time_format,
+            date_format,
         } = args;
 
         let app_datetime = OffsetDateTime::from(app_time);
+        // Count to the nearest upcoming event (or milestone).
+        // Passed ones are skipped, but the latest one to count since.
+        let mut milestones = milestones;
+        milestones.push(event);
+        milestones.sort_by_key(|e| e.date_time);
+        let now = PrimitiveDateTime::new(app_datetime.date(), app_datetime.time());
+        let upcoming = milestones
+            .iter()
+            .position(|e| e.date_time > now)
+            .unwrap_or(milestones.len() - 1);
+        let mut milestones = milestones.split_off(upcoming);
+        let event = milestones.remove(0);
+
         // assume event has as same `offset` as `app_time`
         let event_offset = event.date_time.assume_offset(app_datetime.offset());
         let input_datetime_value = format_offsetdatetime(&event_offset);
@@ -110,7 +141,11 @@ impl EventState {
             start_time: app_datetime,
             with_decis,
             event_time_format,
+            date_format,
+            milestones,
+            snooze,
             done_count: None,
+            has_fired: false,
             app_tx,
             input_datetime: Input::default().with_value(input_datetime_value),
             input_datetime_error: None,
@@ -121,13 +156,26 @@ impl EventState {
         }
     }
 
+    /// Replaces current event (milestones are kept)
+    pub fn set_event(&mut self, event: Event) {
+        let Event { date_time, title } = event;
+        self.start_time = self.app_time;
+        self.save_event_time(date_time);
+        self.title = title;
+        self.done_count = None;
+        self.reset_edit_mode();
+        self.reset_input_datetime();
+        self.reset_input_title();
+    }
+
     // Sets `app_time`
     pub fn set_app_time(&mut self, app_time: AppTime) {
+        let prev_app_time = self.app_time;
         let app_datetime = OffsetDateTime::from(app_time);
         self.app_time = app_datetime;
 
         // Since updating `app_time` is like a `Tick`, we check `done` state here
-        self.check_done();
+        self.check_done(prev_app_time);
     }
 
     pub fn set_with_decis(&mut self, with_decis: bool) {
@@ -145,21 +193,27 @@ impl EventState {
         }
     }
 
+    pub fn get_milestones(&self) -> &[Event] {
+        &self.milestones
+    }
+
     pub fn get_percentage_done(&self) -> u16 {
         get_percentage(self.start_time, self.event_time, self.app_time)
     }
 
-    pub fn get_duration(&mut self) -> CalendarDuration {
+    pub fn get_duration(&self) -> CalendarDuration {
         CalendarDuration::from_start_end_times(self.event_time, self.app_time)
     }
 
-    fn check_done(&mut self) {
+    /// Checks whether `event_time` has been reached since `prev_app_time`.
+    /// Its window is the actual interval between two updates of `app_time` (and no fixed one),
+    /// which can't be missed or spanned by multiple updates. To fire once only,
+    /// even if `app_time` goes back (e.g. by changing system time), `has_fired` is checked, too.
+    fn check_done(&mut self, prev_app_time: OffsetDateTime) {
         let clock_duration = self.get_duration();
         if clock_duration.is_since() {
-            let duration: Duration = clock_duration.into();
-            // give some offset to make sure we are around `Duration::ZERO`
-            // Without that we might miss it, because the app runs on its own FPS
-            if duration < Duration::from_millis(100) {
+            if !self.has_fired && self.event_time > prev_app_time {
+                self.has_fired = true;
                 // reset `done_count`
                 self.done_count = Some(clock::MAX_DONE_COUNT);
                 // send notification
@@ -171,7 +225,44 @@ impl EventState {
             }
             // count (possible) `done`
             self.done_count = clock::count_clock_done(self.done_count);
+            // don't switch while user is editing current event
+            if !self.is_edit_mode() {
+                self.next_milestone();
+            }
+        }
+    }
+
+    /// Whether `event_time` has been passed
+    pub fn is_done(&self) -> bool {
+        self.event_time <= self.app_time
+    }
+
+    /// Pushes a passed `event_time` forward by `snooze`, e.g. to repeat a reminder
+    fn snooze(&mut self) {
+        let event_time = self
+            .event_time
+            .saturating_add(self.snooze.try_into().unwrap_or_default());
+        self.start_time = self.app_time;
+        self.save_event_time(PrimitiveDateTime::new(event_time.date(), event_time.time()));
+        self.done_count = None;
+        self.reset_input_datetime();
+        _ = self.app_tx.send(AppEvent::Flash(format!(
+            "snoozed to {}",
+            format_offsetdatetime(&self.event_time)
+        )));
+    }
+
+    /// Switches to next milestone (if any)
+    fn next_milestone(&mut self) {
+        if self.milestones.is_empty() {
+            return;
         }
+        let Event { date_time, title } = self.milestones.remove(0);
+        self.start_time = self.event_time;
+        self.save_event_time(date_time);
+        self.title = title;
+        self.reset_input_datetime();
+        self.reset_input_title();
     }
 
     fn reset_cursor(&mut self) {
@@ -200,6 +291,8 @@ impl EventState {
         self.event_time =
             // apply offset to be in sync with `AppTime`
             date_time.assume_offset(self.app_time.offset());
+        // re-arm for new `event_time`
+        self.has_fired = false;
     }
 
     fn save_title(&mut self, value: &str) {
@@ -234,11 +327,24 @@ impl EventState {
 }
 
 fn validate_datetime(value: &str) -> Result<time::PrimitiveDateTime, Report> {
+    use time::error::{Parse, ParseFromDescription, TryFromParsed};
+
     time::PrimitiveDateTime::parse(
         value,
         format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
     )
-    .map_err(|_| eyre!("Expected format 'YYYY-MM-DD HH:MM:SS'"))
+    .map_err(|e| match e {
+        // e.g. `2025-02-30`: all components are valid on its own, but not combined
+        Parse::TryFromParsed(TryFromParsed::ComponentRange(e)) if e.name() == "day" => {
+            eyre!("Invalid day for month")
+        }
+        Parse::TryFromParsed(TryFromParsed::ComponentRange(e)) => eyre!("Invalid {}", e.name()),
+        // e.g. `2025-13-01`
+        Parse::ParseFromDescription(ParseFromDescription::InvalidComponent(name)) => {
+            eyre!("Invalid {name}. Expected format 'YYYY-MM-DD HH:MM:SS'")
+        }
+        _ => eyre!("Expected format 'YYYY-MM-DD HH:MM:SS'"),
+    })
 }
 
 const MAX_LABEL_WIDTH: usize = 60;
@@ -354,6 +460,7 @@ impl TuiEventHandler for EventState {
                 KeyCode::Char('e') => {
                     self.edit_mode = EditMode::Editing(self.last_editable);
                 }
+                KeyCode::Char('z') if self.is_done() => self.snooze(),
                 _ => return Some(event),
             },
             _ => return Some(event),
@@ -391,6 +498,18 @@ fn format_offsetdatetime(dt: &OffsetDateTime) -> String {
 pub struct EventWidget {
     pub style: DigitStyle,
     pub blink: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
+    /// Renders labels of years (`Y`) and days (`D`)
+    pub unit_labels: bool,
+    /// Renders a line of time left, time of event and percentage done below title
+    pub status_line: bool,
+    /// Format of time of event in status line
+    pub app_time_format: AppTimeFormat,
+    /// Space between digits and around colons
+    pub spacing: Spacing,
+    /// Dims digits (`--dim`)
+    pub dim: bool,
 }
 
 impl StatefulWidget for EventWidget {
@@ -399,7 +518,12 @@ impl StatefulWidget for EventWidget {
         let with_decis = state.with_decis;
         let clock_duration = state.get_duration();
         let clock_format = clock::format_by_duration(&clock_duration);
-        let clock_widths = clock::clock_horizontal_lengths(&clock_format, with_decis);
+        let clock_widths = clock::clock_horizontal_lengths(
+            &clock_format,
+            with_decis,
+            self.unit_labels,
+            self.spacing,
+        );
         let clock_width = clock_widths.iter().sum();
 
         let area = area.centered(
@@ -408,12 +532,12 @@ impl StatefulWidget for EventWidget {
                 DIGIT_HEIGHT + 7, /* height of all labels + empty lines */
             ),
         );
-        let [_, v1, v2, v3, _, v4] = Layout::vertical(Constraint::from_lengths([
+        let [_, v1, v2, v3, v_status, v4] = Layout::vertical(Constraint::from_lengths([
             3, // empty (offset) to keep everything centered vertically comparing to "clock" widgets with one label only
             DIGIT_HEIGHT,
             1, // label: event date
             1, // label: event title
-            1, // empty
+            1, // empty or (optional) label: status
             1, // label: error
         ]))
         .areas(area);
@@ -428,14 +552,21 @@ impl StatefulWidget for EventWidget {
 
         let render_clock_state = clock::RenderClockState {
             with_decis,
+            decimal_separator: self.decimal_separator,
+            flip: self.flip,
             duration: clock_duration.clone(),
             editable_time: None,
             format: clock_format,
             symbol,
             widths: clock_widths,
+            with_labels: self.unit_labels,
+            spacing: self.spacing,
         };
 
         clock::render_clock(v1, buf, render_clock_state);
+        if self.dim {
+            buf.set_style(v1, Modifier::DIM);
+        }
 
         // Helper to calculate centered area, cursor x position, and scroll
         let calc_editable_input_positions = |input: &Input, area: Rect| -> (Rect, u16, usize) {
@@ -507,7 +638,7 @@ impl StatefulWidget for EventWidget {
                     };
                 };
 
-                let date = AppTime::Local(state.event_time).format_date();
+                let date = AppTime::Local(state.event_time).format_date(&state.date_format);
                 let datetime_label = if let Some(tf) = state.event_time_format {
                     format!("{} {}", date, AppTime::Local(state.event_time).format(&tf))
                 } else {
@@ -536,12 +667,36 @@ impl StatefulWidget for EventWidget {
             }
             // NORMAL
             _ => {
-                Paragraph::new(state.input_title.value().to_uppercase())
+                let title = state.input_title.value();
+                let label = match (state.milestones.len(), title.is_empty()) {
+                    (0, _) => title.to_string(),
+                    (remaining, true) => format!("next ({remaining} remaining)"),
+                    (remaining, false) => format!("next: {title} ({remaining} remaining)"),
+                };
+                Paragraph::new(label.to_uppercase())
                     .centered()
                     .render(v3, buf);
             }
         };
 
+        // Render status
+        if self.status_line {
+            let left = if clock_duration.is_since() {
+                Duration::ZERO
+            } else {
+                clock_duration.clone().into()
+            };
+            let status = clock::status_line(
+                left.into(),
+                AppTime::Local(state.event_time),
+                state.get_percentage_done(),
+                &self.app_time_format,
+            );
+            Paragraph::new(status.to_uppercase())
+                .centered()
+                .render(v_status, buf);
+        }
+
         // Render error
         let error_txt: String = match (&state.input_datetime_error, &state.input_title_error) {
             (Some(e), _) => e.to_string(),
@@ -595,4 +750,25 @@ mod tests {
         assert_eq!(get_percentage(end, start, halfway), 100);
         assert_eq!(get_percentage(start, start, start), 100);
     }
+
+    #[test]
+    fn test_validate_datetime() {
+        assert_eq!(
+            validate_datetime("2024-02-29 10:00:00").unwrap(),
+            datetime!(2024-02-29 10:00:00)
+        );
+        let error = |value: &str| validate_datetime(value).unwrap_err().to_string();
+        assert_eq!(error("2025-02-30 10:00:00"), "Invalid day for month");
+        assert_eq!(error("2025-02-29 10:00:00"), "Invalid day for month");
+        assert_eq!(error("2025-04-31 10:00:00"), "Invalid day for month");
+        assert_eq!(
+            error("2025-13-01 10:00:00"),
+            "Invalid month. Expected format 'YYYY-MM-DD HH:MM:SS'"
+        );
+        assert_eq!(
+            error("2025-01-01 24:00:00"),
+            "Invalid hour. Expected format 'YYYY-MM-DD HH:MM:SS'"
+        );
+        assert_eq!(error("2025-01-01"), "Expected format 'YYYY-MM-DD HH:MM:SS'");
+    }
 }
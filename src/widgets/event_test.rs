@@ -1,26 +1,27 @@
 use crate::{
-    common::{AppTime, AppTimeFormat, Style},
+    common::{AppTime, AppTimeFormat, ClockTypeId, DateFormat, DecimalSeparator, Spacing, Style},
     event::Event,
+    events::{AppEvent, TuiEvent, TuiEventHandler},
     widgets::{
-        event::{EventState, EventStateArgs, EventWidget},
-        test_utils::{DrawArgs, FIXED_TIME, draw},
+        event::{DEFAULT_SNOOZE, EventState, EventStateArgs, EventWidget},
+        test_utils::{DrawArgs, FIXED_TIME, app_channel, app_tx, draw},
     },
 };
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
 use insta::assert_snapshot;
 use ratatui::{Terminal, backend::TestBackend};
 use time::macros::datetime;
 
-fn app_tx() -> crate::events::AppEventTx {
-    tokio::sync::mpsc::unbounded_channel().0
-}
-
 fn args() -> EventStateArgs {
     EventStateArgs {
         app_time: AppTime::Utc(FIXED_TIME),
         event: Event::default(),
+        milestones: Vec::new(),
+        snooze: DEFAULT_SNOOZE,
         with_decis: false,
         app_tx: app_tx(),
         event_time_format: None,
+        date_format: DateFormat::default(),
     }
 }
 
@@ -32,6 +33,13 @@ fn w() -> EventWidget {
     EventWidget {
         style: Style::default(),
         blink: false,
+        decimal_separator: DecimalSeparator::default(),
+        flip: None,
+        unit_labels: true,
+        status_line: false,
+        app_time_format: AppTimeFormat::default(),
+        spacing: Spacing::default(),
+        dim: false,
     }
 }
 
@@ -102,3 +110,191 @@ fn test_event_until() {
     let t = terminal(w(), st);
     assert_snapshot!("event_until", t.backend());
 }
+
+#[test]
+fn test_event_status_line() {
+    let st = st_with_args(EventStateArgs {
+        event: Event {
+            date_time: datetime!(2050-01-01 00:00),
+            title: Some("hello future".into()),
+        },
+        ..args()
+    });
+    let t = terminal(
+        EventWidget {
+            status_line: true,
+            ..w()
+        },
+        st,
+    );
+    assert_snapshot!("event_status_line", t.backend());
+}
+
+fn event(date_time: time::PrimitiveDateTime, title: &str) -> Event {
+    Event {
+        date_time,
+        title: Some(title.into()),
+    }
+}
+
+fn st_milestones(app_tx: crate::events::AppEventTx) -> EventState {
+    st_with_args(EventStateArgs {
+        event: event(datetime!(2024-06-10 14:30:01), "alpha"),
+        milestones: vec![
+            event(datetime!(2024-06-12 00:00), "launch"),
+            // passed already
+            event(datetime!(2024-06-01 00:00), "kickoff"),
+            event(datetime!(2024-06-11 00:00), "beta"),
+        ],
+        app_tx,
+        ..args()
+    })
+}
+
+#[test]
+fn test_event_milestones() {
+    let st = st_milestones(app_tx());
+    assert_eq!(st.get_event().title, Some("alpha".into()));
+    let titles: Vec<_> = st
+        .get_milestones()
+        .iter()
+        .map(|e| e.title.clone().unwrap())
+        .collect();
+    assert_eq!(titles, ["beta", "launch"]);
+    let t = terminal(w(), st);
+    assert_snapshot!("event_milestones", t.backend());
+}
+
+#[test]
+fn test_event_milestones_next() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_milestones(tx);
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01 UTC)));
+    // notification of passed milestone
+    assert!(rx.try_recv().is_ok());
+    assert_eq!(st.get_event().title, Some("beta".into()));
+    assert_eq!(st.get_milestones().len(), 1);
+    // latest one keeps counting since
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-11 00:00 UTC)));
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-12 00:00 UTC)));
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-13 00:00 UTC)));
+    assert_eq!(st.get_event().title, Some("launch".into()));
+    assert!(st.get_milestones().is_empty());
+}
+
+#[test]
+fn test_event_milestones_passed() {
+    let st = st_with_args(EventStateArgs {
+        milestones: vec![event(datetime!(2000-01-01 00:00), "y2k")],
+        ..args()
+    });
+    // latest passed one
+    assert_eq!(st.get_event().title, Some("y2k".into()));
+    assert!(st.get_milestones().is_empty());
+}
+
+fn st_done(app_tx: crate::events::AppEventTx) -> EventState {
+    st_with_args(EventStateArgs {
+        event: event(datetime!(2024-06-10 14:30:01), "done"),
+        app_tx,
+        ..args()
+    })
+}
+
+#[test]
+fn test_event_done_by_slow_update() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_done(tx);
+    // app has been stuck for a while
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:05 UTC)));
+    assert!(rx.try_recv().is_ok());
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_event_done_once() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_done(tx);
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01 UTC)));
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01.05 UTC)));
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01.09 UTC)));
+    assert!(rx.try_recv().is_ok());
+    assert!(rx.try_recv().is_err());
+    // app time goes back and passes event again, e.g. by changing system time
+    st.set_app_time(AppTime::Utc(FIXED_TIME));
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:02 UTC)));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_event_done_rearmed_by_edit() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_done(tx);
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01 UTC)));
+    assert!(rx.try_recv().is_ok());
+    // edit `2024-06-10 14:30:01` -> `2024-06-10 14:30:03`
+    for code in [
+        KeyCode::Char('e'),
+        KeyCode::Backspace,
+        KeyCode::Char('3'),
+        KeyCode::Enter,
+    ] {
+        st.update(TuiEvent::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+            code,
+            KeyModifiers::NONE,
+        ))));
+    }
+    // ignore `SetCursor`
+    while rx.try_recv().is_ok() {}
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:03 UTC)));
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(AppEvent::ClockDone(ClockTypeId::Event, ..))
+    ));
+}
+
+#[test]
+fn test_event_done_passed() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_with_args(EventStateArgs {
+        app_tx: tx,
+        ..args()
+    });
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01 UTC)));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_event_snooze() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_done(tx);
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-10 14:30:01 UTC)));
+    assert!(st.is_done());
+    st.update(TuiEvent::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+        KeyCode::Char('z'),
+        KeyModifiers::NONE,
+    ))));
+    assert!(!st.is_done());
+    assert_eq!(st.get_event().date_time, datetime!(2024-06-11 14:30:01));
+    let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+    assert_eq!(
+        events.last(),
+        Some(&AppEvent::Flash("snoozed to 2024-06-11 14:30:01".into()))
+    );
+    // re-armed
+    st.set_app_time(AppTime::Utc(datetime!(2024-06-11 14:30:01 UTC)));
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(AppEvent::ClockDone(ClockTypeId::Event, ..))
+    ));
+}
+
+#[test]
+fn test_event_snooze_upcoming() {
+    let mut st = st_done(app_tx());
+    st.update(TuiEvent::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+        KeyCode::Char('z'),
+        KeyModifiers::NONE,
+    ))));
+    assert_eq!(st.get_event().date_time, datetime!(2024-06-10 14:30:01));
+}
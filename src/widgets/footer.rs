@@ -1,20 +1,32 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
-use crate::common::{AppEditMode, AppTime, AppTimeFormat, Content};
+use crate::{
+    common::{AppEditMode, AppTime, AppTimeFormat, Content},
+    duration::DurationEx,
+};
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Layout, Position, Rect},
     style::Style,
     symbols::{border, scrollbar},
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, StatefulWidget, Table, Widget},
 };
 
+/// Clickable items of `Footer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FooterItem {
+    Menu,
+    Content(Content),
+}
+
 #[derive(Debug, Clone)]
 pub struct FooterState {
     show_menu: bool,
     app_time_format: Option<AppTimeFormat>,
     vim_motions: bool,
+    /// Rendered areas of all clickable items (needed for hit-testing)
+    item_areas: Vec<(FooterItem, Rect)>,
 }
 
 impl FooterState {
@@ -27,9 +39,18 @@ impl FooterState {
             show_menu,
             app_time_format,
             vim_motions,
+            item_areas: Vec::new(),
         }
     }
 
+    /// Returns a clickable item rendered at given `position` (if any)
+    pub fn get_item_at(&self, position: Position) -> Option<FooterItem> {
+        self.item_areas
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(item, _)| *item)
+    }
+
     pub fn set_show_menu(&mut self, value: bool) {
         self.show_menu = value;
     }
@@ -72,6 +93,15 @@ pub struct Footer {
     pub app_time: AppTime,
     pub pomodoro_auto_switch: bool,
     pub is_tabata: bool,
+    pub countdown_count_up: bool,
+    /// Whether countdown is done and measures elapsed time (MET) since then
+    pub countdown_met: bool,
+    /// Shows app time with seconds, regardless of its format
+    pub app_time_seconds: bool,
+    /// Shows hint to copy current value to clipboard
+    pub clipboard: bool,
+    /// Time elapsed since launch to show (if any)
+    pub uptime: Option<Duration>,
 }
 
 const SPACE: &str = " "; // single (empty) SPACE
@@ -113,6 +143,7 @@ impl StatefulWidget for Footer {
             ),
             (Content::Event, "event"),
             (Content::LocalTime, "local time"),
+            (Content::Alarm, "alarm"),
         ]);
 
         let [_, area] =
@@ -121,32 +152,58 @@ impl StatefulWidget for Footer {
         let [border_area, menu_area] =
             Layout::vertical([Constraint::Length(1), Constraint::Percentage(100)]).areas(area);
 
+        let menu_title = Line::from(vec![
+            Span::styled("m", BOLD),
+            Span::from(SPACE),
+            Span::styled(if state.show_menu { "hide" } else { "show" }, ITALIC),
+            Span::from(SPACE),
+            Span::styled("menu", ITALIC),
+            Span::from(SPACE),
+        ]);
+        state.item_areas = vec![(
+            FooterItem::Menu,
+            Rect {
+                width: menu_title.width() as u16,
+                height: 1,
+                ..border_area
+            },
+        )];
+
         Block::new()
             .borders(Borders::TOP)
-            .title(Line::from(vec![
-                Span::styled("m", BOLD),
-                Span::from(SPACE),
-                Span::styled(if state.show_menu { "hide" } else { "show" }, ITALIC),
-                Span::from(SPACE),
-                Span::styled("menu", ITALIC),
-                Span::from(SPACE),
-            ]))
+            .title(menu_title)
             .title(
                 Line::from(match (state.app_time_format, self.selected_content) {
                     // Show time
                     (Some(v), content) if content != Content::LocalTime => format!(
                         "{SPACE}{}{SPACE}", // keep SPACE around
-                        self.app_time.format(&v)
+                        self.app_time.format(&if self.app_time_seconds {
+                            v.to_seconds()
+                        } else {
+                            v
+                        })
                     ),
                     // Hide time -> empty string
                     _ => "".into(),
                 })
                 .right_aligned(),
             )
+            .title(
+                Line::from(
+                    self.uptime
+                        .map(|uptime| format!("{SPACE}up {}{SPACE}", DurationEx::from(uptime)))
+                        .unwrap_or_default(),
+                )
+                .centered(),
+            )
             .border_set(border::PLAIN)
             .render(border_area, buf);
+        const FIRST_COLUMN_WIDTH: u16 = 12;
+        const COLUMN_SPACING: u16 = 1;
         // show menu
         if state.show_menu {
+            // x position of next content label
+            let mut label_x = menu_area.x + FIRST_COLUMN_WIDTH + COLUMN_SPACING;
             let mut content_labels: Vec<Span> = content_labels
                 .iter()
                 .enumerate()
@@ -159,6 +216,13 @@ impl StatefulWidget for Footer {
                     } else {
                         format!("{label}{WIDE_SPACE}")
                     };
+                    // clickable area: `no` + `SPACE` + `label` (w/o `WIDE_SPACE`)
+                    let item_width = (no.to_string().len() + SPACE.len() + label.len()) as u16;
+                    state.item_areas.push((
+                        FooterItem::Content(*content),
+                        Rect::new(label_x, menu_area.y, item_width, 1),
+                    ));
+                    label_x += item_width + WIDE_SPACE.len() as u16;
                     [
                         Span::styled(format!("{no}"), BOLD),
                         Span::from(SPACE),
@@ -178,7 +242,45 @@ impl StatefulWidget for Footer {
                 Span::styled("switch screens", ITALIC),
             ]);
 
-            let widths = [Constraint::Length(12), Constraint::Percentage(100)];
+            let mut appearance = vec![
+                Span::styled(",", BOLD),
+                Span::from(SPACE),
+                Span::styled("change style", ITALIC),
+                Span::from(WIDE_SPACE),
+                Span::styled(".", BOLD),
+                Span::from(SPACE),
+                Span::styled("toggle deciseconds", ITALIC),
+                Span::from(WIDE_SPACE),
+                Span::styled(":", BOLD),
+                Span::from(SPACE),
+                Span::styled(
+                    format!(
+                        "toggle {} time",
+                        match self.app_time {
+                            AppTime::Local(_) => "local",
+                            AppTime::Utc(_) => "utc",
+                        }
+                    ),
+                    ITALIC,
+                ),
+                Span::from(WIDE_SPACE),
+                Span::styled("S", BOLD),
+                Span::from(SPACE),
+                Span::styled("settings", ITALIC),
+            ];
+            if self.clipboard {
+                appearance.extend([
+                    Span::from(WIDE_SPACE),
+                    Span::styled("y", BOLD),
+                    Span::from(SPACE),
+                    Span::styled("copy", ITALIC),
+                ]);
+            }
+
+            let widths = [
+                Constraint::Length(FIRST_COLUMN_WIDTH),
+                Constraint::Percentage(100),
+            ];
             let mut table_rows = vec![
                 // screens
                 Row::new(vec![
@@ -188,33 +290,12 @@ impl StatefulWidget for Footer {
                 // appearance
                 Row::new(vec![
                     Cell::from(Span::from("appearance")),
-                    Cell::from(Line::from(vec![
-                        Span::styled(",", BOLD),
-                        Span::from(SPACE),
-                        Span::styled("change style", ITALIC),
-                        Span::from(WIDE_SPACE),
-                        Span::styled(".", BOLD),
-                        Span::from(SPACE),
-                        Span::styled("toggle deciseconds", ITALIC),
-                        Span::from(WIDE_SPACE),
-                        Span::styled(":", BOLD),
-                        Span::from(SPACE),
-                        Span::styled(
-                            format!(
-                                "toggle {} time",
-                                match self.app_time {
-                                    AppTime::Local(_) => "local",
-                                    AppTime::Utc(_) => "utc",
-                                }
-                            ),
-                            ITALIC,
-                        ),
-                    ])),
+                    Cell::from(Line::from(appearance)),
                 ]),
             ];
 
-            // Controls (except for `localtime`)
-            if self.selected_content != Content::LocalTime {
+            // Controls (except for `localtime` and `alarm`)
+            if !matches!(self.selected_content, Content::LocalTime | Content::Alarm) {
                 table_rows.extend_from_slice(&[
                     // controls - 1. row
                     Row::new(vec![
@@ -242,6 +323,17 @@ impl StatefulWidget for Footer {
                                             Span::styled("^e", BOLD),
                                             Span::from(SPACE),
                                             Span::styled("edit by local time", ITALIC),
+                                            Span::from(WIDE_SPACE),
+                                            Span::styled("u", BOLD),
+                                            Span::from(SPACE),
+                                            Span::styled(
+                                                if self.countdown_count_up {
+                                                    "show time left"
+                                                } else {
+                                                    "show elapsed"
+                                                },
+                                                ITALIC,
+                                            ),
                                         ]);
                                     }
                                     spans.extend_from_slice(&[
@@ -250,6 +342,16 @@ impl StatefulWidget for Footer {
                                         Span::from(SPACE),
                                         Span::styled("reset clock", ITALIC),
                                     ]);
+                                    if self.selected_content == Content::Countdown
+                                        && self.countdown_met
+                                    {
+                                        spans.extend_from_slice(&[
+                                            Span::from(WIDE_SPACE),
+                                            Span::styled("R", BOLD),
+                                            Span::from(SPACE),
+                                            Span::styled("reset elapsed", ITALIC),
+                                        ]);
+                                    }
                                     if self.selected_content == Content::Pomodoro {
                                         spans.extend_from_slice(&[
                                             Span::from(WIDE_SPACE),
@@ -351,6 +453,14 @@ impl StatefulWidget for Footer {
                                                 Span::from(SPACE),
                                                 Span::styled("previous round", ITALIC),
                                                 Span::from(WIDE_SPACE),
+                                                Span::styled("[", BOLD),
+                                                Span::from(SPACE),
+                                                Span::from("or"),
+                                                Span::from(SPACE),
+                                                Span::styled("]", BOLD),
+                                                Span::from(SPACE),
+                                                Span::styled("adjust round", ITALIC),
+                                                Span::from(WIDE_SPACE),
                                                 Span::styled(format!("^{}", symbol_up), BOLD),
                                                 Span::from(SPACE),
                                                 Span::styled("max rounds up", ITALIC),
@@ -394,7 +504,7 @@ impl StatefulWidget for Footer {
                 ])
             }
 
-            let table = Table::new(table_rows, widths).column_spacing(1);
+            let table = Table::new(table_rows, widths).column_spacing(COLUMN_SPACING);
 
             Widget::render(table, menu_area, buf);
         }
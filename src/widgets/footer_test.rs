@@ -1,9 +1,16 @@
-use ratatui::{Terminal, backend::TestBackend};
+use ratatui::{
+    Terminal,
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Position, Rect},
+    widgets::StatefulWidget,
+};
+use std::time::Duration;
 
 use crate::{
     common::{AppEditMode, AppTime, AppTimeFormat, Content},
     widgets::{
-        footer::{Footer, FooterState},
+        footer::{Footer, FooterItem, FooterState},
         test_utils::{DrawArgs, FIXED_TIME, draw},
     },
 };
@@ -19,6 +26,11 @@ fn w() -> Footer {
         app_time: AppTime::Local(FIXED_TIME),
         pomodoro_auto_switch: false,
         is_tabata: false,
+        countdown_count_up: false,
+        countdown_met: false,
+        app_time_seconds: false,
+        clipboard: false,
+        uptime: None,
     }
 }
 
@@ -65,6 +77,17 @@ fn test_menu_countdown_running() {
     assert_snapshot!("menu_countdown_running", t.backend());
 }
 
+#[test]
+fn test_menu_countdown_done() {
+    let w = Footer {
+        running_clock: true,
+        countdown_met: true,
+        ..w()
+    };
+    let t = terminal(w, st());
+    assert_snapshot!("menu_countdown_done", t.backend());
+}
+
 #[test]
 fn test_menu_countdown_edit_mode() {
     let w = Footer {
@@ -233,3 +256,83 @@ fn test_menu_time_format_hh_12_mm() {
     let t = terminal(w(), st);
     assert_snapshot!("menu_time_format_hh_12_mm", t.backend());
 }
+
+#[test]
+fn test_menu_time_format_hh_12_mm_ss() {
+    let st = st().with_app_time_format(AppTimeFormat::Hh12MmSs);
+    let t = terminal(w(), st);
+    assert_snapshot!("menu_time_format_hh_12_mm_ss", t.backend());
+}
+
+#[test]
+fn test_menu_time_format_hh_mm_with_seconds() {
+    let st = st().with_app_time_format(AppTimeFormat::HhMm);
+    let w = Footer {
+        app_time_seconds: true,
+        ..w()
+    };
+    let t = terminal(w, st);
+    assert_snapshot!("menu_time_format_hh_mm_with_seconds", t.backend());
+}
+
+#[test]
+fn test_menu_clipboard() {
+    let w = Footer {
+        clipboard: true,
+        ..w()
+    };
+    let t = terminal(w, st());
+    assert_snapshot!("menu_clipboard", t.backend());
+}
+
+#[test]
+fn test_menu_uptime() {
+    let w = Footer {
+        uptime: Some(Duration::from_secs(2 * 60 * 60 + 5)),
+        ..w()
+    };
+    let t = terminal(w, st().with_show_menu(false));
+    assert_snapshot!("menu_uptime", t.backend());
+}
+
+// mouse
+
+#[test]
+fn test_get_item_at() {
+    let mut st = st();
+    let area = Rect::new(0, 0, 120, 6);
+    w().render(area, &mut Buffer::empty(area), &mut st);
+
+    // `m hide menu`
+    assert_eq!(st.get_item_at(Position::new(1, 0)), Some(FooterItem::Menu));
+    assert_eq!(st.get_item_at(Position::new(11, 0)), Some(FooterItem::Menu));
+    assert_eq!(st.get_item_at(Position::new(13, 0)), None);
+    // `1 countdown`
+    assert_eq!(
+        st.get_item_at(Position::new(14, 1)),
+        Some(FooterItem::Content(Content::Countdown))
+    );
+    assert_eq!(
+        st.get_item_at(Position::new(24, 1)),
+        Some(FooterItem::Content(Content::Countdown))
+    );
+    // space between labels
+    assert_eq!(st.get_item_at(Position::new(26, 1)), None);
+    // `2 timer`
+    assert_eq!(
+        st.get_item_at(Position::new(28, 1)),
+        Some(FooterItem::Content(Content::Timer))
+    );
+    // others
+    assert_eq!(st.get_item_at(Position::new(14, 2)), None);
+}
+
+#[test]
+fn test_get_item_at_menu_hidden() {
+    let mut st = st().with_show_menu(false);
+    let area = Rect::new(0, 0, 120, 1);
+    w().render(area, &mut Buffer::empty(area), &mut st);
+
+    assert_eq!(st.get_item_at(Position::new(1, 0)), Some(FooterItem::Menu));
+    assert_eq!(st.get_item_at(Position::new(14, 1)), None);
+}
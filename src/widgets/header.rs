@@ -1,22 +1,46 @@
+use clap::ValueEnum;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    widgets::{Block, Borders, Widget},
+    widgets::{Block, Borders, Gauge, Widget},
 };
+use serde::{Deserialize, Serialize};
 
 use crate::widgets::progressbar::Progressbar;
 
+/// Variants to render progress of current clock
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize)]
+pub enum Progress {
+    /// Bar above content
+    #[default]
+    #[value(name = "top", alias = "t")]
+    Top,
+    /// Bar below content
+    #[value(name = "bottom", alias = "b")]
+    Bottom,
+    /// Gauge labeled by percentage above content
+    #[value(name = "gauge", alias = "g")]
+    Gauge,
+    /// No progress at all
+    #[value(name = "off", alias = "o")]
+    Off,
+}
+
 #[derive(Debug, Clone)]
 pub struct Header {
     pub percentage: Option<u16>,
+    /// Renders a `Gauge` instead of a `Progressbar`
+    pub gauge: bool,
 }
 
 impl Widget for Header {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if let Some(percentage) = self.percentage {
-            Progressbar::new(percentage).render(area, buf);
-        } else {
-            Block::new().borders(Borders::TOP).render(area, buf);
+        match self.percentage {
+            Some(percentage) if self.gauge => Gauge::default()
+                .percent(percentage.min(100))
+                .render(area, buf),
+            Some(percentage) => Progressbar::new(percentage).render(area, buf),
+            None => Block::new().borders(Borders::TOP).render(area, buf),
         }
     }
 }
@@ -8,7 +8,11 @@ const RECT: Rect = Rect::new(0, 0, W, 1);
 #[test]
 fn test_header_none() {
     let mut b = Buffer::empty(RECT);
-    Header { percentage: None }.render(RECT, &mut b);
+    Header {
+        percentage: None,
+        gauge: false,
+    }
+    .render(RECT, &mut b);
     assert_eq!(b, Buffer::with_lines(["──────────"]));
 }
 
@@ -17,6 +21,7 @@ fn test_header_progress() {
     let mut b = Buffer::empty(RECT);
     Header {
         percentage: Some(50),
+        gauge: false,
     }
     .render(RECT, &mut b);
     assert_eq!(b, Buffer::with_lines(["━━━━━─────"]));
@@ -27,7 +32,30 @@ fn test_header_progress_full() {
     let mut b = Buffer::empty(RECT);
     Header {
         percentage: Some(100),
+        gauge: false,
     }
     .render(RECT, &mut b);
     assert_eq!(b, Buffer::with_lines(["━━━━━━━━━━"]));
 }
+
+#[test]
+fn test_header_gauge() {
+    let mut b = Buffer::empty(RECT);
+    Header {
+        percentage: Some(50),
+        gauge: true,
+    }
+    .render(RECT, &mut b);
+    assert_eq!(b, Buffer::with_lines(["███50%    "]));
+}
+
+#[test]
+fn test_header_gauge_none() {
+    let mut b = Buffer::empty(RECT);
+    Header {
+        percentage: None,
+        gauge: true,
+    }
+    .render(RECT, &mut b);
+    assert_eq!(b, Buffer::with_lines(["──────────"]));
+}
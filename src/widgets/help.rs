@@ -0,0 +1,176 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Row, StatefulWidget, Table, Widget},
+};
+
+/// All keybindings grouped by context: `(context, [(key, description)])`
+const KEYBINDINGS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "global",
+        &[
+            ("1 .. 6", "switch screens"),
+            ("← or →", "previous / next screen"),
+            ("h or l", "previous / next screen (vim)"),
+            (",", "change style"),
+            ("<", "change style (backward)"),
+            (".", "toggle deciseconds"),
+            (":", "toggle local time (w/o vim)"),
+            (";", "toggle local time (backward)"),
+            (":", "command line (vim)"),
+            ("m", "toggle menu"),
+            ("f", "toggle zen mode"),
+            ("⌥+ or ⌥-", "move clock down / up"),
+            ("S", "settings"),
+            ("F1 or h", "help"),
+            ("y", "copy value (clipboard feature)"),
+            ("F12", "store snapshot"),
+            ("q", "quit"),
+        ],
+    ),
+    (
+        "countdown",
+        &[
+            ("space", "start / stop"),
+            ("e", "edit"),
+            ("^e", "edit by local time"),
+            ("u", "toggle elapsed time / time left"),
+            ("r", "reset clock (and sequence)"),
+            ("R", "reset elapsed time (if done)"),
+            ("s", "pause / resume elapsed (if done)"),
+            ("n", "add countdown"),
+            ("x", "remove countdown"),
+            ("tab or ⇧tab", "next / previous countdown"),
+            ("1 .. N", "load preset (of --countdown)"),
+            ("N+1 .. 6", "switch screens (as usual)"),
+        ],
+    ),
+    (
+        "timer",
+        &[
+            ("space", "start / stop"),
+            ("e", "edit"),
+            ("r", "reset clock"),
+        ],
+    ),
+    (
+        "pomodoro",
+        &[
+            ("space", "start / stop"),
+            ("e", "edit"),
+            ("r", "reset clock"),
+            ("^r", "reset clocks and rounds"),
+            ("^← or ^→", "switch work / pause"),
+            ("a", "toggle auto switch"),
+            ("↑ or ↓", "next / previous round"),
+            ("[ or ]", "adjust round (keeps clocks)"),
+            ("^↑ or ^↓", "increase / decrease max rounds"),
+        ],
+    ),
+    ("event", &[("e", "edit"), ("z", "snooze (if done)")]),
+    (
+        "edit mode",
+        &[
+            ("s", "save changes"),
+            ("^s", "save initial value"),
+            ("esc", "skip changes"),
+            ("← or →", "change selection"),
+            ("↑ or ↓", "edit up / down"),
+            ("^↑ or ^↓", "edit up / down (jump step)"),
+        ],
+    ),
+];
+
+const KEY_WIDTH: u16 = 11;
+const DESCRIPTION_WIDTH: u16 = 32;
+const WIDTH: u16 = KEY_WIDTH + DESCRIPTION_WIDTH + 5; // incl. borders + padding + column spacing
+
+/// State for `HelpWidget`
+#[derive(Debug, Clone, Default)]
+pub struct HelpState {
+    show: bool,
+    /// Number of rows scrolled down
+    scroll: u16,
+}
+
+impl HelpState {
+    pub fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    pub fn toggle(&mut self) {
+        self.show = !self.show;
+        self.scroll = 0;
+    }
+
+    pub fn hide(&mut self) {
+        self.show = false;
+        self.scroll = 0;
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1).min(max_scroll());
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
+
+fn rows() -> Vec<Row<'static>> {
+    KEYBINDINGS
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (context, bindings))| {
+            // empty row between contexts
+            let space = (index > 0).then(|| Row::new(vec![""]));
+            let title = Row::new(vec![Cell::from(*context)])
+                .style(Style::new().add_modifier(Modifier::BOLD));
+            let bindings = bindings.iter().map(|(key, description)| {
+                Row::new(vec![
+                    Cell::from(*key).style(Style::new().add_modifier(Modifier::BOLD)),
+                    Cell::from(*description).style(Style::new().add_modifier(Modifier::ITALIC)),
+                ])
+            });
+            space.into_iter().chain([title]).chain(bindings)
+        })
+        .collect()
+}
+
+fn max_scroll() -> u16 {
+    rows().len().saturating_sub(1) as u16
+}
+
+/// Overlay to list all keybindings
+pub struct HelpWidget;
+
+impl StatefulWidget for HelpWidget {
+    type State = HelpState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let rows = rows();
+        let area = area.centered(
+            Constraint::Length(WIDTH),
+            Constraint::Max(rows.len() as u16 + 2 /* borders */),
+        );
+
+        Clear.render(area, buf);
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(" help ").centered())
+            .title_bottom(Line::raw(" ↑↓ scroll  any key close ").centered());
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let inner = inner.centered_horizontally(Constraint::Length(inner.width.saturating_sub(2)));
+        let table = Table::new(
+            rows.into_iter().skip(state.scroll as usize),
+            [
+                Constraint::Length(KEY_WIDTH),
+                Constraint::Length(DESCRIPTION_WIDTH),
+            ],
+        );
+        Widget::render(table, inner, buf);
+    }
+}
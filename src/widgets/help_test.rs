@@ -0,0 +1,51 @@
+use crate::widgets::{
+    help::{HelpState, HelpWidget},
+    test_utils::{DrawArgs, draw},
+};
+use insta::assert_snapshot;
+use ratatui::{Terminal, backend::TestBackend};
+
+fn terminal(st: HelpState) -> Terminal<TestBackend> {
+    draw(DrawArgs {
+        widget: HelpWidget,
+        state: st,
+        width: 60,
+        height: 20,
+    })
+}
+
+#[test]
+fn test_help() {
+    let t = terminal(HelpState::default());
+    assert_snapshot!("help", t.backend());
+}
+
+#[test]
+fn test_help_scrolled() {
+    let mut st = HelpState::default();
+    for _ in 0..14 {
+        st.scroll_down();
+    }
+    let t = terminal(st);
+    assert_snapshot!("help_scrolled", t.backend());
+}
+
+#[test]
+fn test_help_scroll_bounds() {
+    let mut st = HelpState::default();
+    st.scroll_up();
+    let top = terminal(st.clone());
+    assert_eq!(
+        top.backend().buffer(),
+        terminal(HelpState::default()).backend().buffer()
+    );
+    // scrolling down is limited
+    for _ in 0..1000 {
+        st.scroll_down();
+    }
+    st.scroll_up();
+    st.scroll_down();
+    let bottom = terminal(st.clone());
+    st.scroll_down();
+    assert_eq!(bottom.backend().buffer(), terminal(st).backend().buffer());
+}
@@ -7,24 +7,27 @@ use ratatui::{
 };
 
 use crate::{
-    common::{AppTime, AppTimeFormat, Style as DigitStyle},
+    common::{AppTime, AppTimeFormat, DateFormat, Flip, Style as DigitStyle},
     duration::{ClockDuration, DurationEx},
     events::{TuiEvent, TuiEventHandler},
     widgets::clock_elements::{
         COLON_WIDTH, Colon, DIGIT_HEIGHT, DIGIT_SPACE_WIDTH, DIGIT_WIDTH, Digit,
     },
 };
+use clap::ValueEnum;
 use std::cmp::max;
 
 /// State for `LocalTimeWidget`
 pub struct LocalTimeState {
     time: AppTime,
     format: AppTimeFormat,
+    date_format: DateFormat,
 }
 
 pub struct LocalTimeStateArgs {
     pub app_time: AppTime,
     pub app_time_format: AppTimeFormat,
+    pub date_format: DateFormat,
 }
 
 impl LocalTimeState {
@@ -32,11 +35,13 @@ impl LocalTimeState {
         let LocalTimeStateArgs {
             app_time,
             app_time_format,
+            date_format,
         } = args;
 
         Self {
             time: app_time,
             format: app_time_format,
+            date_format,
         }
     }
 
@@ -56,12 +61,78 @@ impl TuiEventHandler for LocalTimeState {
     }
 }
 
+/// Variants to render local time
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
+pub enum LocalTimeStyle {
+    #[default]
+    #[value(name = "digital", alias = "d")]
+    Digital,
+    /// Columns of bits (binary-coded decimal) for each digit
+    #[value(name = "binary", alias = "b")]
+    Binary,
+}
+
+const BINARY_CELL_WIDTH: u16 = 2;
+const BINARY_ROWS: u16 = 4; // bits: 8, 4, 2, 1
+const BINARY_EMPTY_SYMBOL: &str = "·";
+const BINARY_VALUE_WIDTH: u16 = BINARY_CELL_WIDTH + DIGIT_SPACE_WIDTH + BINARY_CELL_WIDTH; // cell-space-cell
+const BINARY_VALUE_SPACE_WIDTH: u16 = 3; // space between hours, minutes and seconds
+
 #[derive(Debug)]
 pub struct LocalTimeWidget {
     pub style: DigitStyle,
+    pub flip: Option<Flip>,
+    /// Note: `flip` is ignored by `LocalTimeStyle::Binary`
+    pub local_time_style: LocalTimeStyle,
+    /// Dims digits (`--dim`)
+    pub dim: bool,
 }
 
 impl LocalTimeWidget {
+    fn get_binary_width(count: u16) -> u16 {
+        count * BINARY_VALUE_WIDTH + count.saturating_sub(1) * BINARY_VALUE_SPACE_WIDTH
+    }
+
+    /// Renders a column of bits for tens and ones of each value.
+    /// Each value is passed with the number of bits needed for its tens
+    /// (e.g. 2 bits for tens of hours, which is `2` at most).
+    fn render_binary(&self, values: &[(u64, u16)], area: Rect, buf: &mut Buffer) {
+        let filled = self
+            .style
+            .get_digit_symbol()
+            .repeat(BINARY_CELL_WIDTH as usize);
+        let empty = BINARY_EMPTY_SYMBOL.repeat(BINARY_CELL_WIDTH as usize);
+        let none = " ".repeat(BINARY_CELL_WIDTH as usize);
+        let value_space = " ".repeat(BINARY_VALUE_SPACE_WIDTH as usize);
+        let digit_space = " ".repeat(DIGIT_SPACE_WIDTH as usize);
+
+        let rows = Layout::vertical(vec![Constraint::Length(1); BINARY_ROWS as usize]).split(area);
+        for (index, row) in rows.iter().enumerate() {
+            let bit = BINARY_ROWS - 1 - index as u16;
+            let cell = |digit: u64, bits: u16| {
+                if bit >= bits {
+                    none.as_str()
+                } else if (digit >> bit) & 1 == 1 {
+                    filled.as_str()
+                } else {
+                    empty.as_str()
+                }
+            };
+            let line = values
+                .iter()
+                .map(|(value, tens_bits)| {
+                    format!(
+                        "{}{digit_space}{}",
+                        cell(value / 10, *tens_bits),
+                        cell(value % 10, BINARY_ROWS)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(&value_space);
+            Line::raw(line).render(*row, buf);
+        }
+    }
+
     fn get_horizontal_lengths(&self, format: &AppTimeFormat) -> Vec<u16> {
         const PERIOD_WIDTH: u16 = 2; // PM or AM
 
@@ -88,6 +159,22 @@ impl LocalTimeWidget {
                 DIGIT_SPACE_WIDTH, // (space)
                 DIGIT_WIDTH,       // m
             ],
+            AppTimeFormat::Hh12MmSs => vec![
+                DIGIT_SPACE_WIDTH + PERIOD_WIDTH, // (space) + (empty period) to center everything well horizontally
+                DIGIT_WIDTH,                      // H
+                DIGIT_SPACE_WIDTH,                // (space)
+                DIGIT_WIDTH,                      // h
+                COLON_WIDTH,                      // :
+                DIGIT_WIDTH,                      // M
+                DIGIT_SPACE_WIDTH,                // (space)
+                DIGIT_WIDTH,                      // m
+                COLON_WIDTH,                      // :
+                DIGIT_WIDTH,                      // S
+                DIGIT_SPACE_WIDTH,                // (space)
+                DIGIT_WIDTH,                      // s
+                DIGIT_SPACE_WIDTH,                // (space)
+                PERIOD_WIDTH,                     // period
+            ],
             AppTimeFormat::Hh12Mm => vec![
                 DIGIT_SPACE_WIDTH + PERIOD_WIDTH, // (space) + (empty period) to center everything well horizontally
                 DIGIT_WIDTH,                      // H
@@ -113,17 +200,67 @@ impl StatefulWidget for LocalTimeWidget {
         let minutes = current_value.minutes_mod();
         let seconds = current_value.seconds_mod();
         let symbol = self.style.get_digit_symbol();
+        let flip = self.flip;
 
         let label = Line::raw("Local Time".to_uppercase());
-        let label_date = Line::raw(state.time.format_date().to_uppercase());
+        let label_date = Line::raw(state.time.format_date(&state.date_format).to_uppercase());
         let mut content_width = max(label.width(), label_date.width()) as u16;
 
+        if self.local_time_style == LocalTimeStyle::Binary {
+            let mut values = vec![
+                (
+                    if state.format.is_12h() {
+                        hours12
+                    } else {
+                        hours
+                    },
+                    2,
+                ),
+                (minutes, 3),
+            ];
+            if state.format.with_seconds() {
+                values.push((seconds, 3));
+            }
+            let label = if state.format.is_12h() {
+                Line::raw(format!("Local Time {}", state.time.get_period()).to_uppercase())
+            } else {
+                label
+            };
+            let content_width = max(
+                Self::get_binary_width(values.len() as u16),
+                max(label.width(), label_date.width()) as u16,
+            );
+            let v_heights = [
+                1,           // empty (offset) to keep everything centered vertically
+                BINARY_ROWS, // local time
+                1,           // empty
+                1,           // label
+                1,           // date
+            ];
+            let area = area.centered(
+                Constraint::Length(content_width),
+                Constraint::Length(v_heights.iter().sum()),
+            );
+            let [_, v1, _, v2, v3] =
+                Layout::vertical(Constraint::from_lengths(v_heights)).areas(area);
+            let v1 = v1.centered_horizontally(Constraint::Length(Self::get_binary_width(
+                values.len() as u16,
+            )));
+            self.render_binary(&values, v1, buf);
+            if self.dim {
+                buf.set_style(v1, Modifier::DIM);
+            }
+            label.centered().render(v2, buf);
+            label_date.centered().render(v3, buf);
+            return;
+        }
+
         let format = state.format;
         let widths = self.get_horizontal_lengths(&format);
         let mut widths = widths;
-        // Special case for `Hh12Mm`
+        // Special case for `Hh12Mm` and `Hh12MmSs`
         // It might be `h:Mm` OR `Hh:Mm` depending on `hours12`
-        if state.format == AppTimeFormat::Hh12Mm && hours12 < 10 {
+        if state.format.is_12h() && hours12 < 10 {
             // single digit means, no (zero) width's for `H` and `space`
             widths[1] = 0; // `H`
             widths[2] = 0; // `space`
@@ -148,39 +285,103 @@ impl StatefulWidget for LocalTimeWidget {
             AppTimeFormat::HhMmSs => {
                 let [hh, _, h, c_hm, mm, _, m, c_ms, ss, _, s] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(v1);
-                Digit::new(hours / 10, false, symbol).render(hh, buf);
-                Digit::new(hours % 10, false, symbol).render(h, buf);
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(minutes / 10, false, symbol).render(mm, buf);
-                Digit::new(minutes % 10, false, symbol).render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(seconds / 10, false, symbol).render(ss, buf);
-                Digit::new(seconds % 10, false, symbol).render(s, buf);
+                Digit::new(hours / 10, false, symbol)
+                    .flip(flip)
+                    .render(hh, buf);
+                Digit::new(hours % 10, false, symbol)
+                    .flip(flip)
+                    .render(h, buf);
+                Colon::new(symbol).flip(flip).render(c_hm, buf);
+                Digit::new(minutes / 10, false, symbol)
+                    .flip(flip)
+                    .render(mm, buf);
+                Digit::new(minutes % 10, false, symbol)
+                    .flip(flip)
+                    .render(m, buf);
+                Colon::new(symbol).flip(flip).render(c_ms, buf);
+                Digit::new(seconds / 10, false, symbol)
+                    .flip(flip)
+                    .render(ss, buf);
+                Digit::new(seconds % 10, false, symbol)
+                    .flip(flip)
+                    .render(s, buf);
             }
             AppTimeFormat::HhMm => {
                 let [hh, _, h, c_hm, mm, _, m] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(v1);
-                Digit::new(hours / 10, false, symbol).render(hh, buf);
-                Digit::new(hours % 10, false, symbol).render(h, buf);
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(minutes / 10, false, symbol).render(mm, buf);
-                Digit::new(minutes % 10, false, symbol).render(m, buf);
+                Digit::new(hours / 10, false, symbol)
+                    .flip(flip)
+                    .render(hh, buf);
+                Digit::new(hours % 10, false, symbol)
+                    .flip(flip)
+                    .render(h, buf);
+                Colon::new(symbol).flip(flip).render(c_hm, buf);
+                Digit::new(minutes / 10, false, symbol)
+                    .flip(flip)
+                    .render(mm, buf);
+                Digit::new(minutes % 10, false, symbol)
+                    .flip(flip)
+                    .render(m, buf);
+            }
+            AppTimeFormat::Hh12MmSs => {
+                let [_, hh, _, h, c_hm, mm, _, m, c_ms, ss, _, s, _, p] =
+                    Layout::horizontal(Constraint::from_lengths(widths)).areas(v1);
+                // Hh
+                if hours12 >= 10 {
+                    Digit::new(hours12 / 10, false, symbol)
+                        .flip(flip)
+                        .render(hh, buf);
+                    Digit::new(hours12 % 10, false, symbol)
+                        .flip(flip)
+                        .render(h, buf);
+                }
+                // h
+                else {
+                    Digit::new(hours12, false, symbol).flip(flip).render(h, buf);
+                }
+                Colon::new(symbol).flip(flip).render(c_hm, buf);
+                Digit::new(minutes / 10, false, symbol)
+                    .flip(flip)
+                    .render(mm, buf);
+                Digit::new(minutes % 10, false, symbol)
+                    .flip(flip)
+                    .render(m, buf);
+                Colon::new(symbol).flip(flip).render(c_ms, buf);
+                Digit::new(seconds / 10, false, symbol)
+                    .flip(flip)
+                    .render(ss, buf);
+                Digit::new(seconds % 10, false, symbol)
+                    .flip(flip)
+                    .render(s, buf);
+                Span::styled(
+                    state.time.get_period().to_uppercase(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )
+                .render(p, buf);
             }
             AppTimeFormat::Hh12Mm => {
                 let [_, hh, _, h, c_hm, mm, _, m, _, p] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(v1);
                 // Hh
                 if hours12 >= 10 {
-                    Digit::new(hours12 / 10, false, symbol).render(hh, buf);
-                    Digit::new(hours12 % 10, false, symbol).render(h, buf);
+                    Digit::new(hours12 / 10, false, symbol)
+                        .flip(flip)
+                        .render(hh, buf);
+                    Digit::new(hours12 % 10, false, symbol)
+                        .flip(flip)
+                        .render(h, buf);
                 }
                 // h
                 else {
-                    Digit::new(hours12, false, symbol).render(h, buf);
+                    Digit::new(hours12, false, symbol).flip(flip).render(h, buf);
                 }
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(minutes / 10, false, symbol).render(mm, buf);
-                Digit::new(minutes % 10, false, symbol).render(m, buf);
+                Colon::new(symbol).flip(flip).render(c_hm, buf);
+                Digit::new(minutes / 10, false, symbol)
+                    .flip(flip)
+                    .render(mm, buf);
+                Digit::new(minutes % 10, false, symbol)
+                    .flip(flip)
+                    .render(m, buf);
                 Span::styled(
                     state.time.get_period().to_uppercase(),
                     Style::default().add_modifier(Modifier::BOLD),
@@ -188,6 +389,9 @@ impl StatefulWidget for LocalTimeWidget {
                 .render(p, buf);
             }
         }
+        if self.dim {
+            buf.set_style(v1, Modifier::DIM);
+        }
         label.centered().render(v2, buf);
         label_date.centered().render(v3, buf);
     }
@@ -1,7 +1,7 @@
 use crate::{
-    common::{AppTime, AppTimeFormat, Style},
+    common::{AppTime, AppTimeFormat, DateFormat, Style},
     widgets::{
-        local_time::{LocalTimeState, LocalTimeStateArgs, LocalTimeWidget},
+        local_time::{LocalTimeState, LocalTimeStateArgs, LocalTimeStyle, LocalTimeWidget},
         test_utils::{DrawArgs, FIXED_TIME, FIXED_TIME_AM, draw},
     },
 };
@@ -12,6 +12,7 @@ fn args() -> LocalTimeStateArgs {
     LocalTimeStateArgs {
         app_time: AppTime::Utc(FIXED_TIME),
         app_time_format: AppTimeFormat::HhMmSs,
+        date_format: DateFormat::default(),
     }
 }
 
@@ -22,6 +23,9 @@ fn st_with_args(args: LocalTimeStateArgs) -> LocalTimeState {
 fn w() -> LocalTimeWidget {
     LocalTimeWidget {
         style: Style::default(),
+        flip: None,
+        local_time_style: LocalTimeStyle::default(),
+        dim: false,
     }
 }
 
@@ -71,7 +75,73 @@ fn test_local_time_hh12mm_am() {
         st_with_args(LocalTimeStateArgs {
             app_time: AppTime::Utc(FIXED_TIME_AM),
             app_time_format: AppTimeFormat::Hh12Mm,
+            ..args()
         }),
     );
     assert_snapshot!("local_time_hh12mm_am", t.backend());
 }
+
+#[test]
+fn test_local_time_hh12mmss_pm() {
+    let t = terminal(
+        w(),
+        st_with_args(LocalTimeStateArgs {
+            app_time_format: AppTimeFormat::Hh12MmSs,
+            ..args()
+        }),
+    );
+    assert_snapshot!("local_time_hh12mmss_pm", t.backend());
+}
+
+#[test]
+fn test_local_time_hh12mmss_am() {
+    let t = terminal(
+        w(),
+        st_with_args(LocalTimeStateArgs {
+            app_time: AppTime::Utc(FIXED_TIME_AM),
+            app_time_format: AppTimeFormat::Hh12MmSs,
+            ..args()
+        }),
+    );
+    assert_snapshot!("local_time_hh12mmss_am", t.backend());
+}
+
+#[test]
+fn test_local_time_date_format() {
+    let t = terminal(
+        w(),
+        st_with_args(LocalTimeStateArgs {
+            date_format: DateFormat::parse("[weekday repr:short] [day] [month repr:short]")
+                .unwrap(),
+            ..args()
+        }),
+    );
+    assert_snapshot!("local_time_date_format", t.backend());
+}
+
+#[test]
+fn test_local_time_binary_hhmmss() {
+    let t = terminal(
+        LocalTimeWidget {
+            local_time_style: LocalTimeStyle::Binary,
+            ..w()
+        },
+        st_with_args(args()),
+    );
+    assert_snapshot!("local_time_binary_hhmmss", t.backend());
+}
+
+#[test]
+fn test_local_time_binary_hh12mm() {
+    let t = terminal(
+        LocalTimeWidget {
+            local_time_style: LocalTimeStyle::Binary,
+            ..w()
+        },
+        st_with_args(LocalTimeStateArgs {
+            app_time_format: AppTimeFormat::Hh12Mm,
+            ..args()
+        }),
+    );
+    assert_snapshot!("local_time_binary_hh12mm", t.backend());
+}
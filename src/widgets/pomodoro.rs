@@ -1,19 +1,26 @@
 use crate::{
-    common::{ClockDescription, ClockName, Style},
+    common::{
+        AppTime, AppTimeFormat, ClockDescription, ClockName, DecimalSeparator, Flip, Spacing, Style,
+    },
     constants::{TABATA_MAX_ROUNDS, TABATA_PAUSE, TABATA_WORK, TICK_VALUE_MS},
-    events::{AppEventTx, TuiEvent, TuiEventHandler},
-    widgets::clock::{ClockState, ClockStateArgs, ClockWidget, Countdown},
+    events::{AppEvent, AppEventTx, TuiEvent, TuiEventHandler},
+    widgets::{
+        clock::{ClockState, ClockStateArgs, ClockWidget, Countdown, urgency_color},
+        progressbar::BlockBar,
+    },
 };
-use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyModifiers};
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyModifiers, MouseEventKind};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
+    style::Modifier,
     text::Line,
     widgets::{StatefulWidget, Widget},
 };
 use serde::{Deserialize, Serialize};
 use std::{cmp::max, time::Duration};
 use strum::Display;
+use time::{Date, OffsetDateTime};
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum PauseDuration {
@@ -83,6 +90,16 @@ pub struct PomodoroState {
     vim_motions: bool,
     auto_switch: bool,
     max_rounds: Option<u64>,
+    /// Daily goal of work rounds
+    goal: Option<u64>,
+    /// Work rounds done at `goal_date`
+    goal_progress: u64,
+    goal_date: Date,
+    /// Current time to compute time to finish by
+    app_time: AppTime,
+    app_tx: AppEventTx,
+    /// Ticks of a running pause to animate it (see `breathe_modifier`)
+    breathe_count: u64,
 }
 
 pub struct PomodoroStateArgs {
@@ -97,6 +114,10 @@ pub struct PomodoroStateArgs {
     pub vim_motions: bool,
     pub auto_switch: bool,
     pub max_rounds: Option<u64>,
+    pub goal: Option<u64>,
+    pub goal_progress: u64,
+    pub goal_date: Date,
+    pub app_time: AppTime,
 }
 
 impl PomodoroState {
@@ -113,6 +134,10 @@ impl PomodoroState {
             vim_motions,
             auto_switch,
             max_rounds,
+            goal,
+            goal_progress,
+            goal_date,
+            app_time,
         } = args;
         let mut state = Self {
             mode,
@@ -129,7 +154,7 @@ impl PomodoroState {
                     current_value: current_value_pause,
                     tick_value: Duration::from_millis(TICK_VALUE_MS),
                     with_decis,
-                    app_tx: Some(app_tx),
+                    app_tx: Some(app_tx.clone()),
                 }),
             },
             round,
@@ -137,6 +162,12 @@ impl PomodoroState {
             vim_motions,
             auto_switch,
             max_rounds,
+            goal,
+            goal_progress,
+            goal_date,
+            app_time,
+            app_tx,
+            breathe_count: 0,
         };
         state.update_clock_names();
         state
@@ -170,6 +201,10 @@ impl PomodoroState {
         &self.mode
     }
 
+    pub fn set_running(&mut self, running: bool) {
+        self.get_clock_mut().set_running(running);
+    }
+
     pub fn get_round(&self) -> u64 {
         self.round
     }
@@ -186,6 +221,47 @@ impl PomodoroState {
         self.max_rounds
     }
 
+    pub fn get_goal(&self) -> Option<u64> {
+        self.goal
+    }
+
+    pub fn get_goal_progress(&self) -> u64 {
+        self.goal_progress
+    }
+
+    pub fn get_goal_date(&self) -> Date {
+        self.goal_date
+    }
+
+    pub fn is_goal_reached(&self) -> bool {
+        self.goal.is_some_and(|goal| self.goal_progress >= goal)
+    }
+
+    /// Sets `app_time` and the date of its goal (see `set_date`)
+    pub fn set_app_time(&mut self, app_time: AppTime) {
+        self.app_time = app_time;
+        self.set_date(OffsetDateTime::from(app_time).date());
+    }
+
+    /// Progress of a goal is counted per day. It will be reset on a new day.
+    pub fn set_date(&mut self, date: Date) {
+        if date != self.goal_date {
+            self.goal_date = date;
+            self.goal_progress = 0;
+        }
+    }
+
+    fn count_goal_progress(&mut self) {
+        self.goal_progress += 1;
+        if let Some(goal) = self.goal
+            && self.goal_progress == goal
+        {
+            _ = self
+                .app_tx
+                .send(AppEvent::GoalReached(self.pomodoro_name(), goal));
+        }
+    }
+
     fn is_last_round(&self) -> bool {
         self.max_rounds.is_some_and(|m| self.round >= m)
     }
@@ -195,6 +271,19 @@ impl PomodoroState {
         self.is_last_round() && self.get_clock_work().is_done()
     }
 
+    /// Whether a pause is running (to animate it)
+    pub fn is_breathing(&self) -> bool {
+        self.mode == Mode::Pause && self.get_clock().is_running()
+    }
+
+    fn update_breathe_count(&mut self) {
+        self.breathe_count = if self.is_breathing() {
+            self.breathe_count.wrapping_add(1)
+        } else {
+            0
+        };
+    }
+
     pub fn is_tabata(&self) -> bool {
         *self.get_clock_work().get_initial_value() == TABATA_WORK.into()
             && self.pause_duration == PauseDuration::Fixed(TABATA_PAUSE)
@@ -250,6 +339,16 @@ impl PomodoroState {
         self.get_clock_pause_mut().set_initial_value(initial.into());
     }
 
+    pub fn set_max_value(&mut self, value: Duration) {
+        self.clock_map.work.set_max_value(value);
+        self.clock_map.pause.set_max_value(value);
+    }
+
+    pub fn set_jump_step(&mut self, step: u32) {
+        self.clock_map.work.set_jump_step(step);
+        self.clock_map.pause.set_jump_step(step);
+    }
+
     pub fn set_with_decis(&mut self, with_decis: bool) {
         self.clock_map.work.with_decis = with_decis;
         self.clock_map.pause.with_decis = with_decis;
@@ -294,6 +393,20 @@ impl PomodoroState {
         self.get_clock_work_mut().reset();
     }
 
+    /// Adjusts the round counter only, clocks are kept untouched.
+    fn increase_round(&mut self) {
+        if !self.is_last_round() {
+            self.round += 1;
+            self.update_clock_names();
+        }
+    }
+
+    /// Adjusts the round counter only, clocks are kept untouched.
+    fn decrease_round(&mut self) {
+        self.round = self.round.saturating_sub(1).max(1);
+        self.update_clock_names();
+    }
+
     // Switch `Mode`
     fn switch_mode(&mut self) {
         match self.mode {
@@ -317,6 +430,10 @@ impl PomodoroState {
         if !self.is_last_round() {
             self.switch_mode();
             self.get_clock_mut().run();
+            _ = self.app_tx.send(AppEvent::PomodoroSwitched(
+                self.mode.clone(),
+                self.mode == Mode::Pause && self.pause_duration.is_special_round(self.round),
+            ));
         }
     }
 }
@@ -326,8 +443,13 @@ impl TuiEventHandler for PomodoroState {
         let edit_mode = self.get_clock().is_edit_mode();
         match event {
             TuiEvent::Tick => {
+                let work_was_done = self.get_clock_work().is_done();
                 self.get_clock_mut().tick();
+                if !work_was_done && self.get_clock_work().is_done() {
+                    self.count_goal_progress();
+                }
                 self.get_clock_mut().update_done_count();
+                self.update_breathe_count();
                 if self.auto_switch && self.get_clock().is_done_counted() {
                     self.switch_mode_auto();
                 }
@@ -399,6 +521,12 @@ impl TuiEventHandler for PomodoroState {
                 }
                 _ => return Some(event),
             },
+            // change value by scrolling (EDIT mode only)
+            TuiEvent::Mouse(mouse) if edit_mode => match mouse.kind {
+                MouseEventKind::ScrollUp => self.get_clock_mut().edit_up(),
+                MouseEventKind::ScrollDown => self.get_clock_mut().edit_down(),
+                _ => return Some(event),
+            },
             // default mode
             TuiEvent::Crossterm(CrosstermEvent::Key(key)) => match key.code {
                 // Toggle run/pause
@@ -462,6 +590,9 @@ impl TuiEventHandler for PomodoroState {
                 KeyCode::Char('a') => {
                     self.auto_switch = !self.auto_switch;
                 }
+                // adjust round counter
+                KeyCode::Char(']') => self.increase_round(),
+                KeyCode::Char('[') => self.decrease_round(),
                 // reset rounds AND clocks
                 KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.round = 1;
@@ -481,20 +612,64 @@ impl TuiEventHandler for PomodoroState {
     }
 }
 
+/// Ticks of one breath (4 sec.)
+const BREATHE_TICKS: u64 = 40;
+
+/// Helper to get a `Modifier` to let digits breathe while rendering.
+/// Its phases (dim, normal, bold, normal) are based on a given `count` value.
+pub fn breathe_modifier(count: u64) -> Modifier {
+    match (count % BREATHE_TICKS) * 4 / BREATHE_TICKS {
+        0 => Modifier::DIM,
+        2 => Modifier::BOLD,
+        _ => Modifier::empty(),
+    }
+}
+
 pub struct PomodoroWidget {
     pub style: Style,
     pub blink: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
+    /// Change color of digits depending on time left
+    pub urgency_colors: bool,
+    /// Renders labels of years (`Y`) and days (`D`)
+    pub unit_labels: bool,
+    /// Renders a line of time left, time to finish and percentage done below label
+    pub status_line: bool,
+    /// Format of time to finish in status line
+    pub app_time_format: AppTimeFormat,
+    /// Space between digits and around colons
+    pub spacing: Spacing,
+    /// Hides seconds of values exceeding it
+    pub hide_seconds_after: Option<Duration>,
+    /// Pulses digits of a running pause
+    pub breathe: bool,
+    /// Appends initial value of current clock (work or pause) to label
+    pub show_initial: bool,
+    /// Renders a `BlockBar` below label
+    pub bar: bool,
+    /// Dims digits (`--dim`)
+    pub dim: bool,
 }
 
 impl StatefulWidget for PomodoroWidget {
     type State = PomodoroState;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let clock_widget = ClockWidget::new(self.style, self.blink);
+        let clock_widget =
+            ClockWidget::new(self.style, self.blink, self.decimal_separator, self.flip)
+                .unit_labels(self.unit_labels)
+                .spacing(self.spacing)
+                .hide_seconds_after(self.hide_seconds_after)
+                .dim(self.dim)
+                .color(
+                    self.urgency_colors
+                        .then(|| urgency_color(state.get_clock().get_percentage_done())),
+                );
         let is_special_pause = state.get_mode() == &Mode::Pause
             && state
                 .get_pause_duration()
                 .is_special_round(state.get_round());
-        let label = Line::raw(
+        let mut label = Line::raw(
             (format!(
                 "{} {} {}{}",
                 if state.is_tabata() {
@@ -508,28 +683,66 @@ impl StatefulWidget for PomodoroWidget {
             ))
             .to_uppercase(),
         );
+        if self.show_initial {
+            label.push_span(format!(" / {}", state.get_clock().get_initial_value()));
+        }
         let label_round = Line::raw(match state.get_max_rounds() {
             Some(max) => format!("ROUND {} OF {}", state.get_round(), max),
             None => format!("ROUND {}", state.get_round()),
         });
+        let label_goal = state.get_goal().map(|goal| {
+            Line::raw(format!(
+                "GOAL {}/{}{}",
+                state.get_goal_progress(),
+                goal,
+                if state.is_goal_reached() {
+                    " REACHED!"
+                } else {
+                    ""
+                }
+            ))
+        });
+        let label_goal_height = label_goal.as_ref().map_or(0, |_| 1);
+        let label_status = self.status_line.then(|| {
+            Line::raw(
+                state
+                    .get_clock()
+                    .get_status_line(state.app_time, &self.app_time_format)
+                    .to_uppercase(),
+            )
+        });
+        let label_status_height = label_status.as_ref().map_or(0, |_| 1);
+        let bar_height = if self.bar { 1 } else { 0 };
 
         let area = area.centered(
             Constraint::Length(max(
-                clock_widget
-                    .get_width(state.get_clock().get_format(), state.get_clock().with_decis),
-                max(label.width() as u16, label_round.width() as u16),
+                clock_widget.get_width(state.get_clock()),
+                max(
+                    max(label.width() as u16, label_round.width() as u16),
+                    max(
+                        label_goal.as_ref().map_or(0, |l| l.width() as u16),
+                        label_status.as_ref().map_or(0, |l| l.width() as u16),
+                    ),
+                ),
             )),
             Constraint::Length(
-                // empty label + height of `label` + `label_round`
-                clock_widget.get_height() + 3,
+                // empty label + height of `label` + `label_round` + (optional) `label_goal` + (optional) `label_status` + (optional) bar
+                clock_widget.get_height()
+                    + 3
+                    + label_goal_height
+                    + label_status_height
+                    + bar_height,
             ),
         );
 
-        let [v1, v2, v3, v4] = Layout::vertical(Constraint::from_lengths([
+        let [v1, v2, v3, v_bar, v_status, v4, v5] = Layout::vertical(Constraint::from_lengths([
             1,
             clock_widget.get_height(),
             1,
+            bar_height,
+            label_status_height,
             1,
+            label_goal_height,
         ]))
         .areas(area);
 
@@ -537,7 +750,19 @@ impl StatefulWidget for PomodoroWidget {
         // views (which have one label below the clock only)
         Line::raw("").centered().render(v1, buf);
         clock_widget.render(v2, buf, state.get_clock_mut());
+        if self.breathe && state.is_breathing() {
+            buf.set_style(v2, breathe_modifier(state.breathe_count));
+        }
         label.centered().render(v3, buf);
+        if self.bar {
+            BlockBar::new(state.get_clock()).render(v_bar, buf);
+        }
+        if let Some(label_status) = label_status {
+            label_status.centered().render(v_status, buf);
+        }
         label_round.centered().render(v4, buf);
+        if let Some(label_goal) = label_goal {
+            label_goal.centered().render(v5, buf);
+        }
     }
 }
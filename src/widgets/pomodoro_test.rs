@@ -1,28 +1,38 @@
 use crate::{
-    common::Style,
+    common::{AppTime, AppTimeFormat, DecimalSeparator, Spacing, Style},
     constants::{TABATA_MAX_ROUNDS, TABATA_PAUSE, TABATA_WORK},
     duration::{ONE_MINUTE, ONE_SECOND},
     events::{TuiEvent, TuiEventHandler},
     widgets::{
-        pomodoro::{Mode, PauseDuration, PomodoroState, PomodoroStateArgs, PomodoroWidget},
-        test_utils::{DrawArgs, Key, draw},
+        pomodoro::{
+            Mode, PauseDuration, PomodoroState, PomodoroStateArgs, PomodoroWidget, breathe_modifier,
+        },
+        test_utils::{DrawArgs, FIXED_TIME, Key, app_channel, app_tx, draw},
     },
 };
 use insta::assert_snapshot;
-use ratatui::{Terminal, backend::TestBackend};
+use ratatui::{Terminal, backend::TestBackend, style::Modifier};
 use std::time::Duration;
 
 const WORK: Duration = ONE_MINUTE.saturating_mul(25); /* 25min */
 const PAUSE: Duration = ONE_MINUTE.saturating_mul(5); /* 5min */
 
-fn app_tx() -> crate::events::AppEventTx {
-    tokio::sync::mpsc::unbounded_channel().0
-}
-
 fn w() -> PomodoroWidget {
     PomodoroWidget {
         style: Style::default(),
         blink: false,
+        decimal_separator: DecimalSeparator::default(),
+        flip: None,
+        unit_labels: true,
+        urgency_colors: false,
+        status_line: false,
+        app_time_format: AppTimeFormat::default(),
+        spacing: Spacing::default(),
+        hide_seconds_after: None,
+        breathe: false,
+        show_initial: false,
+        bar: false,
+        dim: false,
     }
 }
 
@@ -39,6 +49,10 @@ fn args() -> PomodoroStateArgs {
         vim_motions: false,
         auto_switch: false,
         max_rounds: None,
+        goal: None,
+        goal_progress: 0,
+        goal_date: FIXED_TIME.date(),
+        app_time: AppTime::Utc(FIXED_TIME),
     }
 }
 
@@ -95,6 +109,28 @@ fn test_max_rounds_last_round() {
 
 // work
 
+#[test]
+fn test_adjust_round() {
+    let mut st = st_with_args(PomodoroStateArgs {
+        current_value_work: WORK - ONE_MINUTE,
+        max_rounds: Some(3),
+        ..args()
+    });
+    st.update(Key::RoundDown.into());
+    assert_eq!(st.get_round(), 1);
+    for _ in 0..5 {
+        st.update(Key::RoundUp.into());
+    }
+    assert_eq!(st.get_round(), 3);
+    st.update(Key::RoundDown.into());
+    assert_eq!(st.get_round(), 2);
+    // clock is kept untouched
+    assert_eq!(
+        Duration::from(*st.get_clock_work().get_current_value()),
+        WORK - ONE_MINUTE
+    );
+}
+
 #[test]
 fn test_work_pause() {
     let t = terminal(w(), st());
@@ -134,6 +170,17 @@ fn test_work_done() {
     assert_snapshot!("work_done", t.backend());
 }
 
+#[test]
+fn test_work_bar() {
+    let mut st = st_with_args(PomodoroStateArgs {
+        current_value_work: WORK.saturating_mul(3) / 4,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    let t = terminal(PomodoroWidget { bar: true, ..w() }, st);
+    assert_snapshot!("work_bar", t.backend());
+}
+
 #[test]
 fn test_work_edit_minutes() {
     let mut st = st();
@@ -185,3 +232,118 @@ fn test_tabata_pause() {
     let t = terminal(w(), st);
     assert_snapshot!("tabata_pause", t.backend());
 }
+
+#[test]
+fn test_goal_progress() {
+    let mut st = st_with_args(PomodoroStateArgs {
+        current_value_work: ONE_SECOND / 10,
+        goal: Some(8),
+        goal_progress: 4,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    assert_eq!(st.get_goal_progress(), 5);
+    let t = terminal(w(), st);
+    assert_snapshot!("goal_progress", t.backend());
+}
+
+#[test]
+fn test_goal_reached() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_with_args(PomodoroStateArgs {
+        current_value_work: ONE_SECOND / 10,
+        goal: Some(8),
+        goal_progress: 7,
+        app_tx: tx,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    assert!(st.is_goal_reached());
+    assert!(
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .any(|e| matches!(e, crate::events::AppEvent::GoalReached(_, 8)))
+    );
+    let t = terminal(w(), st);
+    assert_snapshot!("goal_reached", t.backend());
+}
+
+#[test]
+fn test_goal_reset_next_day() {
+    let mut st = st_with_args(PomodoroStateArgs {
+        goal: Some(8),
+        goal_progress: 7,
+        ..args()
+    });
+    st.set_date(FIXED_TIME.date());
+    assert_eq!(st.get_goal_progress(), 7);
+    st.set_date(FIXED_TIME.date().next_day().unwrap());
+    assert_eq!(st.get_goal_progress(), 0);
+}
+
+#[test]
+fn test_pomodoro_status_line() {
+    let st = st_with_args(PomodoroStateArgs {
+        current_value_work: WORK - ONE_MINUTE.saturating_mul(10),
+        ..args()
+    });
+    let t = terminal(
+        PomodoroWidget {
+            status_line: true,
+            ..w()
+        },
+        st,
+    );
+    assert_snapshot!("pomodoro_status_line", t.backend());
+}
+
+#[test]
+fn test_pomodoro_show_initial() {
+    let mut st = st_with_args(PomodoroStateArgs {
+        current_value_work: WORK - ONE_MINUTE,
+        ..args()
+    });
+    st.update(Key::StartStop.into());
+    let t = terminal(
+        PomodoroWidget {
+            show_initial: true,
+            ..w()
+        },
+        st,
+    );
+    assert_snapshot!("pomodoro_show_initial", t.backend());
+}
+
+#[test]
+fn test_breathe_modifier() {
+    assert_eq!(breathe_modifier(0), Modifier::DIM);
+    assert_eq!(breathe_modifier(10), Modifier::empty());
+    assert_eq!(breathe_modifier(25), Modifier::BOLD);
+    assert_eq!(breathe_modifier(39), Modifier::empty());
+    // next breath
+    assert_eq!(breathe_modifier(40), Modifier::DIM);
+}
+
+#[test]
+fn test_breathe() {
+    let mut st = st_with_args(PomodoroStateArgs {
+        mode: Mode::Pause,
+        ..args()
+    });
+    assert!(!st.is_breathing());
+    st.update(Key::StartStop.into());
+    assert!(st.is_breathing());
+    for _ in 0..25 {
+        st.update(TuiEvent::Tick);
+    }
+    let t = terminal(
+        PomodoroWidget {
+            breathe: true,
+            ..w()
+        },
+        st,
+    );
+    // digits (centered vertically)
+    assert_eq!(t.backend().buffer()[(35, 6)].modifier, Modifier::BOLD);
+}
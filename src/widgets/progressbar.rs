@@ -1,9 +1,13 @@
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
-    symbols::line,
+    style::{Modifier, Style},
+    symbols::{block, line},
     widgets::{Fill, Widget},
 };
+use std::time::Duration;
+
+use crate::widgets::clock::ClockState;
 
 #[derive(Debug, Clone)]
 pub struct Progressbar {
@@ -25,3 +29,67 @@ impl Widget for Progressbar {
         Fill::new(line::HORIZONTAL).render(h2, buf);
     }
 }
+
+/// Eighth blocks filled from left, indexed by eighths (`0` = empty)
+const EIGHTHS: [&str; 8] = [
+    " ",
+    block::ONE_EIGHTH,
+    block::ONE_QUARTER,
+    block::THREE_EIGHTHS,
+    block::HALF,
+    block::FIVE_EIGHTHS,
+    block::THREE_QUARTERS,
+    block::SEVEN_EIGHTHS,
+];
+
+/// Bar of block characters, which drains from left to right by time passed.
+/// Its edge is rendered smoothly by eighth blocks.
+#[derive(Debug, Clone)]
+pub struct BlockBar {
+    /// Time passed
+    pub done: Duration,
+    pub total: Duration,
+}
+
+impl BlockBar {
+    /// Bar of time passed of given clock
+    pub fn new<T>(clock: &ClockState<T>) -> Self {
+        let total = Duration::from(*clock.get_initial_value());
+        Self {
+            done: total.saturating_sub(Duration::from(*clock.get_current_value())),
+            total,
+        }
+    }
+
+    /// Number of (horizontal) eighths of given width, which are drained
+    fn drained_eighths(&self, width: u16) -> u128 {
+        let eighths = u128::from(width) * 8;
+        if self.total.is_zero() {
+            return 0;
+        }
+        (self.done.as_millis() * eighths / self.total.as_millis()).min(eighths)
+    }
+}
+
+impl Widget for BlockBar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // e.g. no space left in tiny terminals
+        if area.is_empty() {
+            return;
+        }
+        let drained = self.drained_eighths(area.width);
+        let (cells, eighths) = ((drained / 8) as u16, (drained % 8) as usize);
+        for x in area.left()..area.right() {
+            let cell = &mut buf[(x, area.y)];
+            match x - area.left() {
+                i if i < cells => cell.set_symbol(" "),
+                // Eighth blocks are filled from left only. To fill it from right
+                // drained eighths are rendered reversed.
+                i if i == cells && eighths > 0 => cell
+                    .set_symbol(EIGHTHS[eighths])
+                    .set_style(Style::new().add_modifier(Modifier::REVERSED)),
+                _ => cell.set_symbol(block::FULL),
+            };
+        }
+    }
+}
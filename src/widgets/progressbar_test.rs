@@ -0,0 +1,49 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::Widget,
+};
+use std::time::Duration;
+
+use crate::widgets::progressbar::BlockBar;
+
+const W: u16 = 10;
+const RECT: Rect = Rect::new(0, 0, W, 1);
+
+#[test]
+fn test_block_bar() {
+    let render = |done: u64| {
+        let mut b = Buffer::empty(RECT);
+        BlockBar {
+            done: Duration::from_secs(done),
+            total: Duration::from_secs(80),
+        }
+        .render(RECT, &mut b);
+        b
+    };
+    assert_eq!(render(0), Buffer::with_lines(["██████████"]));
+    // 3 of 80 eighths drained
+    let mut expected = Buffer::with_lines(["▍█████████"]);
+    expected.set_style(
+        Rect::new(0, 0, 1, 1),
+        Style::new().add_modifier(Modifier::REVERSED),
+    );
+    assert_eq!(render(3), expected);
+    assert_eq!(render(40), Buffer::with_lines(["     █████"]));
+    assert_eq!(render(80), Buffer::with_lines(["          "]));
+    assert_eq!(render(100), Buffer::with_lines(["          "]));
+}
+
+#[test]
+fn test_block_bar_empty_area() {
+    // area at bottom of buffer w/o any height
+    let area = Rect::new(0, 1, W, 0);
+    let mut b = Buffer::empty(RECT);
+    BlockBar {
+        done: Duration::from_secs(3),
+        total: Duration::from_secs(80),
+    }
+    .render(area, &mut b);
+    assert_eq!(b, Buffer::empty(RECT));
+}
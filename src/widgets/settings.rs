@@ -0,0 +1,120 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, StatefulWidget, Widget},
+};
+use strum::Display;
+
+/// Settings which can be changed in-app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum SettingsItem {
+    #[strum(to_string = "style")]
+    Style,
+    #[strum(to_string = "deciseconds")]
+    Decis,
+    #[strum(to_string = "decimal separator")]
+    DecimalSeparator,
+    #[strum(to_string = "notification")]
+    Notification,
+    #[strum(to_string = "blink")]
+    Blink,
+    #[strum(to_string = "dim")]
+    Dim,
+    #[strum(to_string = "elapsed time (MET)")]
+    Met,
+}
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 7] = [
+        SettingsItem::Style,
+        SettingsItem::Decis,
+        SettingsItem::DecimalSeparator,
+        SettingsItem::Notification,
+        SettingsItem::Blink,
+        SettingsItem::Dim,
+        SettingsItem::Met,
+    ];
+}
+
+/// State for `SettingsWidget`
+#[derive(Debug, Clone, Default)]
+pub struct SettingsState {
+    show: bool,
+    /// Index of selected item in `SettingsItem::ALL`
+    selected: usize,
+}
+
+impl SettingsState {
+    pub fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    pub fn toggle(&mut self) {
+        self.show = !self.show;
+    }
+
+    pub fn hide(&mut self) {
+        self.show = false;
+    }
+
+    pub fn get_selected(&self) -> SettingsItem {
+        SettingsItem::ALL[self.selected]
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % SettingsItem::ALL.len();
+    }
+
+    pub fn prev(&mut self) {
+        self.selected = self
+            .selected
+            .checked_sub(1)
+            .unwrap_or(SettingsItem::ALL.len() - 1);
+    }
+}
+
+/// Overlay to list and change settings
+pub struct SettingsWidget {
+    /// Values of `SettingsItem::ALL` (in same order)
+    pub values: [String; SettingsItem::ALL.len()],
+}
+
+const LABEL_WIDTH: u16 = 20;
+const VALUE_WIDTH: u16 = 12;
+
+impl StatefulWidget for SettingsWidget {
+    type State = SettingsState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let hint = Line::raw(" ↑↓ select  ←→ change  esc close ");
+        let width = (LABEL_WIDTH + VALUE_WIDTH).max(hint.width() as u16) + 4 /* borders + padding */;
+        let height = SettingsItem::ALL.len() as u16 + 2 /* borders */;
+        let area = area.centered(Constraint::Length(width), Constraint::Length(height));
+
+        Clear.render(area, buf);
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(" settings ").centered())
+            .title_bottom(hint.centered());
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let inner = inner.centered_horizontally(Constraint::Length(inner.width.saturating_sub(2)));
+        let rows =
+            Layout::vertical(vec![Constraint::Length(1); SettingsItem::ALL.len()]).split(inner);
+        for (index, (item, value)) in SettingsItem::ALL.iter().zip(self.values).enumerate() {
+            let [label_area, value_area] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Length(VALUE_WIDTH)])
+                    .areas(rows[index]);
+            let style = if index == state.selected {
+                Style::new().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::new()
+            };
+            buf.set_style(rows[index], style);
+            Line::raw(item.to_string()).render(label_area, buf);
+            Line::raw(value).right_aligned().render(value_area, buf);
+        }
+    }
+}
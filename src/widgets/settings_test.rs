@@ -0,0 +1,53 @@
+use crate::widgets::{
+    settings::{SettingsItem, SettingsState, SettingsWidget},
+    test_utils::{DrawArgs, draw},
+};
+use insta::assert_snapshot;
+use ratatui::{Terminal, backend::TestBackend};
+
+fn w() -> SettingsWidget {
+    SettingsWidget {
+        values: ["full", "off", ".", "on", "off", "off", "on"].map(String::from),
+    }
+}
+
+fn terminal(w: SettingsWidget, st: SettingsState) -> Terminal<TestBackend> {
+    draw(DrawArgs {
+        widget: w,
+        state: st,
+        width: 60,
+        height: 12,
+    })
+}
+
+#[test]
+fn test_settings() {
+    let mut st = SettingsState::default();
+    st.next();
+    let t = terminal(w(), st);
+    assert_snapshot!("settings", t.backend());
+}
+
+#[test]
+fn test_settings_select() {
+    let mut st = SettingsState::default();
+    assert_eq!(st.get_selected(), SettingsItem::Style);
+    st.next();
+    assert_eq!(st.get_selected(), SettingsItem::Decis);
+    st.prev();
+    st.prev();
+    // wrap around
+    assert_eq!(st.get_selected(), SettingsItem::Met);
+    st.next();
+    assert_eq!(st.get_selected(), SettingsItem::Style);
+}
+
+#[test]
+fn test_settings_toggle() {
+    let mut st = SettingsState::default();
+    assert!(!st.is_shown());
+    st.toggle();
+    assert!(st.is_shown());
+    st.hide();
+    assert!(!st.is_shown());
+}
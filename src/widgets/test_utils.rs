@@ -1,15 +1,27 @@
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use ratatui::{Terminal, backend::TestBackend, widgets::StatefulWidget};
 use time::{OffsetDateTime, macros::datetime};
 
-use crate::events::TuiEvent;
+use crate::events::{AppEventRx, AppEventTx, TuiEvent};
 
 pub const FIXED_TIME: OffsetDateTime = datetime!(2024-06-10 14:30:00 UTC);
 pub const FIXED_TIME_AM: OffsetDateTime = datetime!(2024-06-10 09:30:00 UTC);
 
+/// Stub of `AppEventTx` to create states, which are not checked for any `AppEvent`
+pub fn app_tx() -> AppEventTx {
+    app_channel().0
+}
+
+/// `AppEventTx` and its `AppEventRx` to check `AppEvent`s sent by a state
+pub fn app_channel() -> (AppEventTx, AppEventRx) {
+    tokio::sync::mpsc::unbounded_channel()
+}
+
 pub enum Key {
     StartStop,
     Edit,
+    RoundUp,
+    RoundDown,
 }
 
 impl From<Key> for TuiEvent {
@@ -17,11 +29,33 @@ impl From<Key> for TuiEvent {
         let code = match action {
             Key::StartStop => KeyCode::Char(' '),
             Key::Edit => KeyCode::Char('e'),
+            Key::RoundUp => KeyCode::Char(']'),
+            Key::RoundDown => KeyCode::Char('['),
         };
         TuiEvent::Crossterm(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
     }
 }
 
+pub enum Mouse {
+    ScrollUp,
+    ScrollDown,
+}
+
+impl From<Mouse> for TuiEvent {
+    fn from(action: Mouse) -> Self {
+        let kind = match action {
+            Mouse::ScrollUp => MouseEventKind::ScrollUp,
+            Mouse::ScrollDown => MouseEventKind::ScrollDown,
+        };
+        TuiEvent::Mouse(MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+}
+
 pub struct DrawArgs<W>
 where
     W: StatefulWidget,
@@ -51,3 +85,20 @@ where
         .unwrap();
     terminal
 }
+
+/// Rendered lines of a `TestBackend` (w/o trailing whitespace),
+/// e.g. to compare them with expected lines
+pub fn lines(terminal: &Terminal<TestBackend>) -> Vec<String> {
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_owned()
+        })
+        .collect()
+}
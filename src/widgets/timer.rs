@@ -1,9 +1,10 @@
 use crate::{
-    common::Style,
-    events::{TuiEvent, TuiEventHandler},
+    common::{AppTime, DecimalSeparator, Flip, Spacing, Style},
+    duration::DurationEx,
+    events::{AppEvent, AppEventTx, TuiEvent, TuiEventHandler},
     widgets::clock::{self, ClockState, ClockWidget},
 };
-use crossterm::event::{Event as CrosstermEvent, KeyModifiers};
+use crossterm::event::{Event as CrosstermEvent, KeyModifiers, MouseEventKind};
 use ratatui::{
     buffer::Buffer,
     crossterm::event::KeyCode,
@@ -11,25 +12,71 @@ use ratatui::{
     text::Line,
     widgets::{StatefulWidget, Widget},
 };
-use std::cmp::max;
+use std::{cmp::max, time::Duration};
 
 pub struct TimerState {
     clock: ClockState<clock::Timer>,
     vim_motions: bool,
+    /// Soft goal to reach, the timer keeps counting afterwards
+    goal: Option<Duration>,
+    /// Whether reaching `goal` has been notified already
+    goal_notified: bool,
+    app_tx: Option<AppEventTx>,
 }
 
 impl TimerState {
     pub fn new(clock: ClockState<clock::Timer>, vim_motions: bool) -> Self {
-        Self { clock, vim_motions }
+        Self {
+            clock,
+            vim_motions,
+            goal: None,
+            goal_notified: false,
+            app_tx: None,
+        }
+    }
+
+    /// Sets a soft goal (if any). Reaching it is notified once by `AppEvent::TimerGoalReached`.
+    pub fn with_goal(mut self, goal: Option<Duration>, app_tx: AppEventTx) -> Self {
+        self.goal = goal;
+        // don't notify a goal reached before
+        self.goal_notified = self.is_goal_reached();
+        self.app_tx = Some(app_tx);
+        self
+    }
+
+    pub fn is_goal_reached(&self) -> bool {
+        self.goal
+            .is_some_and(|goal| Duration::from(*self.clock.get_current_value()) >= goal)
+    }
+
+    /// Notifies reaching `goal` once.
+    /// Going below it again (e.g. by resetting the timer) allows to notify it next time.
+    fn check_goal(&mut self) {
+        let reached = self.is_goal_reached();
+        if reached
+            && !self.goal_notified
+            && let (Some(goal), Some(tx)) = (self.goal, &self.app_tx)
+        {
+            _ = tx.send(AppEvent::TimerGoalReached(goal));
+        }
+        self.goal_notified = reached;
     }
 
     pub fn set_with_decis(&mut self, with_decis: bool) {
         self.clock.with_decis = with_decis;
     }
 
+    pub fn set_app_time(&mut self, app_time: AppTime) {
+        self.clock.set_app_time(app_time);
+    }
+
     pub fn get_clock(&self) -> &ClockState<clock::Timer> {
         &self.clock
     }
+
+    pub fn set_running(&mut self, running: bool) {
+        self.clock.set_running(running);
+    }
 }
 
 impl TuiEventHandler for TimerState {
@@ -39,6 +86,7 @@ impl TuiEventHandler for TimerState {
             TuiEvent::Tick => {
                 self.clock.tick();
                 self.clock.update_done_count();
+                self.check_goal();
             }
             // EDIT mode
             TuiEvent::Crossterm(CrosstermEvent::Key(key)) if edit_mode => match key.code {
@@ -99,6 +147,12 @@ impl TuiEventHandler for TimerState {
                 }
                 _ => return Some(event),
             },
+            // change value by scrolling (EDIT mode only)
+            TuiEvent::Mouse(mouse) if edit_mode => match mouse.kind {
+                MouseEventKind::ScrollUp => self.clock.edit_up(),
+                MouseEventKind::ScrollDown => self.clock.edit_down(),
+                _ => return Some(event),
+            },
             // default mode
             TuiEvent::Crossterm(CrosstermEvent::Key(key)) => match key.code {
                 // Toggle run/pause
@@ -124,26 +178,86 @@ impl TuiEventHandler for TimerState {
 pub struct Timer {
     pub style: Style,
     pub blink: bool,
+    pub decimal_separator: DecimalSeparator,
+    pub flip: Option<Flip>,
+    /// Renders labels of years (`Y`) and days (`D`)
+    pub unit_labels: bool,
+    /// Renders a line of current value below label
+    pub status_line: bool,
+    /// Space between digits and around colons
+    pub spacing: Spacing,
+    /// Hides seconds of values exceeding it
+    pub hide_seconds_after: Option<Duration>,
+    /// Renders time running and wall-clock time since first run in label
+    pub wall_time: bool,
+    /// Dims digits (`--dim`)
+    pub dim: bool,
 }
 
 impl StatefulWidget for Timer {
     type State = TimerState;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let goal_reached = state.is_goal_reached();
+        let clock_widget =
+            ClockWidget::new(self.style, self.blink, self.decimal_separator, self.flip)
+                .unit_labels(self.unit_labels)
+                .spacing(self.spacing)
+                .hide_seconds_after(self.hide_seconds_after)
+                .dim(self.dim)
+                .color(goal_reached.then(|| clock::urgency_color(100)));
+        let wall_time = match (
+            self.wall_time,
+            state.clock.get_running_time(),
+            state.clock.get_wall_time(),
+        ) {
+            (true, Some(running), Some(wall)) => format!(
+                " ran {} of {} wall",
+                DurationEx::from(running),
+                DurationEx::from(wall)
+            ),
+            _ => String::new(),
+        };
+        let label = Line::raw(
+            match state.goal {
+                Some(goal) => format!(
+                    "Timer {} goal {}{}{wall_time}",
+                    state.clock.get_mode(),
+                    DurationEx::from(goal),
+                    if goal_reached { " reached!" } else { "" }
+                ),
+                None => format!("Timer {}{wall_time}", state.clock.get_mode()),
+            }
+            .to_uppercase(),
+        );
+        let label_status = self
+            .status_line
+            .then(|| Line::raw(state.clock.get_current_value().to_string()));
+        let label_status_height = label_status.as_ref().map_or(0, |_| 1);
         let clock = &mut state.clock;
-        let clock_widget = ClockWidget::new(self.style, self.blink);
-        let label = Line::raw((format!("Timer {}", clock.get_mode())).to_uppercase());
 
         let area = area.centered(
             Constraint::Length(max(
-                clock_widget.get_width(clock.get_format(), clock.with_decis),
-                label.width() as u16,
+                clock_widget.get_width(clock),
+                max(
+                    label.width() as u16,
+                    label_status.as_ref().map_or(0, |l| l.width() as u16),
+                ),
             )),
-            Constraint::Length(clock_widget.get_height() + 1 /* height of label */),
+            Constraint::Length(
+                clock_widget.get_height() + 1 /* height of label */ + label_status_height,
+            ),
         );
-        let [v1, v2] =
-            Layout::vertical(Constraint::from_lengths([clock_widget.get_height(), 1])).areas(area);
+        let [v1, v2, v3] = Layout::vertical(Constraint::from_lengths([
+            clock_widget.get_height(),
+            1,
+            label_status_height,
+        ]))
+        .areas(area);
 
         clock_widget.render(v1, buf, clock);
         label.centered().render(v2, buf);
+        if let Some(label_status) = label_status {
+            label_status.centered().render(v3, buf);
+        }
     }
 }
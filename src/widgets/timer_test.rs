@@ -1,11 +1,11 @@
 use crate::{
-    common::Style,
+    common::{AppTime, DecimalSeparator, Spacing, Style},
     constants::TICK_VALUE_MS,
     duration::{ONE_MINUTE, ONE_SECOND},
-    events::TuiEventHandler,
+    events::{AppEvent, TuiEvent, TuiEventHandler},
     widgets::{
         clock::{ClockState, ClockStateArgs, Timer as ClockTimer},
-        test_utils::{DrawArgs, Key, draw},
+        test_utils::{DrawArgs, FIXED_TIME, Key, Mouse, app_channel, draw, lines},
         timer::{Timer, TimerState},
     },
 };
@@ -35,7 +35,8 @@ fn st_with_args(a: Args) -> TimerState {
             tick_value: Duration::from_millis(TICK_VALUE_MS),
             with_decis: a.with_decis,
             app_tx: None,
-        }),
+        })
+        .with_app_time(AppTime::Utc(FIXED_TIME)),
         a.vim_motions,
     )
 }
@@ -48,6 +49,14 @@ fn w() -> Timer {
     Timer {
         style: Style::default(),
         blink: false,
+        decimal_separator: DecimalSeparator::default(),
+        flip: None,
+        unit_labels: true,
+        status_line: false,
+        spacing: Spacing::default(),
+        hide_seconds_after: None,
+        wall_time: false,
+        dim: false,
     }
 }
 
@@ -110,3 +119,116 @@ fn test_timer_edit_seconds() {
     let t = terminal(w(), st);
     assert_snapshot!("timer_edit_seconds", t.backend());
 }
+
+#[test]
+fn test_timer_edit_scroll() {
+    let mut st = st_with_args(Args {
+        current_value: ONE_MINUTE.saturating_mul(5),
+        ..args()
+    });
+    st.update(Key::Edit.into());
+    st.update(Mouse::ScrollUp.into());
+    st.update(Mouse::ScrollUp.into());
+    st.update(Mouse::ScrollDown.into());
+    // minutes are selected by default
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_MINUTE.saturating_mul(6)
+    );
+}
+
+#[test]
+fn test_timer_scroll_ignored_if_not_editing() {
+    let mut st = st_with_args(Args {
+        current_value: ONE_MINUTE.saturating_mul(5),
+        ..args()
+    });
+    assert!(st.update(Mouse::ScrollUp.into()).is_some());
+    assert_eq!(
+        Duration::from(*st.get_clock().get_current_value()),
+        ONE_MINUTE.saturating_mul(5)
+    );
+}
+
+#[test]
+fn test_timer_goal() {
+    let (tx, mut rx) = app_channel();
+    let mut st = st_with_args(Args {
+        current_value: ONE_MINUTE - Duration::from_millis(TICK_VALUE_MS),
+        ..args()
+    })
+    .with_goal(Some(ONE_MINUTE), tx);
+    st.update(Key::StartStop.into());
+    st.update(TuiEvent::Tick);
+    assert!(st.is_goal_reached());
+    // notified once only
+    assert_eq!(rx.try_recv(), Ok(AppEvent::TimerGoalReached(ONE_MINUTE)));
+    st.update(TuiEvent::Tick);
+    assert!(rx.try_recv().is_err());
+    let t = terminal(w(), st);
+    assert_snapshot!("timer_goal", t.backend());
+}
+
+#[test]
+fn test_timer_status_line() {
+    let st = st_with_args(Args {
+        current_value: ONE_MINUTE.saturating_mul(5),
+        ..args()
+    });
+    let t = terminal(
+        Timer {
+            status_line: true,
+            ..w()
+        },
+        st,
+    );
+    assert_snapshot!("timer_status_line", t.backend());
+}
+
+#[test]
+fn test_timer_lines() {
+    let t = draw(DrawArgs {
+        widget: w(),
+        state: st_with_args(Args {
+            current_value: ONE_MINUTE + ONE_SECOND.saturating_mul(5),
+            ..args()
+        }),
+        width: 24,
+        height: 8,
+    });
+    assert_eq!(
+        lines(&t),
+        vec![
+            "",
+            "     ██    █████ █████",
+            "     ██ ██ ██ ██ ██",
+            "     ██    ██ ██ █████",
+            "     ██ ██ ██ ██    ██",
+            "     ██    █████ █████",
+            "",
+            "        TIMER ||",
+        ]
+    );
+}
+
+#[test]
+fn test_timer_wall_time_label() {
+    let w = || Timer {
+        wall_time: true,
+        ..w()
+    };
+    // no session before first run
+    let t = terminal(w(), st());
+    assert!(!lines(&t).concat().contains("WALL"));
+    let mut st = st();
+    st.update(Key::StartStop.into());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE));
+    st.update(Key::StartStop.into());
+    st.set_app_time(AppTime::Utc(FIXED_TIME + ONE_MINUTE * 3));
+    let t = terminal(w(), st);
+    assert!(
+        lines(&t)
+            .concat()
+            .contains("TIMER || RAN 1:00 OF 3:00 WALL")
+    );
+}